@@ -0,0 +1,48 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+// A minimal timing harness, so that the benchmarks run on stable Rust
+// without extra dependencies. Run them with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERS: u32 = 1_000_000;
+
+// Runs `f` over `inputs` repeatedly and prints the mean time of one call.
+pub fn bench<I: Copy, O, F: FnMut(I) -> O>(name: &str, inputs: &[I], mut f: F) {
+    let rounds = ITERS / inputs.len() as u32;
+    // warm up.
+    for &x in inputs {
+        black_box(f(black_box(x)));
+    }
+    let start = Instant::now();
+    for _ in 0..rounds {
+        for &x in inputs {
+            black_box(f(black_box(x)));
+        }
+    }
+    let elapsed = start.elapsed();
+    let n = (rounds as usize * inputs.len()) as f64;
+    println!("{:<32} {:>8.2} ns/iter", name, elapsed.as_secs_f64() * 1e9 / n);
+}
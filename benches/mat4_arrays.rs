@@ -0,0 +1,66 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+// Compares `multiply_mat4_arrays` with the naive loop of `a[i] * b[i]`,
+// which it replaced.
+
+extern crate glm;
+
+mod common;
+
+use common::bench;
+use glm::*;
+use glm::ext::multiply_mat4_arrays;
+
+const N: usize = 64;
+
+// The previous implementation of `multiply_mat4_arrays`.
+fn naive<F: BaseFloat>(a: &[Matrix4<F>], b: &[Matrix4<F>], out: &mut [Matrix4<F>]) {
+    for ((l, r), o) in a.iter().zip(b.iter()).zip(out.iter_mut()) {
+        *o = l.mul_m(r);
+    }
+}
+
+fn matrices<F: BaseFloat>(k: usize) -> Vec<Matrix4<F>> {
+    (0..N).map(|i| {
+        let f = |x: usize| F::from((x * k + i) % 17).unwrap();
+        Matrix4::new(
+            Vector4::new(f(0), f(1), f(2), f(3)),
+            Vector4::new(f(4), f(5), f(6), f(7)),
+            Vector4::new(f(8), f(9), f(10), f(11)),
+            Vector4::new(f(12), f(13), f(14), f(15)),
+        )
+    }).collect()
+}
+
+fn main() {
+    let (a, b) = (matrices::<f32>(3), matrices::<f32>(5));
+    let mut out = a.clone();
+    bench("Mat4 x 64 (a[i] * b[i])", &[()], |_| naive(&a, &b, &mut out));
+    bench("Mat4 x 64", &[()], |_| multiply_mat4_arrays(&a, &b, &mut out));
+
+    let (a, b) = (matrices::<f64>(3), matrices::<f64>(5));
+    let mut out = a.clone();
+    bench("DMat4 x 64 (a[i] * b[i])", &[()], |_| naive(&a, &b, &mut out));
+    bench("DMat4 x 64", &[()], |_| multiply_mat4_arrays(&a, &b, &mut out));
+}
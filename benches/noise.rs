@@ -0,0 +1,47 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+// Compares the simplex noise of `PermutationTable` with the free functions.
+
+extern crate glm;
+
+mod common;
+
+use common::bench;
+use glm::*;
+use glm::ext::*;
+
+fn main() {
+    let ps: Vec<Vec4> = (0..1024).map(|i| {
+        let f = i as f32;
+        vec4(f * 0.173 - 50., f * 0.071, -f * 0.113, f * 0.037 + 3.)
+    }).collect();
+    let table = PermutationTable::new(7);
+
+    bench("simplex2", &ps, |p| simplex2(p.xy()));
+    bench("PermutationTable::simplex2", &ps, |p| table.simplex2(p.xy()));
+    bench("simplex3", &ps, |p| simplex3(p.xyz()));
+    bench("PermutationTable::simplex3", &ps, |p| table.simplex3(p.xyz()));
+    bench("simplex4", &ps, simplex4);
+    bench("PermutationTable::simplex4", &ps, |p| table.simplex4(p));
+}
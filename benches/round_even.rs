@@ -0,0 +1,62 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+// Compares `roundEven` with the branchy implementation it replaced.
+
+extern crate glm;
+
+mod common;
+
+use common::bench;
+use glm::*;
+
+// The previous implementation of `roundEven`.
+fn round_even_branchy<F: BaseFloat>(f: F) -> F {
+    let ling = F::zero();
+    let yi = F::one();
+    let er = yi + yi;
+
+    let int = f.trunc();
+    if f.fract().abs() != F::from(0.5).unwrap() {
+        f.round()
+    } else if int % er == ling {
+        int
+    } else if int < ling {
+        int - yi
+    } else {
+        int + yi
+    }
+}
+
+fn main() {
+    let xs: Vec<f32> = (-500..500).map(|i| i as f32 * 0.25).collect();
+    let ds: Vec<f64> = xs.iter().map(|&x| x as f64).collect();
+    let vs: Vec<Vec4> = xs.chunks(4).map(|c| vec4(c[0], c[1], c[2], c[3])).collect();
+
+    bench("roundEven f32 (branchy)", &xs, round_even_branchy);
+    bench("roundEven f32", &xs, roundEven);
+    bench("roundEven f64 (branchy)", &ds, round_even_branchy);
+    bench("roundEven f64", &ds, roundEven);
+    bench("roundEven Vec4 (branchy)", &vs, |v: Vec4| v.map(round_even_branchy));
+    bench("roundEven Vec4", &vs, roundEven);
+}
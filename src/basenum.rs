@@ -25,6 +25,7 @@ use std::cmp;
 use std::{ f32, f64 };
 use std::ops::{ Sub, Div, Rem, Neg };
 use rand::Rand;
+use simd;
 use num::{ PrimInt, Float, One, Signed, Zero };
 
 /// Marker trait for primitive types.
@@ -189,6 +190,55 @@ pub trait BaseFloat: Float + BaseNum + SignedNum + ApproxEq<BaseType = Self> {
     fn to_radians(self) -> Self;
     fn frexp(self) -> (Self, isize);
     fn ldexp(self, exp: isize) -> Self;
+    /// Returns `1 / sqrt(self)`.
+    ///
+    /// With feature `fast-rsqrt`, `f32` uses the hardware approximation
+    /// (SSE `rsqrtss`) refined by one Newton-Raphson iteration, which is
+    /// faster but only accurate to about 22 bits.
+    #[inline(always)]
+    fn rsqrt(self) -> Self {
+        self.sqrt().recip()
+    }
+    /// Returns `self * a + b` with only one rounding.
+    ///
+    /// With feature `fma-dispatch`, the FMA instruction is selected at run
+    /// time on x86_64 CPUs that support it, even if the target does not
+    /// enable it at compile time.
+    #[inline(always)]
+    fn fused_mul_add(self, a: Self, b: Self) -> Self {
+        self.mul_add(a, b)
+    }
+
+    // Kernels of `Vector4` and `Matrix4`, on arrays. They are an internal
+    // dispatch detail, not part of the public API, and must not be
+    // overridden outside this crate. With feature `simd`, `f32` uses the SSE
+    // implementations.
+
+    // Returns the dot product of 4-component vectors `x` and `y`.
+    #[doc(hidden)]
+    #[inline(always)]
+    fn dot4(x: &[Self; 4], y: &[Self; 4]) -> Self {
+        simd::scalar::dot4(x, y)
+    }
+    // Returns `m * v`, where `m` is a column major 4x4 matrix.
+    #[doc(hidden)]
+    #[inline(always)]
+    fn mul_mat4_vec4(m: &[[Self; 4]; 4], v: &[Self; 4]) -> [Self; 4] {
+        simd::scalar::mul_mat4_vec4(m, v)
+    }
+    // Returns `l * r`, where `l` and `r` are column major 4x4 matrices.
+    #[doc(hidden)]
+    #[inline(always)]
+    fn mul_mat4(l: &[[Self; 4]; 4], r: &[[Self; 4]; 4]) -> [[Self; 4]; 4] {
+        simd::scalar::mul_mat4(l, r)
+    }
+    // Returns the inverse of column major 4x4 matrix `m`, or `None` if `m`
+    // is singular.
+    #[doc(hidden)]
+    #[inline]
+    fn inverse_mat4(m: &[[Self; 4]; 4]) -> Option<[[Self; 4]; 4]> {
+        simd::scalar::inverse_mat4(m)
+    }
 }
 
 impl SignedNum for i32 {
@@ -222,8 +272,123 @@ macro_rules! impl_int(
 );
 impl_int! { i32, u32 }
 
+#[cfg(all(
+    feature = "fast-rsqrt",
+    any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse"))
+))]
+#[inline(always)]
+fn rsqrt_f32(x: f32) -> f32 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::{ _mm_set_ss, _mm_rsqrt_ss, _mm_cvtss_f32 };
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::{ _mm_set_ss, _mm_rsqrt_ss, _mm_cvtss_f32 };
+
+    let y = unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(x))) };
+    // One Newton-Raphson iteration.
+    y * (1.5 - 0.5 * x * y * y)
+}
+
+#[cfg(not(all(
+    feature = "fast-rsqrt",
+    any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse"))
+)))]
+#[inline(always)]
+fn rsqrt_f32(x: f32) -> f32 {
+    x.sqrt().recip()
+}
+
+#[inline(always)]
+fn rsqrt_f64(x: f64) -> f64 {
+    x.sqrt().recip()
+}
+
+// With feature `fma-dispatch` on x86_64 targets compiled without FMA, the
+// FMA instruction is used if the running CPU supports it. Otherwise,
+// `mul_add` is used, which is either the FMA instruction (if the target
+// enables it) or a correctly rounded, but slow, libm call.
+#[cfg(all(
+    feature = "fma-dispatch",
+    target_arch = "x86_64",
+    not(target_feature = "fma")
+))]
+mod fma_dispatch {
+    use std::arch::x86_64::{
+        _mm_set_ss, _mm_fmadd_ss, _mm_cvtss_f32,
+        _mm_set_sd, _mm_fmadd_sd, _mm_cvtsd_f64,
+    };
+
+    #[target_feature(enable = "fma")]
+    unsafe fn fma_f32_hw(a: f32, b: f32, c: f32) -> f32 {
+        _mm_cvtss_f32(_mm_fmadd_ss(_mm_set_ss(a), _mm_set_ss(b), _mm_set_ss(c)))
+    }
+
+    #[target_feature(enable = "fma")]
+    unsafe fn fma_f64_hw(a: f64, b: f64, c: f64) -> f64 {
+        _mm_cvtsd_f64(_mm_fmadd_sd(_mm_set_sd(a), _mm_set_sd(b), _mm_set_sd(c)))
+    }
+
+    #[inline(always)]
+    pub fn fma_f32(a: f32, b: f32, c: f32) -> f32 {
+        if is_x86_feature_detected!("fma") {
+            unsafe { fma_f32_hw(a, b, c) }
+        } else {
+            a.mul_add(b, c)
+        }
+    }
+
+    #[inline(always)]
+    pub fn fma_f64(a: f64, b: f64, c: f64) -> f64 {
+        if is_x86_feature_detected!("fma") {
+            unsafe { fma_f64_hw(a, b, c) }
+        } else {
+            a.mul_add(b, c)
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "fma-dispatch",
+    target_arch = "x86_64",
+    not(target_feature = "fma")
+))]
+use self::fma_dispatch::{ fma_f32, fma_f64 };
+
+#[cfg(not(all(
+    feature = "fma-dispatch",
+    target_arch = "x86_64",
+    not(target_feature = "fma")
+)))]
+#[inline(always)]
+fn fma_f32(a: f32, b: f32, c: f32) -> f32 {
+    a.mul_add(b, c)
+}
+
+#[cfg(not(all(
+    feature = "fma-dispatch",
+    target_arch = "x86_64",
+    not(target_feature = "fma")
+)))]
+#[inline(always)]
+fn fma_f64(a: f64, b: f64, c: f64) -> f64 {
+    a.mul_add(b, c)
+}
+
+#[cfg(all(
+    feature = "simd",
+    any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse"))
+))]
+use simd::sse as kernel_f32;
+
+#[cfg(not(all(
+    feature = "simd",
+    any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse"))
+)))]
+use simd::scalar as kernel_f32;
+
+use simd::scalar as kernel_f64;
+
 macro_rules! impl_flt(
-    ($t: ident) => {
+    ($t: ident, $rsqrt: ident, $fma: ident, $kernel: ident) => {
         impl Primitive for $t {}
         impl SignedNum for $t {
             #[inline(always)]
@@ -284,9 +449,33 @@ macro_rules! impl_flt(
                 let f = exp as $t;
                 self * f.exp2()
             }
+            #[inline(always)]
+            fn rsqrt(self) -> $t {
+                $rsqrt(self)
+            }
+            #[inline(always)]
+            fn fused_mul_add(self, a: $t, b: $t) -> $t {
+                $fma(self, a, b)
+            }
+            #[inline(always)]
+            fn dot4(x: &[$t; 4], y: &[$t; 4]) -> $t {
+                $kernel::dot4(x, y)
+            }
+            #[inline(always)]
+            fn mul_mat4_vec4(m: &[[$t; 4]; 4], v: &[$t; 4]) -> [$t; 4] {
+                $kernel::mul_mat4_vec4(m, v)
+            }
+            #[inline(always)]
+            fn mul_mat4(l: &[[$t; 4]; 4], r: &[[$t; 4]; 4]) -> [[$t; 4]; 4] {
+                $kernel::mul_mat4(l, r)
+            }
+            #[inline]
+            fn inverse_mat4(m: &[[$t; 4]; 4]) -> Option<[[$t; 4]; 4]> {
+                $kernel::inverse_mat4(m)
+            }
         }
     }
 );
 
-impl_flt! { f32 }
-impl_flt! { f64 }
+impl_flt! { f32, rsqrt_f32, fma_f32, kernel_f32 }
+impl_flt! { f64, rsqrt_f64, fma_f64, kernel_f64 }
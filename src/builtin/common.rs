@@ -25,11 +25,10 @@
 
 use basenum::*;
 use traits::*;
-use vec::traits::{ GenVec, GenFloatVec, GenNumVec };
+use vec::traits::{ GenVec, NumVecType, FloatVecType };
 use vec::vec::{ Vector2, Vector3, Vector4 };
 use std::mem;
-use std::ops::Rem;
-use num::{ Float, Zero };
+use num::{ Float, One, Zero };
 
 pub trait FloatIntRel<E: BaseFloat, I: BaseInt, GI: GenInt<I>>: GenFloat<E> {
     // float -> int
@@ -83,7 +82,7 @@ macro_rules! impl_vec_FloatIntRel {
                 fn split_int<F: Fn(E) -> (E, I)>(&self, fun: F) -> ($t<E>, $t<I>) {
                     let mut f = $t::<E>::zero();
                     let mut i = $t::<I>::zero();
-                    let dim = $t::<E>::dim();
+                    let dim = $t::<E>::DIM;
                     for j in 0..dim {
                         let (a, b) = fun(self[j]);
                         f[j] = a;
@@ -283,25 +282,20 @@ pub fn round<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
 /// assert_eq!(roundEven(2.5_f32), 2.);
 /// assert_eq!(roundEven(1.5_f32), 2.);
 /// assert_eq!(roundEven(vec4(3.14, -3.14, -1.5, -2.5)), vec4(3., -3., -2., -2.));
+/// assert_eq!(roundEven(0.5_f64), 0.);
+/// assert_eq!(roundEven(1e20_f32), 1e20);
 /// ```
 #[inline(always)]
 #[allow(non_snake_case)]
 pub fn roundEven<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
     x.map(|f| -> F {
-        let ling = F::zero();
-        let yi = F::one();
-        let er = yi + yi;
-
-        let int = f.trunc();
-        if f.fract().abs() != F::from(0.5).unwrap() {
-            f.round()
-        } else if int % er == ling {
-            int
-        } else if int < ling {
-            int - yi
-        } else {
-            int + yi
-        }
+        // `2^(p-1)`, where `p` is the number of significand bits. Adding and
+        // then subtracting it lets the FPU round to the nearest even integer.
+        // Larger numbers are integers already.
+        let magic = F::epsilon().recip();
+        let a = f.abs();
+        let r = if a < magic { (a + magic) - magic } else { a };
+        r * f.signum()
     })
 }
 
@@ -339,6 +333,9 @@ pub fn fract<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
 
 /// Modulus. Returns `x – y ∗ floor(x/y)`.
 ///
+/// Unlike Rust's `%` operator, which truncates, the result has the same sign
+/// as `y`.
+///
 /// # Note
 ///
 /// Original function name `mod` is renamed to `fmod` because **mod** is
@@ -347,11 +344,19 @@ pub fn fract<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
 /// # Example
 ///
 /// ```
-/// assert_eq!(glm::fmod(3.5_f32, 3.), 0.5);
+/// use glm::{ fmod, vec4 };
+///
+/// assert_eq!(fmod(3.5_f32, 3.), 0.5);
+/// assert_eq!(fmod(-3.5_f32, 3.), 2.5);
+/// let x = vec4(3.5, -3.5, 3.5, -3.5);
+/// let y = vec4(3., 3., -3., -3.);
+/// assert_eq!(fmod(x, y), vec4(0.5, 2.5, -2.5, -0.5));
 /// ```
 #[inline(always)]
 pub fn fmod<F: BaseFloat, T: GenFloat<F>>(x: T, y: T) -> T {
-    x.zip(y, Rem::rem)
+    x.zip(y, |a, b| -> F {
+        a - b * (a / b).floor()
+    })
 }
 
 /// Modulus with a scalar number.
@@ -368,12 +373,13 @@ pub fn fmod<F: BaseFloat, T: GenFloat<F>>(x: T, y: T) -> T {
 /// use glm::{ mod_s, dvec3 };
 ///
 /// let v = dvec3(-1.5, 1.5, 10.5);
-/// assert_eq!(mod_s(v, 2.), dvec3(-1.5, 1.5, 0.5));
+/// assert_eq!(mod_s(v, 2.), dvec3(0.5, 1.5, 0.5));
+/// assert_eq!(mod_s(v, -2.), dvec3(-1.5, -0.5, -1.5));
 /// ```
 #[inline(always)]
-pub fn mod_s<F: BaseFloat, T: GenFloatVec<F>>(x: T, y: F) -> T {
-    x.map(|f| -> F {
-        f.rem(y)
+pub fn mod_s<T: FloatVecType>(x: T, y: T::Scalar) -> T {
+    x.map(|f| -> T::Scalar {
+        f - y * (f / y).floor()
     })
 }
 
@@ -427,8 +433,8 @@ pub fn min<S: BaseNum, T: GenNum<S>>(x: T, y: T) -> T {
 /// assert_eq!(glm::min_s(v, y), glm::vec2(1., 2.));
 /// ```
 #[inline(always)]
-pub fn min_s<S: BaseNum, T: GenNumVec<S>>(x: T, y: S) -> T {
-    x.map(|c| -> S {
+pub fn min_s<T: NumVecType>(x: T, y: T::Scalar) -> T {
+    x.map(|c| -> T::Scalar {
         BaseNum::min(c, y)
     })
 }
@@ -462,8 +468,8 @@ pub fn max<S: BaseNum, T: GenNum<S>>(x: T, y: T) -> T {
 /// assert_eq!(glm::max_s(v, y), glm::vec2(2., 3.));
 /// ```
 #[inline(always)]
-pub fn max_s<S: BaseNum, T: GenNumVec<S>>(x: T, y: S) -> T {
-    x.map(|s| -> S { BaseNum::max(s, y) })
+pub fn max_s<T: NumVecType>(x: T, y: T::Scalar) -> T {
+    x.map(|s| -> T::Scalar { BaseNum::max(s, y) })
 }
 
 /// Returns `min (max (x, min_val), max_val)`.
@@ -499,7 +505,7 @@ pub fn clamp<S: BaseNum, T: GenNum<S>>(x: T, min_val: T, max_val: T) -> T {
 /// assert_eq!(clamp_s(dv2, 0., 1.), dvec2(0., 1.));
 /// ```
 #[inline(always)]
-pub fn clamp_s<S: BaseNum, T: GenNumVec<S>>(x: T, min_val: S, max_val: S) -> T {
+pub fn clamp_s<T: NumVecType>(x: T, min_val: T::Scalar, max_val: T::Scalar) -> T {
     min_s(max_s(x, min_val), max_val)
 }
 
@@ -540,8 +546,8 @@ pub fn mix<F: BaseFloat, T: GenFloat<F>>(x: T, y: T, a: T) -> T {
 /// assert_eq!(mix_s(x, y, 0.5), dvec3(55., 110., 165.));
 /// ```
 #[inline(always)]
-pub fn mix_s<F: BaseFloat, T: GenFloatVec<F>>(x: T, y: T, a: F) -> T {
-    let yi = F::one();
+pub fn mix_s<T: FloatVecType>(x: T, y: T, a: T::Scalar) -> T {
+    let yi = T::Scalar::one();
     x * (yi - a) + y * a
 }
 
@@ -630,12 +636,12 @@ pub fn step<F: BaseFloat, T: GenFloat<F>>(edge: T, x: T) -> T {
 /// assert_eq!(step_s(0_f32, vec3(-1., 0., 1.)), vec3(0., 1., 1.));
 /// ```
 #[inline(always)]
-pub fn step_s<F: BaseFloat, T: GenFloatVec<F>>(edge: F, x: T) -> T {
-    x.map(|f| -> F {
+pub fn step_s<T: FloatVecType>(edge: T::Scalar, x: T) -> T {
+    x.map(|f| -> T::Scalar {
         if f < edge {
-            F::zero()
+            T::Scalar::zero()
         } else {
-            F::one()
+            T::Scalar::one()
         }
     })
 }
@@ -676,18 +682,14 @@ pub fn smoothstep<F: BaseFloat, T: GenFloat<F>>(edge0: T, edge1: T, x: T) -> T {
 ///
 /// `smoothstep_s` is not a GLSL function name.
 #[inline]
-pub fn smoothstep_s
-<
-F: BaseFloat + GenNum<F>,
-T: GenFloatVec<F>
->(edge0: F, edge1: F, x: T) -> T {
-    let ling = F::zero();
-    let yi = F::one();
+pub fn smoothstep_s<T: FloatVecType>(edge0: T::Scalar, edge1: T::Scalar, x: T) -> T {
+    let ling = T::Scalar::zero();
+    let yi = T::Scalar::one();
     let er = yi + yi;
     let san = er + yi;
 
-    x.map(|f| -> F {
-        let t = clamp((f - edge0) / (edge1 - edge0), ling, yi);
+    x.map(|f| -> T::Scalar {
+        let t = BaseNum::min(BaseNum::max((f - edge0) / (edge1 - edge0), ling), yi);
         t * t * (san - er * t)
     })
 }
@@ -858,11 +860,16 @@ pub fn uintBitsToFloat<G: GenType, T: IntFloatRel<u32, f32, G>>(value: T) -> G {
 
 /// Computes and returns `a * b + c`.
 ///
+/// The result is rounded only once. See feature `fma-dispatch` for the
+/// performance on targets that do not enable the FMA instruction.
+///
 /// # Example
 ///
 /// ```
 /// use glm::{ fma, vec3 };
 /// assert_eq!(fma(1.5_f32, 2.25, 3.125), 6.5);
+/// // `0.1 * 10. - 1.` is `0` if the product is rounded first.
+/// assert_eq!(fma(0.1_f64, 10., -1.), 5.551115123125783e-17);
 /// let a = vec3(-1., 2., 3.);
 /// let b = vec3(4., 5., 6.);
 /// let c = vec3(7., 8., 0.);
@@ -132,16 +132,17 @@ pub fn sqrt<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
 ///
 /// Results are undefined if `x ≤ 0`.
 ///
+/// Enable feature `fast-rsqrt` to trade precision for speed, see
+/// `BaseFloat::rsqrt`. `normalize` is affected as well.
+///
 /// # Example
 ///
 /// ```
-/// use glm::{ inversesqrt, vec2 };
-/// assert_eq!(inversesqrt(4_f32), 0.5);
-/// assert_eq!(inversesqrt(vec2(64., 1.)), vec2(0.125, 1.));
+/// use glm::{ inversesqrt, vec2, is_approx_eq };
+/// assert!(is_approx_eq(&inversesqrt(4_f32), &0.5));
+/// assert!(is_approx_eq(&inversesqrt(vec2(64., 1.)), &vec2(0.125, 1.)));
 /// ```
 #[inline(always)]
 pub fn inversesqrt<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
-    x.map(|f| -> F {
-        f.sqrt().recip()
-    })
+    x.map(BaseFloat::rsqrt)
 }
@@ -46,7 +46,7 @@ use super::exp::inversesqrt;
 /// ```
 #[inline(always)]
 pub fn dot<S: BaseFloat, T: GenFloatVec<S>>(x: T, y: T) -> S {
-    (x * y).sum()
+    x.dot(y)
 }
 
 /// Returns the length of vector `x`, i.e., `sqrt(x[0]^2 + x[1]^2 + ...)`.
@@ -177,10 +177,13 @@ pub fn imulExtended<T: GenIType>(x: T, y: T) -> (T, T) {
 /// # Example
 ///
 /// ```
-/// use glm::bitfieldExtract;
+/// use glm::{ bitfieldExtract, ivec2 };
 ///
-/// assert_eq!(bitfieldExtract(0xF000FFFF, 32, 12), 0);
+/// assert_eq!(bitfieldExtract(0xF000FFFF_u32, 32, 12), 0);
 /// assert_eq!(bitfieldExtract(0b11100011_u32, 1, 6), 0b110001);
+/// // sign-extended for signed integers.
+/// assert_eq!(bitfieldExtract(0b10110000_i32, 4, 4), -5);
+/// assert_eq!(bitfieldExtract(ivec2(0x70, 0xF0), 4, 4), ivec2(7, -1));
 /// ```
 #[allow(non_snake_case)]
 pub fn bitfieldExtract
@@ -188,13 +191,15 @@ pub fn bitfieldExtract
 I: BaseInt,
 T: GenInt<I>
 >(value: T, offset: usize, bits: usize) -> T {
-    let ling = T::zero();
-    if value.is_zero() || bits == 0 || offset + bits > 32 {
-        ling
+    let width = I::zero().count_zeros() as usize;
+    if bits == 0 || offset + bits > width {
+        T::zero()
     } else {
-        let mask = I::from((1_u32 << bits) - 1).unwrap();
+        // moves the field to the most significant bits, then shifts it back.
+        // Right shift is arithmetic for signed integers, which does the sign
+        // extension.
         value.map(|i| -> I {
-            (i >> offset) & mask
+            (i << (width - offset - bits)) >> (width - bits)
         })
     }
 }
@@ -350,3 +355,30 @@ T: IntIntRel<B, I>
         }
     })
 }
+
+#[cfg(test)]
+mod test {
+
+    use vec::vec::{ ivec4, uvec4 };
+    use super::*;
+
+    #[test]
+    fn test_bitfield_extract() {
+        // expected values are from GLSL.
+        let x = 0x8F0F00F1_u32;
+        assert_eq!(bitfieldExtract(x, 0, 32), x);
+        assert_eq!(bitfieldExtract(x as i32, 0, 32), x as i32);
+        assert_eq!(
+            bitfieldExtract(uvec4(x, x, x, x), 28, 4),
+            uvec4(0x8, 0x8, 0x8, 0x8)
+        );
+        assert_eq!(
+            bitfieldExtract(ivec4(x as i32, 0x0F0, 0x070, 0x100), 4, 4),
+            ivec4(-1, -1, 7, 0)
+        );
+        assert_eq!(bitfieldExtract(x as i32, 28, 4), -8);
+        assert_eq!(bitfieldExtract(x as i32, 24, 4), -1);
+        assert_eq!(bitfieldExtract(x as i32, 24, 5), 0xF);
+        assert_eq!(bitfieldExtract(x as i32, 4, 0), 0);
+    }
+}
@@ -23,9 +23,8 @@
 
 // The GLSL Specification, ch 8.6, Matrix Functions.
 
-use basenum::BaseFloat;
-use mat::traits::{ GenMat, GenSquareMat };
-use vec::traits::GenFloatVec;
+use mat::traits::{ MatType, SquareMatType };
+use vec::traits::GenVec;
 
 /// Multiply matrix `x` by matrix `y` component-wise, i.e., `result[i][j]` is
 /// the scalar product of `x[i][j]` and `y[i][j]`.
@@ -47,11 +46,7 @@ use vec::traits::GenFloatVec;
 /// ```
 #[inline(always)]
 #[allow(non_snake_case)]
-pub fn matrixCompMult<
-T: BaseFloat,
-C: GenFloatVec<T>,
-M: GenMat<T, C>
->(x: &M, y: &M) -> M {
+pub fn matrixCompMult<M: MatType>(x: &M, y: &M) -> M {
     x.mul_c(y)
 }
 
@@ -73,24 +68,9 @@ M: GenMat<T, C>
 /// ```
 #[inline]
 #[allow(non_snake_case)]
-pub fn outerProduct<
-T: BaseFloat,
-C: GenFloatVec<T>,
-R: GenFloatVec<T>,
-/*
- * NOTE:
- * I can't believe Rust allows this! But Rust is wrong at the first place.
- * Associated types (e.g., `R` and `Transpose` of `GenMat` here) are not type
- * parameters, and should not be mandatorily required when specifying a type,
- * and if `Transpose` is ommitted (as we did before), that does not mean
- * `GenMat` is not implemented for `Tanspose` of `M` (E0277). How could that
- * be possible?
- */
-N: GenMat<T, R, R = C, Transpose = M>,
-M: GenMat<T, C, R = R, Transpose = N>
->(c: C, r: R) -> M {
+pub fn outerProduct<M: MatType>(c: M::Column, r: M::R) -> M {
     let mut z = M::zero();
-    let dim = R::dim();
+    let dim = M::R::DIM;
     for i in 0..dim {
         z[i] = c * r[i];
     };
@@ -101,21 +81,13 @@ M: GenMat<T, C, R = R, Transpose = N>
 ///
 /// The input matrix `m` is not modified.
 #[inline(always)]
-pub fn transpose<
-T: BaseFloat,
-C: GenFloatVec<T>,
-M: GenMat<T, C>
->(m: &M) -> M::Transpose {
+pub fn transpose<M: MatType>(m: &M) -> M::Transpose {
     m.transpose()
 }
 
 /// Returns the determinant of `m`.
 #[inline(always)]
-pub fn determinant<
-T: BaseFloat,
-C: GenFloatVec<T>,
-M: GenSquareMat<T, C>
->(m: &M) -> T {
+pub fn determinant<M: SquareMatType>(m: &M) -> M::Scalar {
     m.determinant()
 }
 
@@ -127,11 +99,7 @@ M: GenSquareMat<T, C>
 ///
 /// It is a panic if `m` is singular or poorly-conditioned (nearly singular).
 #[inline]
-pub fn inverse<
-T: BaseFloat,
-C: GenFloatVec<T>,
-M: GenSquareMat<T, C>
->(m: &M) -> M {
+pub fn inverse<M: SquareMatType>(m: &M) -> M {
     let inv = m.inverse();
     match inv {
         Some(im) => im,
@@ -76,6 +76,8 @@ pub use self::noise::{
     noise1, noise2, noise3, noise4,
 };
 
+pub(crate) use self::noise::NoiseImpl;
+
 mod trig;
 mod exp;
 mod common;
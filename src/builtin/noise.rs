@@ -44,42 +44,73 @@ use super::vecrel::lessThan;
 use num::{ One, Zero };
 
 #[allow(non_snake_case)]
-pub fn grad4(j: f32, ip: Vec4) -> Vec4 {
-    let mut pXYZ = floor(fract(vec3(j, j, j) * ip.truncate(3)) * 7.) * ip[2] - 1.;
+fn grad4(j: f32, ip: Vec4) -> Vec4 {
+    let mut pXYZ = floor(fract(vec3(j, j, j) * ip.xyz()) * 7.) * ip[2] - 1.;
     let pW = 1.5 - dot(abs(pXYZ), Vec3::one());
     let s = to_vec4(lessThan(vec4(pXYZ.x, pXYZ.y, pXYZ.z, pW), Vec4::zero()));
-    pXYZ = pXYZ + (s.truncate(3) * 2. - 1.) * s.w;
+    pXYZ += (s.xyz() * 2. - 1.) * s.w;
     vec4(pXYZ.x, pXYZ.y, pXYZ.z, pW)
 }
 
 #[inline(always)]
-pub fn mod289<T: GenType>(x: T) -> T {
+fn mod289<T: GenType>(x: T) -> T {
     x - floor(x * (1. / 289.)) * 289.
 }
 
 #[inline(always)]
-pub fn permute<T: GenType>(x: T) -> T {
+fn permute<T: GenType>(x: T) -> T {
     mod289((x * 34. + 1.) * x)
 }
 
 #[inline(always)]
-pub fn taylor_inv_sqrt<T: GenType>(x: T) -> T {
+fn taylor_inv_sqrt<T: GenType>(x: T) -> T {
     -x * 0.85373472095314 + 1.79284291400159
 }
 
-pub trait NoiseImpl {
-    fn noise1(self) -> f32;
+// Hashes lattice hashes `h` once more for `channel`, i.e., composes the
+// permutation of the lattice points with a permutation that is different
+// for each channel. So the channels get different gradients at the same
+// lattice points, instead of being translated copies of each other.
+//
+// Channel `0` keeps the hashes of the original simplex noise.
+#[inline(always)]
+fn channel_permute<T: GenType>(h: T, channel: f32) -> T {
+    if channel == 0. {
+        h
+    } else {
+        permute(h + channel * 59.)
+    }
+}
+
+pub trait NoiseImpl: Sized {
+    /// Returns the simplex noise value of `channel` and its gradient.
+    ///
+    /// Different channels use different permutations of the lattice
+    /// gradients, so they are uncorrelated with each other rather than
+    /// shifted copies of the same field.
+    fn simplex_grad(self, channel: f32) -> (f32, Self);
+
+    #[inline(always)]
+    fn simplex(self, channel: f32) -> f32 {
+        self.simplex_grad(channel).0
+    }
+
+    #[inline(always)]
+    fn noise1(self) -> f32 {
+        self.simplex(0.)
+    }
 }
 
 impl NoiseImpl for f32 {
     #[inline]
-    fn noise1(self) -> f32 {
-        vec2(self, 0.).noise1()
+    fn simplex_grad(self, channel: f32) -> (f32, f32) {
+        let (n, g) = vec2(self, 0.).simplex_grad(channel);
+        (n, g.x)
     }
 }
 
 impl NoiseImpl for Vec2 {
-    fn noise1(self) -> f32 {
+    fn simplex_grad(self, channel: f32) -> (f32, Vec2) {
         let yi = Vec2::one();
         let C = vec4(
              0.211324865405187,     //  (3.0 -  sqrt(3.0)) / 6.0
@@ -103,20 +134,15 @@ impl NoiseImpl for Vec2 {
 
         // Permutations
         i = mod_s(i, 289.);    // Avoid truncation effects in permutation
-        let p = permute(permute(
+        let p = channel_permute(permute(permute(
             vec3(0., i1.y, 1.) + i.y) +
-            vec3(0., i1.x, 1.) + i.x);
-
-        let mut m = max_s(
-            -vec3(
-                dot(x0, x0),
-                dot(vec2(x12.x, x12.y), vec2(x12.x, x12.y)),
-                dot(vec2(x12.z, x12.w), vec2(x12.z, x12.w))
-            ) + 0.5,
-            0.
-        );
-        m = m * m;
-        m = m * m;
+            vec3(0., i1.x, 1.) + i.x), channel);
+
+        let x1 = vec2(x12.x, x12.y);
+        let x2 = vec2(x12.z, x12.w);
+        let t = max_s(-vec3(dot(x0, x0), dot(x1, x1), dot(x2, x2)) + 0.5, 0.);
+        let t2 = t * t;
+        let mut m = t2 * t2;
 
         // Gradients: 41 points uniformly over a line, mapped onto a diamond.
         // The ring size 17*17 = 289 is close to a multiple of 41 (41*7 = 287)
@@ -128,7 +154,8 @@ impl NoiseImpl for Vec2 {
 
         // Normalise gradients implicitly by scaling m
         // Inlined for speed: m *= taylorInvSqrt( a0*a0 + h*h );
-        m = m * ((a0 * a0 + h * h) * -0.85373472095314 + 1.79284291400159);
+        let norm = (a0 * a0 + h * h) * -0.853_734_7 + 1.792_842_9;
+        m *= norm;
 
         // Compute final noise value at P
         let g = vec3(
@@ -137,12 +164,19 @@ impl NoiseImpl for Vec2 {
             a0.y * x12.x + h.y * x12.y,
             a0.z * x12.z + h.z * x12.w
         );
-        dot(m, g) * 130.
+
+        // d(t^4 * dot(g, x)) / dx = t^4 * g - 8 * t^3 * dot(g, x) * x
+        let dm = t2 * t * norm * g * -8.;
+        let grad =
+            vec2(a0.x, h.x) * m.x + x0 * dm.x +
+            vec2(a0.y, h.y) * m.y + x1 * dm.y +
+            vec2(a0.z, h.z) * m.z + x2 * dm.z;
+        (dot(m, g) * 130., grad * 130.)
     }
 }
 
 impl NoiseImpl for Vec3 {
-    fn noise1(self) -> f32 {
+    fn simplex_grad(self, channel: f32) -> (f32, Vec3) {
         let yi = Vec3::one();
         let C = vec2(1./6., 1./3.);
         let D = vec4(0., 0.5, 1., 2.);
@@ -167,10 +201,10 @@ impl NoiseImpl for Vec3 {
 
         // Permutations
         i = mod289(i);
-        let p: Vec4 = permute(permute(permute(
+        let p: Vec4 = channel_permute(permute(permute(permute(
             vec4(0., i1.z, i2.z, 1.) + i.z) +
             vec4(0., i1.y, i2.y, 1.) + i.y) +
-            vec4(0., i1.x, i2.x, 1.) + i.x);
+            vec4(0., i1.x, i2.x, 1.) + i.x), channel);
 
         // Gradients: 7x7 points over a square, mapped onto an octahedron.
         // The ring size 17*17 = 289 is close to a multiple of 49 (49*6 = 294)
@@ -213,14 +247,23 @@ impl NoiseImpl for Vec3 {
         p3 = p3 * norm.w;
 
         // Mix final noise value
-        let mut m = max_s(-vec4(dot(x0, x0), dot(x1, x1), dot(x2, x2), dot(x3, x3)) + 0.6, 0.);
-        m = m * m;
-        42. * dot(m * m, vec4(dot(p0, x0), dot(p1, x1), dot(p2, x2), dot(p3, x3)))
+        let t = max_s(-vec4(dot(x0, x0), dot(x1, x1), dot(x2, x2), dot(x3, x3)) + 0.6, 0.);
+        let t2 = t * t;
+        let m = t2 * t2;
+        let px = vec4(dot(p0, x0), dot(p1, x1), dot(p2, x2), dot(p3, x3));
+
+        let dm = t2 * t * px * -8.;
+        let grad =
+            p0 * m.x + x0 * dm.x +
+            p1 * m.y + x1 * dm.y +
+            p2 * m.z + x2 * dm.z +
+            p3 * m.w + x3 * dm.w;
+        (42. * dot(m, px), grad * 42.)
     }
 }
 
 impl NoiseImpl for Vec4 {
-    fn noise1(self) -> f32 {
+    fn simplex_grad(self, channel: f32) -> (f32, Vec4) {
         let yi = Vec4::one();
         let C = vec4(
              0.138196601125011,     // (5 - sqrt(5))/20  G4
@@ -270,12 +313,13 @@ impl NoiseImpl for Vec4 {
 
         // Permutations
         i = mod_s(i, 289.);
-        let j0 = permute(permute(permute(permute(i.w) + i.z) + i.y) + i.x);
-        let j1 = permute(permute(permute(permute(
+        let j0 = channel_permute(
+            permute(permute(permute(permute(i.w) + i.z) + i.y) + i.x), channel);
+        let j1 = channel_permute(permute(permute(permute(permute(
             vec4(i1.w, i2.w, i3.w, 1.) + i.w) +
             vec4(i1.z, i2.z, i3.z, 1.) + i.z) +
             vec4(i1.y, i2.y, i3.y, 1.) + i.y) +
-            vec4(i1.x, i2.x, i3.x, 1.) + i.x);
+            vec4(i1.x, i2.x, i3.x, 1.) + i.x), channel);
 
         // Gradients: 7x7x6 points over a cube, mapped onto a 4-cross polytope
         // 7*7*6 = 294, which is close to the ring size 17*17 = 289.
@@ -297,13 +341,24 @@ impl NoiseImpl for Vec4 {
         p4 = p4 * taylor_inv_sqrt(dot(p4, p4));
 
         // Mix contributions from the five corners
-        let mut m0 = max_s(vec3(0.6, 0.6, 0.6) - vec3(dot(x0, x0), dot(x1, x1), dot(x2, x2)), 0.);
-        let mut m1 = max_s(vec2(0.6, 0.6) - vec2(dot(x3, x3), dot(x4, x4)), 0.);
-        m0 = m0 * m0;
-        m1 = m1 * m1;
-
-        dot(m0 * m0, vec3(dot(p0, x0), dot(p1, x1), dot(p2, x2))) +
-        dot(m1 * m1, vec2(dot(p3, x3), dot (p4, x4))) * 49.
+        let t0 = max_s(vec3(0.6, 0.6, 0.6) - vec3(dot(x0, x0), dot(x1, x1), dot(x2, x2)), 0.);
+        let t1 = max_s(vec2(0.6, 0.6) - vec2(dot(x3, x3), dot(x4, x4)), 0.);
+        let t02 = t0 * t0;
+        let t12 = t1 * t1;
+        let m0 = t02 * t02;
+        let m1 = t12 * t12;
+        let px0 = vec3(dot(p0, x0), dot(p1, x1), dot(p2, x2));
+        let px1 = vec2(dot(p3, x3), dot (p4, x4));
+
+        let dm0 = t02 * t0 * px0 * -8.;
+        let dm1 = t12 * t1 * px1 * -8.;
+        let grad =
+            p0 * m0.x + x0 * dm0.x +
+            p1 * m0.y + x1 * dm0.y +
+            p2 * m0.z + x2 * dm0.z +
+            p3 * m1.x + x3 * dm1.x +
+            p4 * m1.y + x4 * dm1.y;
+        ((dot(m0, px0) + dot(m1, px1)) * 49., grad * 49.)
     }
 }
 
@@ -314,31 +369,113 @@ pub fn noise1<T: GenType + NoiseImpl>(x: T) -> f32 {
 }
 
 /// Returns a 2D noise value based on the input value `x`.
+///
+/// The components are independent noise channels. The first one equals
+/// `noise1(x)`.
 #[inline]
 pub fn noise2<T: GenType + NoiseImpl>(x: T) -> Vec2 {
-    vec2(x.noise1(), (-x).noise1())
+    vec2(x.simplex(0.), x.simplex(1.))
 }
 
 /// Returns a 3D noise value based on the input value `x`.
+///
+/// The components are independent noise channels. The first one equals
+/// `noise1(x)`.
 #[inline]
 pub fn noise3<T: GenType + NoiseImpl>(x: T) -> Vec3 {
-    let yi = T::one();
-    vec3((x - yi).noise1(), x.noise1(), (x + yi).noise1())
+    vec3(x.simplex(0.), x.simplex(1.), x.simplex(2.))
 }
 
 /// Returns a 4D noise value based on the input value `x`.
+///
+/// The components are independent noise channels. The first one equals
+/// `noise1(x)`.
 #[inline]
 pub fn noise4<T: GenType + NoiseImpl>(x: T) -> Vec4 {
-    let yi = T::one();
-    vec4(
-        (x - yi).noise1(),
-        x.noise1(),
-        (x + yi).noise1(),
-        (x + yi + yi).noise1()
-    )
+    vec4(x.simplex(0.), x.simplex(1.), x.simplex(2.), x.simplex(3.))
 }
 
 #[cfg(test)]
 mod test {
 
+    use std::ops::IndexMut;
+    use vec::vec::{ Vec4, vec2, vec3, vec4 };
+    use builtin::exp::sqrt;
+    use super::*;
+
+    // Pearson correlation coefficients between the first channel and the
+    // others, sampled on a grid.
+    fn correlations<F: Fn(f32, f32) -> Vec4>(f: F) -> Vec4 {
+        let n = 128;
+        let mut samples = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                samples.push(f(i as f32 * 0.53, j as f32 * 0.61));
+            }
+        }
+        let cnt = samples.len() as f32;
+        let mean = samples.iter().fold(Vec4::zero(), |s, v| s + *v) / cnt;
+        let mut cov = Vec4::zero();
+        for v in samples.iter() {
+            let d = *v - mean;
+            cov = cov + d * d.x;
+        }
+        let var = cov.x;
+        let mut vars = Vec4::zero();
+        for v in samples.iter() {
+            let d = *v - mean;
+            vars = vars + d * d;
+        }
+        cov / sqrt(vars * var)
+    }
+
+    #[test]
+    fn test_first_channel() {
+        let p = vec3(0.3, 1.7, -2.9);
+        assert_eq!(noise2(p).x, noise1(p));
+        assert_eq!(noise3(p).x, noise1(p));
+        assert_eq!(noise4(p).x, noise1(p));
+    }
+
+    fn check_grad<T>(p: T, dim: usize)
+    where
+        T: GenType + NoiseImpl + IndexMut<usize, Output = f32>
+    {
+        let h = 1e-3;
+        for channel in 0..3 {
+            let c = channel as f32;
+            let (_, g) = p.simplex_grad(c);
+            for i in 0..dim {
+                let mut d = T::zero();
+                d[i] = h;
+                let fd = ((p + d).simplex(c) - (p - d).simplex(c)) / (2. * h);
+                assert!(abs(fd - g[i]) < 1e-2, "{} vs {}", fd, g[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gradient() {
+        let h = 1e-3;
+        for &x in [0.37_f32, -2.1, 5.55].iter() {
+            let fd = ((x + h).noise1() - (x - h).noise1()) / (2. * h);
+            assert!(abs(fd - x.simplex_grad(0.).1) < 1e-2);
+        }
+        check_grad(vec2(1.3, -0.7), 2);
+        check_grad(vec2(-4.1, 2.25), 2);
+        check_grad(vec3(1.3, -0.7, 0.2), 3);
+        check_grad(vec3(7.7, 3.1, -2.6), 3);
+        check_grad(vec4(1.3, -0.7, 0.2, 0.9), 4);
+        check_grad(vec4(-3.3, 2.4, 6.1, -0.45), 4);
+    }
+
+    #[test]
+    fn test_independent_channels() {
+        let c2 = correlations(|x, y| noise4(vec2(x, y)));
+        let c3 = correlations(|x, y| noise4(vec3(x, y, x - y)));
+        let c4 = correlations(|x, y| noise4(vec4(x, y, x + y, x * y)));
+        for c in [c2, c3, c4].iter() {
+            assert!(abs(c.y) < 0.1 && abs(c.z) < 0.1 && abs(c.w) < 0.1, "{:?}", c);
+        }
+    }
 }
@@ -22,6 +22,7 @@
 // THE SOFTWARE.
 
 use basenum::Primitive;
+use error::GlmError;
 use vec::traits::GenVec;
 use vec::vec::*;
 use std::default::Default;
@@ -229,6 +230,31 @@ def_cast_scalar_fun! {
     { boolean,  bool }
 }
 
+macro_rules! def_try_cast_scalar_fun {
+    ($({ $nm: ident, $t: ty }),+) => {
+        $(
+            /// Fallible version of the cast function of the same name
+            /// without the `try_` prefix.
+            ///
+            /// Returns `Err(GlmError::CastOutOfRange)` if the value can not
+            /// be represented in the target type.
+            #[inline]
+            pub fn $nm<B: PrimCast, F: ToScalar<B, $t>>(
+                from: F
+            ) -> Result<$t, GlmError> {
+                from.to().ok_or(GlmError::CastOutOfRange)
+            }
+        )+
+    }
+}
+
+def_try_cast_scalar_fun! {
+    { try_int,      i32 },
+    { try_uint,     u32 },
+    { try_float,    f32 },
+    { try_double,   f64 }
+}
+
 /// Traits for converting any scalar/vector values to a vector.
 pub trait ToVector
 <
@@ -343,6 +369,7 @@ def_cast_vector_fun! {
 mod test {
 
     use vec::vec::*;
+    use error::GlmError;
     use super::*;
 
     #[test]
@@ -365,6 +392,13 @@ mod test {
         assert_eq!(float(0_f64), 0.);
     }
 
+    #[test]
+    fn test_try_cast() {
+        assert_eq!(try_uint(-1_i32), Err(GlmError::CastOutOfRange));
+        assert_eq!(try_int(3e10_f64), Err(GlmError::CastOutOfRange));
+        assert_eq!(try_int(vec2(-2., 1.)), Ok(-2));
+    }
+
     #[test]
     fn test_to_vec() {
         assert_eq!(to_vec2(bvec2(true, false)), vec2(1., 0.));
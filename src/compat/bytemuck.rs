@@ -0,0 +1,55 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+
+// `#[derive(Pod)]` does not support generic structs, so the traits are
+// implemented manually.
+
+use bytemuck::{ Pod, Zeroable };
+
+use basenum::{ Primitive, BaseFloat };
+use vec::vec::{ Vector2, Vector3, Vector4 };
+use mat::mat::*;
+use quat::Quaternion;
+
+macro_rules! impl_pod {
+    ($bound: ident, $($t: ident), +) => {
+        $(
+            // SAFETY: `$t` is `#[repr(C)]` and all its fields have the same
+            // type, so there is no padding, and it is all zeros if its
+            // fields are.
+            unsafe impl<T: $bound + Zeroable> Zeroable for $t<T> {}
+            // SAFETY: as above, and `$t` is `Copy` if `T` is.
+            unsafe impl<T: $bound + Pod> Pod for $t<T> {}
+        )+
+    }
+}
+
+impl_pod! { Primitive, Vector2, Vector3, Vector4 }
+impl_pod! {
+    BaseFloat,
+    Matrix2, Matrix3x2, Matrix4x2,
+    Matrix2x3, Matrix3, Matrix4x3,
+    Matrix2x4, Matrix3x4, Matrix4,
+    Quaternion
+}
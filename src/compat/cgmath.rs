@@ -0,0 +1,131 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+use cgmath;
+
+use basenum::{ Primitive, BaseFloat };
+use vec::vec::{ Vector2, Vector3, Vector4 };
+use mat::mat::{ Matrix2, Matrix3, Matrix4 };
+use quat::Quaternion;
+
+macro_rules! impl_vec_conv {
+    ($({ $t: ident, $ct: ident, $($field: ident), + }), +) => {
+        $(
+            impl<T: Primitive> From<cgmath::$ct<T>> for $t<T> {
+                #[inline]
+                fn from(v: cgmath::$ct<T>) -> $t<T> {
+                    $t::new($(v.$field), +)
+                }
+            }
+            impl<T: Primitive> From<$t<T>> for cgmath::$ct<T> {
+                #[inline]
+                fn from(v: $t<T>) -> cgmath::$ct<T> {
+                    cgmath::$ct::new($(v.$field), +)
+                }
+            }
+        )+
+    }
+}
+
+impl_vec_conv! {
+    { Vector2, Vector2, x, y },
+    { Vector3, Vector3, x, y, z },
+    { Vector4, Vector4, x, y, z, w },
+    { Vector2, Point2, x, y },
+    { Vector3, Point3, x, y, z }
+}
+
+macro_rules! impl_mat_conv {
+    ($({ $t: ident, $($field: ident : $cf: ident), + }), +) => {
+        $(
+            impl<T: BaseFloat> From<cgmath::$t<T>> for $t<T> {
+                #[inline]
+                fn from(m: cgmath::$t<T>) -> $t<T> {
+                    $t::new($(m.$cf.into()), +)
+                }
+            }
+            impl<T: BaseFloat> From<$t<T>> for cgmath::$t<T> {
+                #[inline]
+                fn from(m: $t<T>) -> cgmath::$t<T> {
+                    cgmath::$t { $($cf: m.$field.into()), + }
+                }
+            }
+        )+
+    }
+}
+
+impl_mat_conv! {
+    { Matrix2, c0: x, c1: y },
+    { Matrix3, c0: x, c1: y, c2: z },
+    { Matrix4, c0: x, c1: y, c2: z, c3: w }
+}
+
+impl<T: BaseFloat> From<cgmath::Quaternion<T>> for Quaternion<T> {
+    #[inline]
+    fn from(q: cgmath::Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(q.s, q.v.x, q.v.y, q.v.z)
+    }
+}
+
+impl<T: BaseFloat> From<Quaternion<T>> for cgmath::Quaternion<T> {
+    #[inline]
+    fn from(q: Quaternion<T>) -> cgmath::Quaternion<T> {
+        cgmath::Quaternion { s: q.w, v: cgmath::Vector3::new(q.x, q.y, q.z) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use cgmath;
+    use vec::vec::*;
+    use mat::ctor::*;
+    use mat::mat::Mat3;
+    use quat::*;
+
+    #[test]
+    fn test_vec_conv() {
+        let v = vec3(1., 2., 3.);
+        let cv: cgmath::Vector3<f32> = v.into();
+        assert_eq!(cv, cgmath::Vector3::new(1., 2., 3.));
+        assert_eq!(Vec3::from(cv), v);
+        let cp: cgmath::Point3<f32> = v.into();
+        assert_eq!(Vec3::from(cp), v);
+    }
+
+    #[test]
+    fn test_mat_conv() {
+        let m = mat3(1., 2., 3., 4., 5., 6., 7., 8., 9.);
+        let cm: cgmath::Matrix3<f32> = m.into();
+        assert_eq!(cm.y, cgmath::Vector3::new(4., 5., 6.));
+        assert_eq!(Mat3::from(cm), m);
+    }
+
+    #[test]
+    fn test_quat_conv() {
+        let q = quat(1., 2., 3., 4.);
+        let cq: cgmath::Quaternion<f32> = q.into();
+        assert_eq!(cq, cgmath::Quaternion::new(1., 2., 3., 4.));
+        assert_eq!(Quat::from(cq), q);
+    }
+}
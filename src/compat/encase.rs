@@ -0,0 +1,127 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+use encase::vector::{ VectorScalar, AsRefVectorParts, AsMutVectorParts, FromVectorParts };
+use encase::matrix::{ MatrixScalar, AsRefMatrixParts, AsMutMatrixParts, FromMatrixParts };
+
+use basenum::{ Primitive, BaseFloat };
+use vec::vec::{ Vector2, Vector3, Vector4 };
+use mat::mat::*;
+
+macro_rules! impl_shader_vector {
+    ($({ $t: ident, $n: expr }), +) => {
+        $(
+            impl<T: Primitive + VectorScalar> AsRefVectorParts<T, $n> for $t<T> {
+                #[inline]
+                fn as_ref_parts(&self) -> &[T; $n] {
+                    self.as_array()
+                }
+            }
+            impl<T: Primitive + VectorScalar> AsMutVectorParts<T, $n> for $t<T> {
+                #[inline]
+                fn as_mut_parts(&mut self) -> &mut [T; $n] {
+                    self.as_array_mut()
+                }
+            }
+            impl<T: Primitive + VectorScalar> FromVectorParts<T, $n> for $t<T> {
+                #[inline]
+                fn from_parts(parts: [T; $n]) -> $t<T> {
+                    *$t::from_array(&parts)
+                }
+            }
+            ::encase::impl_vector!($n, $t<T>; (T: Primitive));
+        )+
+    }
+}
+
+impl_shader_vector! {
+    { Vector2, 2 },
+    { Vector3, 3 },
+    { Vector4, 4 }
+}
+
+macro_rules! impl_shader_matrix {
+    ($({ $t: ident, $c: expr, $r: expr }), +) => {
+        $(
+            impl<T: BaseFloat + MatrixScalar> AsRefMatrixParts<T, $c, $r> for $t<T> {
+                #[inline]
+                fn as_ref_parts(&self) -> &[[T; $r]; $c] {
+                    self.as_column_arrays()
+                }
+            }
+            impl<T: BaseFloat + MatrixScalar> AsMutMatrixParts<T, $c, $r> for $t<T> {
+                #[inline]
+                fn as_mut_parts(&mut self) -> &mut [[T; $r]; $c] {
+                    self.as_column_arrays_mut()
+                }
+            }
+            impl<T: BaseFloat + MatrixScalar> FromMatrixParts<T, $c, $r> for $t<T> {
+                #[inline]
+                fn from_parts(parts: [[T; $r]; $c]) -> $t<T> {
+                    $t::from(parts)
+                }
+            }
+            ::encase::impl_matrix!($c, $r, $t<T>; (T: BaseFloat));
+        )+
+    }
+}
+
+impl_shader_matrix! {
+    { Matrix2,   2, 2 },
+    { Matrix3x2, 3, 2 },
+    { Matrix4x2, 4, 2 },
+    { Matrix2x3, 2, 3 },
+    { Matrix3,   3, 3 },
+    { Matrix4x3, 4, 3 },
+    { Matrix2x4, 2, 4 },
+    { Matrix3x4, 3, 4 },
+    { Matrix4,   4, 4 }
+}
+
+#[cfg(test)]
+mod test {
+
+    use encase::{ ShaderType, UniformBuffer };
+    use vec::vec::*;
+    use mat::ctor::*;
+    use mat::mat::*;
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(Vec3::min_size().get(), 12);
+        assert_eq!(Mat3::min_size().get(), 48);
+
+        let m = mat3(1., 2., 3., 4., 5., 6., 7., 8., 9.);
+        let mut buf = UniformBuffer::new(Vec::<u8>::new());
+        buf.write(&m).unwrap();
+        assert_eq!(buf.as_ref().len(), 48);
+        let m2: Mat3 = buf.create().unwrap();
+        assert_eq!(m2, m);
+
+        let v = vec4(1., 2., 3., 4.);
+        let mut buf = UniformBuffer::new(Vec::<u8>::new());
+        buf.write(&v).unwrap();
+        let v2: Vec4 = buf.create().unwrap();
+        assert_eq!(v2, v);
+    }
+}
@@ -0,0 +1,50 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Integration with other crates.
+//!
+//! Each integration is enabled by the cargo feature of the same name as the
+//! other crate.
+//!
+//! Conversions to and from the types of other math crates (`cgmath`,
+//! `nalgebra`) are implemented as `From` in both directions, e.g.,
+//!
+//! ~~~ignore
+//! let v: cgmath::Vector3<f32> = glm::vec3(1., 2., 3.).into();
+//! let m: glm::Mat4 = nalgebra::Matrix4::<f32>::identity().into();
+//! ~~~
+//!
+//! # Note
+//!
+//! `nalgebra` names matrices by rows-by-columns, while GLSL names them by
+//! columns-by-rows. So `Matrix2x3` of `glm` corresponds to `Matrix3x2` of
+//! `nalgebra`.
+
+#[cfg(feature = "cgmath")]
+mod cgmath;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "encase")]
+mod encase;
@@ -0,0 +1,161 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+use nalgebra;
+
+use basenum::{ Primitive, BaseFloat };
+use vec::vec::{ Vector2, Vector3, Vector4 };
+use mat::mat::*;
+use quat::Quaternion;
+
+macro_rules! impl_vec_conv {
+    ($({ $t: ident, $n: expr }), +) => {
+        $(
+            impl<T: Primitive + nalgebra::Scalar> From<nalgebra::SVector<T, $n>> for $t<T> {
+                #[inline]
+                fn from(v: nalgebra::SVector<T, $n>) -> $t<T> {
+                    let ary: [T; $n] = v.into();
+                    *$t::from_array(&ary)
+                }
+            }
+            impl<T: Primitive + nalgebra::Scalar> From<$t<T>> for nalgebra::SVector<T, $n> {
+                #[inline]
+                fn from(v: $t<T>) -> nalgebra::SVector<T, $n> {
+                    nalgebra::SVector::from(*v.as_array())
+                }
+            }
+            impl<T: Primitive + nalgebra::Scalar> From<nalgebra::Point<T, $n>> for $t<T> {
+                #[inline]
+                fn from(p: nalgebra::Point<T, $n>) -> $t<T> {
+                    p.coords.into()
+                }
+            }
+            impl<T: Primitive + nalgebra::Scalar> From<$t<T>> for nalgebra::Point<T, $n> {
+                #[inline]
+                fn from(v: $t<T>) -> nalgebra::Point<T, $n> {
+                    nalgebra::Point::from(*v.as_array())
+                }
+            }
+        )+
+    }
+}
+
+impl_vec_conv! {
+    { Vector2, 2 },
+    { Vector3, 3 },
+    { Vector4, 4 }
+}
+
+macro_rules! impl_mat_conv {
+    ($({ $t: ident, $ct: ident, $r: expr, $c: expr, $($i: expr => $field: ident), + }), +) => {
+        $(
+            impl<T: BaseFloat + nalgebra::Scalar> From<nalgebra::SMatrix<T, $r, $c>> for $t<T> {
+                #[inline]
+                fn from(m: nalgebra::SMatrix<T, $r, $c>) -> $t<T> {
+                    let ary: [[T; $r]; $c] = m.into();
+                    $t::new($(*$ct::from_array(&ary[$i])), +)
+                }
+            }
+            impl<T: BaseFloat + nalgebra::Scalar> From<$t<T>> for nalgebra::SMatrix<T, $r, $c> {
+                #[inline]
+                fn from(m: $t<T>) -> nalgebra::SMatrix<T, $r, $c> {
+                    nalgebra::SMatrix::from([$(*m.$field.as_array()), +])
+                }
+            }
+        )+
+    }
+}
+
+impl_mat_conv! {
+    { Matrix2,   Vector2, 2, 2, 0 => c0, 1 => c1 },
+    { Matrix3x2, Vector2, 2, 3, 0 => c0, 1 => c1, 2 => c2 },
+    { Matrix4x2, Vector2, 2, 4, 0 => c0, 1 => c1, 2 => c2, 3 => c3 },
+    { Matrix2x3, Vector3, 3, 2, 0 => c0, 1 => c1 },
+    { Matrix3,   Vector3, 3, 3, 0 => c0, 1 => c1, 2 => c2 },
+    { Matrix4x3, Vector3, 3, 4, 0 => c0, 1 => c1, 2 => c2, 3 => c3 },
+    { Matrix2x4, Vector4, 4, 2, 0 => c0, 1 => c1 },
+    { Matrix3x4, Vector4, 4, 3, 0 => c0, 1 => c1, 2 => c2 },
+    { Matrix4,   Vector4, 4, 4, 0 => c0, 1 => c1, 2 => c2, 3 => c3 }
+}
+
+impl<T: BaseFloat + nalgebra::Scalar> From<nalgebra::Quaternion<T>> for Quaternion<T> {
+    #[inline]
+    fn from(q: nalgebra::Quaternion<T>) -> Quaternion<T> {
+        // `coords` stores the imaginary parts first.
+        let c = q.coords;
+        Quaternion::new(c.w, c.x, c.y, c.z)
+    }
+}
+
+impl<T: BaseFloat + nalgebra::Scalar> From<Quaternion<T>> for nalgebra::Quaternion<T> {
+    #[inline]
+    fn from(q: Quaternion<T>) -> nalgebra::Quaternion<T> {
+        nalgebra::Quaternion::from_vector(nalgebra::Vector4::new(q.x, q.y, q.z, q.w))
+    }
+}
+
+impl<T: BaseFloat + nalgebra::Scalar> From<nalgebra::UnitQuaternion<T>> for Quaternion<T> {
+    #[inline]
+    fn from(q: nalgebra::UnitQuaternion<T>) -> Quaternion<T> {
+        q.into_inner().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use nalgebra;
+    use vec::vec::*;
+    use mat::ctor::*;
+    use mat::mat::*;
+    use quat::*;
+
+    #[test]
+    fn test_vec_conv() {
+        let v = vec3(1., 2., 3.);
+        let nv: nalgebra::Vector3<f32> = v.into();
+        assert_eq!(nv, nalgebra::Vector3::new(1., 2., 3.));
+        assert_eq!(Vec3::from(nv), v);
+        let np: nalgebra::Point3<f32> = v.into();
+        assert_eq!(Vec3::from(np), v);
+    }
+
+    #[test]
+    fn test_mat_conv() {
+        // 3 columns, 2 rows.
+        let m = mat3x2(1., 2., 3., 4., 5., 6.);
+        let nm: nalgebra::Matrix2x3<f32> = m.into();
+        assert_eq!(nm, nalgebra::Matrix2x3::new(1., 3., 5., 2., 4., 6.));
+        assert_eq!(Mat3x2::from(nm), m);
+    }
+
+    #[test]
+    fn test_quat_conv() {
+        let q = quat(1., 2., 3., 4.);
+        let nq: nalgebra::Quaternion<f32> = q.into();
+        assert_eq!(nq, nalgebra::Quaternion::new(1., 2., 3., 4.));
+        assert_eq!(Quat::from(nq), q);
+        let u = nalgebra::UnitQuaternion::identity();
+        assert_eq!(Quat::from(u), Quat::identity());
+    }
+}
@@ -0,0 +1,72 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors reported by the fallible (`try_*`) functions of this crate.
+///
+/// Most functions follow GLSL and never fail: they panic or return `NaN`s
+/// on invalid input instead. Their `try_*` counterparts report such input
+/// with this type, so that it can be propagated with `?`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert_eq!(try_inverse(&mat2(1., 2., 2., 4.)), Err(GlmError::SingularMatrix));
+/// assert_eq!(try_normalize(vec3(0., 0., 0.)), Err(GlmError::DegenerateInput));
+/// assert_eq!(try_uint(-1_i32), Err(GlmError::CastOutOfRange));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum GlmError {
+    /// The matrix is singular or nearly singular, so it has no inverse.
+    SingularMatrix,
+    /// The input is degenerate for the operation, e.g., normalizing a
+    /// zero-length vector.
+    DegenerateInput,
+    /// The value can not be represented in the target type of a cast.
+    CastOutOfRange,
+    /// The string can not be parsed, e.g., a malformed hex color.
+    InvalidFormat,
+    /// The number of elements does not match the dimension of the target
+    /// type, e.g., collecting `5` values into a `Vector4`.
+    LengthMismatch,
+}
+
+impl fmt::Display for GlmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match *self {
+            GlmError::SingularMatrix => "matrix is not invertible",
+            GlmError::DegenerateInput => "degenerate input",
+            GlmError::CastOutOfRange => "value is out of range of the target type",
+            GlmError::InvalidFormat => "invalid format",
+            GlmError::LengthMismatch => "number of elements does not match the dimension",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Error for GlmError {}
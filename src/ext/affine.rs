@@ -0,0 +1,216 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+use basenum::{ BaseFloat, ApproxEq };
+use vec::vec::{ Vector2, Vector3 };
+use mat::mat::{ Matrix2, Matrix3, Matrix3x2, Matrix4, Matrix4x3 };
+use mat::traits::GenSquareMat;
+use std::ops::Mul;
+use num::{ One, Zero };
+
+macro_rules! def_affine {
+    (
+        $t: ident, $m: ident, $sq: ident, $lin: ident, $v: ident,
+        $($c: ident), +; $tc: ident, $dim: expr
+    ) => {
+        impl<T: BaseFloat> $t<T> {
+            /// Constructs an affine transformation from its compact matrix
+            /// form, i.e., the linear part in the leading columns and the
+            /// translation in the last column.
+            #[inline(always)]
+            pub fn new(matrix: $m<T>) -> $t<T> {
+                $t { matrix: matrix }
+            }
+
+            /// Constructs an affine transformation from a linear
+            /// transformation and a translation.
+            #[inline]
+            pub fn from_parts(linear: &$lin<T>, translation: $v<T>) -> $t<T> {
+                $t::new($m::new($(linear.$c), +, translation))
+            }
+
+            /// Returns the identity transformation.
+            #[inline]
+            pub fn identity() -> $t<T> {
+                $t::from_parts(&$lin::one(), $v::zero())
+            }
+
+            /// Constructs an affine transformation from a square matrix by
+            /// dropping its last row.
+            ///
+            /// # Note
+            ///
+            /// The last row of `m` is assumed to be `(0, ..., 0, 1)`. It is not
+            /// checked.
+            #[inline]
+            pub fn from_matrix(m: &$sq<T>) -> $t<T> {
+                $t::new($m::new($(m.$c.without($dim)), +, m.$tc.without($dim)))
+            }
+
+            /// Returns the linear part of the transformation.
+            #[inline]
+            pub fn linear(&self) -> $lin<T> {
+                $lin::new($(self.matrix.$c), +)
+            }
+
+            /// Returns the translation part of the transformation.
+            #[inline(always)]
+            pub fn translation(&self) -> $v<T> {
+                self.matrix.$tc
+            }
+
+            /// Returns the equivalent square matrix.
+            #[inline]
+            pub fn to_matrix(&self) -> $sq<T> {
+                let zero = T::zero();
+                $sq::new(
+                    $(self.matrix.$c.extend(zero)), +,
+                    self.matrix.$tc.extend(T::one())
+                )
+            }
+
+            /// Returns the inverse transformation, or `None` if the linear
+            /// part is not invertible.
+            #[inline]
+            pub fn inverse(&self) -> Option<$t<T>> {
+                self.linear().inverse().map(|inv| {
+                    let t = inv.mul_v(&self.matrix.$tc);
+                    $t::from_parts(&inv, -t)
+                })
+            }
+
+            /// Transforms point `p`, i.e., applies the linear part and then
+            /// translates.
+            #[inline]
+            pub fn transform_point(&self, p: $v<T>) -> $v<T> {
+                self.linear().mul_v(&p) + self.matrix.$tc
+            }
+
+            /// Transforms vector `v`, i.e., only applies the linear part.
+            #[inline]
+            pub fn transform_vector(&self, v: $v<T>) -> $v<T> {
+                self.linear().mul_v(&v)
+            }
+        }
+
+        /// Composition. `a * b` applies `b` first, then `a`.
+        impl<T: BaseFloat> Mul<$t<T>> for $t<T> {
+            type Output = $t<T>;
+            #[inline]
+            fn mul(self, rhs: $t<T>) -> $t<T> {
+                let lin = self.linear();
+                $t::new($m::new(
+                    $(lin.mul_v(&rhs.matrix.$c)), +,
+                    self.transform_point(rhs.matrix.$tc)
+                ))
+            }
+        }
+
+        impl<T: BaseFloat> From<$t<T>> for $sq<T> {
+            #[inline]
+            fn from(a: $t<T>) -> $sq<T> {
+                a.to_matrix()
+            }
+        }
+
+        impl<T: BaseFloat> ApproxEq for $t<T> {
+            type BaseType = T;
+            #[inline]
+            fn is_close_to(&self, rhs: &$t<T>, max_diff: T) -> bool {
+                self.matrix.is_close_to(&rhs.matrix, max_diff)
+            }
+        }
+    }
+}
+
+/// A 2D affine transformation stored as a `Matrix3x2`, i.e., the first two
+/// rows of the equivalent `Matrix3`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let a = Affine2::from_parts(&mat2(2., 0., 0., 2.), vec2(1., 1.));
+/// assert_eq!(a.transform_point(vec2(1., 2.)), vec2(3., 5.));
+/// assert_eq!(a.transform_vector(vec2(1., 2.)), vec2(2., 4.));
+/// let inv = a.inverse().unwrap();
+/// assert_eq!(inv.transform_point(vec2(3., 5.)), vec2(1., 2.));
+/// assert_eq!(a.to_matrix() * vec3(1., 2., 1.), vec3(3., 5., 1.));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Affine2<T: BaseFloat> {
+    /// The compact matrix, translation in column `c2`.
+    pub matrix: Matrix3x2<T>,
+}
+
+/// A 3D affine transformation stored as a `Matrix4x3`, i.e., the first three
+/// rows of the equivalent `Matrix4`.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = translate(&num::one(), vec3(1., 2., 3.));
+/// let a = Affine3::from_matrix(&scale(&m, vec3(2., 2., 2.)));
+/// assert_eq!(a.transform_point(vec3(1., 1., 1.)), vec3(3., 4., 5.));
+/// assert_eq!(a.inverse().unwrap().transform_point(vec3(3., 4., 5.)), vec3(1., 1., 1.));
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Affine3<T: BaseFloat> {
+    /// The compact matrix, translation in column `c3`.
+    pub matrix: Matrix4x3<T>,
+}
+
+def_affine! { Affine2, Matrix3x2, Matrix3, Matrix2, Vector2, c0, c1; c2, 2 }
+def_affine! { Affine3, Matrix4x3, Matrix4, Matrix3, Vector3, c0, c1, c2; c3, 3 }
+
+#[cfg(test)]
+mod test {
+
+    use basenum::ApproxEq;
+    use vec::vec::vec3;
+    use mat::mat::Mat4;
+    use ext::matrix::{ rotate, translate, scale };
+    use num::One;
+    use super::*;
+
+    #[test]
+    fn test_affine3() {
+        let m = rotate(&translate(&Mat4::one(), vec3(1., -2., 0.5)), 0.7, vec3(1., 2., 3.));
+        let n = scale(&translate(&Mat4::one(), vec3(3., 0., -1.)), vec3(2., 1., 0.5));
+        let (a, b) = (Affine3::from_matrix(&m), Affine3::from_matrix(&n));
+        assert!((a * b).to_matrix().is_close_to(&(m * n), 1e-5));
+        assert!((a * a.inverse().unwrap()).is_close_to(&Affine3::identity(), 1e-5));
+        let p = vec3(0.3, -1.2, 4.);
+        let q = m * p.extend(1.);
+        assert!(a.transform_point(p).is_close_to(&q.xyz(), 1e-5));
+    }
+}
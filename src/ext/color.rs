@@ -0,0 +1,266 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Functions for colors stored in vectors.
+
+use basenum::{ BaseNum, BaseFloat };
+use vec::vec::{ Vector3, Vec3, Vec4, vec4 };
+use error::GlmError;
+use num;
+
+#[inline(always)]
+fn weigh<F: BaseFloat>(rgb: Vector3<F>, wr: f64, wg: f64, wb: f64) -> F {
+    let w = |x: f64| -> F { num::cast(x).unwrap() };
+    rgb.x * w(wr) + rgb.y * w(wg) + rgb.z * w(wb)
+}
+
+/// Returns the relative luminance of linear RGB color `rgb`, using the
+/// weights of ITU-R BT.709 (sRGB primaries).
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert!(is_approx_eq(&luminance(vec3(1., 1., 1.)), &1.));
+/// assert!(is_approx_eq(&luminance(vec3(0., 1., 0.)), &0.7152));
+/// ```
+#[inline]
+pub fn luminance<F: BaseFloat>(rgb: Vector3<F>) -> F {
+    weigh(rgb, 0.2126, 0.7152, 0.0722)
+}
+
+/// Returns the relative luminance of linear RGB color `rgb`, using the
+/// weights of ITU-R BT.2020.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert!(is_approx_eq(&luminance_rec2020(vec3(1., 1., 1.)), &1.));
+/// assert!(is_approx_eq(&luminance_rec2020(vec3(0., 1., 0.)), &0.678));
+/// ```
+#[inline]
+pub fn luminance_rec2020<F: BaseFloat>(rgb: Vector3<F>) -> F {
+    weigh(rgb, 0.2627, 0.6780, 0.0593)
+}
+
+/// Orders of the 8-bit channels of a color packed in a `u32`, from the most
+/// significant byte to the least significant one.
+///
+/// For example, `Rgba` is the `0xRRGGBBAA` notation common in UI code, while
+/// bytes `[R, G, B, A]` in memory read as a little-endian `u32` are `Abgr`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ChannelOrder {
+    /// `0xRRGGBBAA`.
+    Rgba,
+    /// `0xAARRGGBB`.
+    Argb,
+    /// `0xBBGGRRAA`.
+    Bgra,
+    /// `0xAABBGGRR`.
+    Abgr,
+}
+
+impl ChannelOrder {
+    // bit offsets of the red, green, blue and alpha channels.
+    #[inline]
+    fn shifts(self) -> [u32; 4] {
+        match self {
+            ChannelOrder::Rgba => [24, 16, 8, 0],
+            ChannelOrder::Argb => [16, 8, 0, 24],
+            ChannelOrder::Bgra => [8, 16, 24, 0],
+            ChannelOrder::Abgr => [0, 8, 16, 24],
+        }
+    }
+}
+
+/// Unpacks a color with 8-bit channels in `order` into a normalized RGBA
+/// vector.
+///
+/// Each channel `c` is converted as `c / 255`, as `unpackUnorm4x8` does.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec4;
+/// use glm::ext::{ color_from_packed, ChannelOrder };
+///
+/// let c = color_from_packed(0xFF0000FF, ChannelOrder::Argb);
+/// assert_eq!(c, vec4(0., 0., 1., 1.));
+/// ```
+#[inline]
+pub fn color_from_packed(c: u32, order: ChannelOrder) -> Vec4 {
+    let s = order.shifts();
+    let ch = |i: usize| -> f32 { ((c >> s[i]) & 0xFF) as f32 / 255. };
+    vec4(ch(0), ch(1), ch(2), ch(3))
+}
+
+/// Packs normalized RGBA color `color` into a `u32` with 8-bit channels in
+/// `order`.
+///
+/// Each component `c` is converted as `round(clamp(c, 0, 1) * 255)`, as
+/// `packUnorm4x8` does.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec4;
+/// use glm::ext::{ color_to_packed, ChannelOrder };
+///
+/// let c = vec4(1., 0.5, 0., 1.);
+/// assert_eq!(color_to_packed(c, ChannelOrder::Bgra), 0x0080FFFF);
+/// ```
+#[inline]
+pub fn color_to_packed(color: Vec4, order: ChannelOrder) -> u32 {
+    let s = order.shifts();
+    let ch = |x: f32, i: usize| -> u32 {
+        let u = (BaseNum::min(BaseNum::max(x, 0.), 1.) * 255.).round() as u32;
+        u << s[i]
+    };
+    ch(color.x, 0) | ch(color.y, 1) | ch(color.z, 2) | ch(color.w, 3)
+}
+
+/// Unpacks a `0xRRGGBBAA` color into a normalized RGBA vector.
+///
+/// This is `color_from_packed(c, ChannelOrder::Rgba)`.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec4;
+/// use glm::ext::color_from_rgba8;
+///
+/// assert_eq!(color_from_rgba8(0xFF00FF00), vec4(1., 0., 1., 0.));
+/// ```
+#[inline(always)]
+pub fn color_from_rgba8(c: u32) -> Vec4 {
+    color_from_packed(c, ChannelOrder::Rgba)
+}
+
+/// Packs normalized RGBA color `color` into a `0xRRGGBBAA` integer.
+///
+/// This is `color_to_packed(color, ChannelOrder::Rgba)`.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec4;
+/// use glm::ext::to_rgba8;
+///
+/// assert_eq!(to_rgba8(vec4(1., 0., 1., 0.)), 0xFF00FF00);
+/// assert_eq!(to_rgba8(vec4(2., -1., 0.2, 1.)), 0xFF0033FF);
+/// ```
+#[inline(always)]
+pub fn to_rgba8(color: Vec4) -> u32 {
+    color_to_packed(color, ChannelOrder::Rgba)
+}
+
+// parses `"#RRGGBB"` (alpha is `0xFF`) or `"#RRGGBBAA"` to `0xRRGGBBAA`.
+fn parse_hex(s: &str, allow_alpha: bool) -> Result<u32, GlmError> {
+    let digits = s.strip_prefix('#').unwrap_or(s);
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(GlmError::InvalidFormat);
+    }
+    let v = u32::from_str_radix(digits, 16).map_err(|_| GlmError::InvalidFormat);
+    match digits.len() {
+        6 => v.map(|c| (c << 8) | 0xFF),
+        8 if allow_alpha => v,
+        _ => Err(GlmError::InvalidFormat),
+    }
+}
+
+/// Parses an RGB color from a hex string `"#RRGGBB"`.
+///
+/// The leading `#` is optional, and hex digits are case-insensitive.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ vec3, GlmError };
+/// use glm::ext::rgb_from_hex;
+///
+/// assert_eq!(rgb_from_hex("#FF00ff"), Ok(vec3(1., 0., 1.)));
+/// assert_eq!(rgb_from_hex("#FF00"), Err(GlmError::InvalidFormat));
+/// ```
+#[inline]
+pub fn rgb_from_hex(s: &str) -> Result<Vec3, GlmError> {
+    parse_hex(s, false).map(|c| color_from_rgba8(c).xyz())
+}
+
+/// Parses an RGBA color from a hex string `"#RRGGBBAA"` or `"#RRGGBB"`.
+///
+/// The leading `#` is optional, and hex digits are case-insensitive. Alpha
+/// is `1` if it is omitted.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec4;
+/// use glm::ext::rgba_from_hex;
+///
+/// assert_eq!(rgba_from_hex("#FF00FF00"), Ok(vec4(1., 0., 1., 0.)));
+/// assert_eq!(rgba_from_hex("00FF00"), Ok(vec4(0., 1., 0., 1.)));
+/// assert!(rgba_from_hex("#GG00FF00").is_err());
+/// ```
+#[inline]
+pub fn rgba_from_hex(s: &str) -> Result<Vec4, GlmError> {
+    parse_hex(s, true).map(color_from_rgba8)
+}
+
+/// Formats RGB color `rgb` as a hex string `"#RRGGBB"`.
+///
+/// Components are clamped to `[0, 1]` first.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec3;
+/// use glm::ext::rgb_to_hex;
+///
+/// assert_eq!(rgb_to_hex(vec3(1., 0.2, 0.)), "#FF3300");
+/// ```
+#[inline]
+pub fn rgb_to_hex(rgb: Vec3) -> String {
+    format!("#{:06X}", to_rgba8(rgb.extend(1.)) >> 8)
+}
+
+/// Formats RGBA color `rgba` as a hex string `"#RRGGBBAA"`.
+///
+/// Components are clamped to `[0, 1]` first.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec4;
+/// use glm::ext::rgba_to_hex;
+///
+/// assert_eq!(rgba_to_hex(vec4(1., 0.2, 0., 0.5)), "#FF330080");
+/// ```
+#[inline]
+pub fn rgba_to_hex(rgba: Vec4) -> String {
+    format!("#{:08X}", to_rgba8(rgba))
+}
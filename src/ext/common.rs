@@ -21,9 +21,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
-use basenum::BaseFloat;
-use traits::GenFloat;
+use basenum::{ BaseFloat, BaseNum, Primitive };
+use builtin::{ max, min };
+use traits::{ GenBType, GenFloat, GenNum };
+use vec::vec::{ Vector2, Vector3, Vector4 };
+use quat::Quaternion;
 use num::Float;
+use std::marker::PhantomData;
 
 /// Returns the reciprocal (inverse) of float number `x`.
 ///
@@ -41,3 +45,305 @@ use num::Float;
 pub fn recip<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
     x.map(Float::recip)
 }
+
+/// Returns `sqrt(x² + y²)` component-wise, without undue overflow or
+/// underflow of the intermediate squares.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec2;
+/// use glm::ext::hypot;
+///
+/// assert_eq!(hypot(3_f32, 4.), 5.);
+/// assert_eq!(hypot(vec2(3e30, 3e-30), vec2(4e30, 4e-30)), vec2(5e30, 5e-30));
+/// ```
+#[inline(always)]
+pub fn hypot<F: BaseFloat, T: GenFloat<F>>(x: T, y: T) -> T {
+    x.zip(y, Float::hypot)
+}
+
+/// Types whose components can be selected by a boolean mask of type `B`.
+pub trait Select<B: GenBType>: Copy {
+    /// Returns `y` for the components where `mask` is `true`, and `self`
+    /// otherwise.
+    fn select(self, y: Self, mask: B) -> Self;
+}
+
+macro_rules! impl_scalar_Select {
+    ($($t: ty),+) => {
+        $(
+            impl Select<bool> for $t {
+                #[inline(always)]
+                fn select(self, y: $t, mask: bool) -> $t {
+                    if mask { y } else { self }
+                }
+            }
+        )+
+    }
+}
+
+impl_scalar_Select! { i32, u32, f32, f64, bool }
+
+macro_rules! impl_vec_Select {
+    ($({ $t: ident, $($field: ident),+ }),+) => {
+        $(
+            impl<T: Primitive> Select<$t<bool>> for $t<T> {
+                #[inline]
+                fn select(self, y: $t<T>, mask: $t<bool>) -> $t<T> {
+                    $t::new($(if mask.$field { y.$field } else { self.$field }),+)
+                }
+            }
+        )+
+    }
+}
+
+impl_vec_Select! {
+    { Vector2, x, y },
+    { Vector3, x, y, z },
+    { Vector4, x, y, z, w }
+}
+
+/// Selects components from `x` or `y` by boolean mask `mask`, i.e., the
+/// `mix(x, y, a)` of GLSL 4.5 with a boolean `a`.
+///
+/// For a component of `mask` that is `false`, the corresponding component of
+/// `x` is returned, otherwise that of `y` is returned.
+///
+/// Unlike `mix_bool`, this works for integer and boolean vectors too, and
+/// non-selected components never affect the result, even if they are `NaN`
+/// or infinity.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::select;
+///
+/// let m = lessThan(ivec3(1, 5, 3), ivec3(2, 2, 2));
+/// assert_eq!(select(ivec3(0, 0, 0), ivec3(1, 2, 3), m), ivec3(1, 0, 0));
+/// assert_eq!(select(bvec2(true, true), bvec2(false, false), bvec2(true, false)), bvec2(false, true));
+/// let inf = 1. / 0_f32;
+/// assert_eq!(select(vec2(inf, 1.), vec2(2., inf), bvec2(true, false)), vec2(2., 1.));
+/// assert_eq!(select(1_u32, 2, true), 2);
+/// ```
+#[inline(always)]
+pub fn select<B: GenBType, T: Select<B>>(x: T, y: T, mask: B) -> T {
+    x.select(y, mask)
+}
+
+/// Moves `a` towards `b` with exponential decay, i.e., frame-rate
+/// independent smoothing.
+///
+/// `lambda` is the decay rate (larger is faster), and `dt` is the elapsed
+/// time. Unlike `mix(a, b, t)` with a constant `t` per frame, calling this
+/// twice with `dt` gives the same result as calling it once with `2 * dt`.
+///
+/// This is `mix(a, b, 1 - exp(-lambda * dt))`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::damp;
+///
+/// let (a, b) = (vec2(0., 0.), vec2(10., 20.));
+/// let once = damp(a, b, 2., 0.5);
+/// let twice = damp(damp(a, b, 2., 0.25), b, 2., 0.25);
+/// assert!(once.is_close_to(&twice, 1e-5));
+/// assert_eq!(damp(1_f64, 2., 3., 0.), 1.);
+/// ```
+#[inline]
+pub fn damp<F: BaseFloat, T: GenFloat<F>>(a: T, b: T, lambda: F, dt: F) -> T {
+    let t = F::one() - (-lambda * dt).exp();
+    a + (b - a) * t
+}
+
+/// Rotates `a` towards `b` with exponential decay, i.e., the quaternion
+/// variant of `damp`.
+///
+/// This is `a.slerp(&b, 1 - exp(-lambda * dt))`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::damp_quat;
+///
+/// let (a, b) = (Quat::identity(), Quat::from_axis_angle(vec3(0., 1., 0.), 2.));
+/// let once = damp_quat(a, b, 2., 0.5);
+/// let twice = damp_quat(damp_quat(a, b, 2., 0.25), b, 2., 0.25);
+/// assert!(is_close_to(&once, &twice, 1e-5));
+/// ```
+#[inline]
+pub fn damp_quat<F: BaseFloat>(
+    a: Quaternion<F>, b: Quaternion<F>, lambda: F, dt: F
+) -> Quaternion<F> {
+    a.slerp(&b, F::one() - (-lambda * dt).exp())
+}
+
+/// Returns a value with the magnitude of `x` and the sign of `y`,
+/// component-wise.
+///
+/// The sign bit of `y` is used, so `-0.0` counts as negative.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec3;
+/// use glm::ext::copysign;
+///
+/// assert_eq!(copysign(2_f32, -0.), -2.);
+/// assert_eq!(copysign(vec3(1., -2., 3.), vec3(-1., 1., 0.)), vec3(-1., 2., 3.));
+/// ```
+#[inline(always)]
+pub fn copysign<F: BaseFloat, T: GenFloat<F>>(x: T, y: T) -> T {
+    x.zip(y, |a, b| -> F {
+        if b.is_sign_negative() { -a.abs() } else { a.abs() }
+    })
+}
+
+/// Returns the minimum of `x`, `y` and `z`, component-wise.
+///
+/// # Example
+///
+/// ```
+/// use glm::ivec2;
+/// use glm::ext::min3;
+///
+/// assert_eq!(min3(3, 1, 2), 1);
+/// assert_eq!(min3(ivec2(1, 6), ivec2(2, 5), ivec2(3, 4)), ivec2(1, 4));
+/// ```
+#[inline(always)]
+pub fn min3<S: BaseNum, T: GenNum<S>>(x: T, y: T, z: T) -> T {
+    min(min(x, y), z)
+}
+
+/// Returns the minimum of `x`, `y`, `z` and `w`, component-wise.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec2;
+/// use glm::ext::min4;
+///
+/// let m = min4(vec2(1., 8.), vec2(2., 7.), vec2(0., 6.), vec2(3., 9.));
+/// assert_eq!(m, vec2(0., 6.));
+/// ```
+#[inline(always)]
+pub fn min4<S: BaseNum, T: GenNum<S>>(x: T, y: T, z: T, w: T) -> T {
+    min(min(x, y), min(z, w))
+}
+
+/// Returns the maximum of `x`, `y` and `z`, component-wise.
+///
+/// # Example
+///
+/// ```
+/// use glm::ivec2;
+/// use glm::ext::max3;
+///
+/// assert_eq!(max3(3, 1, 2), 3);
+/// assert_eq!(max3(ivec2(1, 6), ivec2(2, 5), ivec2(3, 4)), ivec2(3, 6));
+/// ```
+#[inline(always)]
+pub fn max3<S: BaseNum, T: GenNum<S>>(x: T, y: T, z: T) -> T {
+    max(max(x, y), z)
+}
+
+/// Returns the maximum of `x`, `y`, `z` and `w`, component-wise.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec2;
+/// use glm::ext::max4;
+///
+/// let m = max4(vec2(1., 8.), vec2(2., 7.), vec2(0., 6.), vec2(3., 9.));
+/// assert_eq!(m, vec2(3., 9.));
+/// ```
+#[inline(always)]
+pub fn max4<S: BaseNum, T: GenNum<S>>(x: T, y: T, z: T, w: T) -> T {
+    max(max(x, y), max(z, w))
+}
+
+/// Returns the median of `x`, `y` and `z`, component-wise.
+///
+/// # Example
+///
+/// ```
+/// use glm::uvec3;
+/// use glm::ext::mid3;
+///
+/// assert_eq!(mid3(3., 1., 2.), 2.);
+/// let m = mid3(uvec3(1, 6, 2), uvec3(2, 5, 2), uvec3(3, 4, 1));
+/// assert_eq!(m, uvec3(2, 5, 2));
+/// ```
+#[inline(always)]
+pub fn mid3<S: BaseNum, T: GenNum<S>>(x: T, y: T, z: T) -> T {
+    max(min(x, y), min(max(x, y), z))
+}
+
+/// Iterator over evenly spaced values between two endpoints.
+///
+/// It is returned by function `linspace`.
+#[derive(Copy, Clone, Debug)]
+pub struct Linspace<F: BaseFloat, T: GenFloat<F>> {
+    a: T,
+    b: T,
+    n: usize,
+    i: usize,
+    phantom: PhantomData<F>,
+}
+
+impl<F: BaseFloat, T: GenFloat<F>> Iterator for Linspace<F, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.i >= self.n {
+            return None;
+        }
+        let i = self.i;
+        self.i += 1;
+        if i == 0 {
+            Some(self.a)
+        } else if i + 1 == self.n {
+            // hit the end point exactly.
+            Some(self.b)
+        } else {
+            let t = F::from(i).unwrap() / F::from(self.n - 1).unwrap();
+            Some(self.a + (self.b - self.a) * t)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let k = self.n - self.i;
+        (k, Some(k))
+    }
+}
+
+impl<F: BaseFloat, T: GenFloat<F>> ExactSizeIterator for Linspace<F, T> {}
+
+/// Returns an iterator of `n` evenly spaced values from `a` to `b`,
+/// inclusively.
+///
+/// If `n` is `1`, only `a` is produced. If `n` is `0`, nothing is produced.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::linspace;
+///
+/// let xs: Vec<f32> = linspace(0., 1., 5).collect();
+/// assert_eq!(xs, vec![0., 0.25, 0.5, 0.75, 1.]);
+///
+/// let vs: Vec<Vec2> = linspace(vec2(0., 4.), vec2(2., 0.), 3).collect();
+/// assert_eq!(vs, vec![vec2(0., 4.), vec2(1., 2.), vec2(2., 0.)]);
+/// ```
+#[inline]
+pub fn linspace<F: BaseFloat, T: GenFloat<F>>(a: T, b: T, n: usize) -> Linspace<F, T> {
+    Linspace { a: a, b: b, n: n, i: 0, phantom: PhantomData }
+}
@@ -0,0 +1,606 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+use basenum::{ BaseFloat, BaseNum, SignedNum, Primitive, ApproxEq };
+use traits::{ GenNum, NumType, GenFloat };
+use std::num::FpCategory;
+use std::ops::{ Add, Sub, Mul, Div, Rem, Neg };
+use rand::{ Rand, Rng };
+use num::{ Float, Num, NumCast, ToPrimitive, One, Zero };
+
+/// A dual number `real + dual·ε`, where `ε² = 0`.
+///
+/// Evaluating a function `f` at `Dual::variable(x)` yields `f(x)` in the
+/// `real` part and the exact derivative `f'(x)` in the `dual` part, i.e.,
+/// forward mode automatic differentiation.
+///
+/// `Dual<T>` implements `BaseFloat`, so it can be used as the component type
+/// of vectors, and with the generic built-in functions. Comparisons only
+/// look at the `real` parts unless they are equal.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::Dual;
+///
+/// // d/dx sin(x) = cos(x).
+/// let y = sin(Dual::variable(1_f64));
+/// assert_eq!(y.real, 1_f64.sin());
+/// assert_eq!(y.dual, 1_f64.cos());
+///
+/// // Gradient of a distance function along `x`.
+/// let p = Vector2::new(Dual::variable(3_f64), Dual::constant(4.));
+/// let l = length(p);
+/// assert_eq!(l.real, 5.);
+/// assert!((l.dual - 0.6).abs() < 1e-12);
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Dual<T: BaseFloat> {
+    /// The value.
+    pub real: T,
+    /// The derivative.
+    pub dual: T,
+}
+
+impl<T: BaseFloat> Dual<T> {
+    /// Constructs a dual number from its parts.
+    #[inline(always)]
+    pub fn new(real: T, dual: T) -> Dual<T> {
+        Dual { real: real, dual: dual }
+    }
+
+    /// Constructs a constant, i.e., a number whose derivative is `0`.
+    #[inline(always)]
+    pub fn constant(x: T) -> Dual<T> {
+        Dual::new(x, T::zero())
+    }
+
+    /// Constructs the variable to be differentiated against, i.e., a number
+    /// whose derivative is `1`.
+    #[inline(always)]
+    pub fn variable(x: T) -> Dual<T> {
+        Dual::new(x, T::one())
+    }
+
+    // Applies the chain rule, `f(x) = v` and `f'(x) = d`.
+    #[inline(always)]
+    fn chain(self, v: T, d: T) -> Dual<T> {
+        Dual::new(v, self.dual * d)
+    }
+}
+
+impl<T: BaseFloat> Add<Dual<T>> for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn add(self, rhs: Dual<T>) -> Dual<T> {
+        Dual::new(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+
+impl<T: BaseFloat> Sub<Dual<T>> for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn sub(self, rhs: Dual<T>) -> Dual<T> {
+        Dual::new(self.real - rhs.real, self.dual - rhs.dual)
+    }
+}
+
+impl<T: BaseFloat> Mul<Dual<T>> for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn mul(self, rhs: Dual<T>) -> Dual<T> {
+        Dual::new(
+            self.real * rhs.real,
+            self.real * rhs.dual + self.dual * rhs.real
+        )
+    }
+}
+
+impl<T: BaseFloat> Div<Dual<T>> for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn div(self, rhs: Dual<T>) -> Dual<T> {
+        let r = self.real / rhs.real;
+        Dual::new(r, (self.dual - r * rhs.dual) / rhs.real)
+    }
+}
+
+impl<T: BaseFloat> Rem<Dual<T>> for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn rem(self, rhs: Dual<T>) -> Dual<T> {
+        let q = (self.real / rhs.real).trunc();
+        Dual::new(self.real % rhs.real, self.dual - rhs.dual * q)
+    }
+}
+
+impl<T: BaseFloat> Neg for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn neg(self) -> Dual<T> {
+        Dual::new(-self.real, -self.dual)
+    }
+}
+
+impl<T: BaseFloat> Zero for Dual<T> {
+    #[inline(always)]
+    fn zero() -> Dual<T> {
+        Dual::constant(T::zero())
+    }
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.dual.is_zero()
+    }
+}
+
+impl<T: BaseFloat> One for Dual<T> {
+    #[inline(always)]
+    fn one() -> Dual<T> {
+        Dual::constant(T::one())
+    }
+}
+
+impl<T: BaseFloat> Num for Dual<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Dual<T>, T::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(Dual::constant)
+    }
+}
+
+impl<T: BaseFloat> ToPrimitive for Dual<T> {
+    #[inline(always)]
+    fn to_i64(&self) -> Option<i64> {
+        self.real.to_i64()
+    }
+    #[inline(always)]
+    fn to_u64(&self) -> Option<u64> {
+        self.real.to_u64()
+    }
+    #[inline(always)]
+    fn to_f32(&self) -> Option<f32> {
+        self.real.to_f32()
+    }
+    #[inline(always)]
+    fn to_f64(&self) -> Option<f64> {
+        self.real.to_f64()
+    }
+}
+
+impl<T: BaseFloat> NumCast for Dual<T> {
+    #[inline(always)]
+    fn from<N: ToPrimitive>(n: N) -> Option<Dual<T>> {
+        <T as NumCast>::from(n).map(Dual::constant)
+    }
+}
+
+impl<T: BaseFloat> Rand for Dual<T> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Dual<T> {
+        Dual::constant(rng.gen())
+    }
+}
+
+impl<T: BaseFloat> Float for Dual<T> {
+    #[inline(always)]
+    fn nan() -> Dual<T> {
+        Dual::constant(T::nan())
+    }
+    #[inline(always)]
+    fn infinity() -> Dual<T> {
+        Dual::constant(T::infinity())
+    }
+    #[inline(always)]
+    fn neg_infinity() -> Dual<T> {
+        Dual::constant(T::neg_infinity())
+    }
+    #[inline(always)]
+    fn neg_zero() -> Dual<T> {
+        Dual::constant(T::neg_zero())
+    }
+    #[inline(always)]
+    fn min_value() -> Dual<T> {
+        Dual::constant(T::min_value())
+    }
+    #[inline(always)]
+    fn min_positive_value() -> Dual<T> {
+        Dual::constant(T::min_positive_value())
+    }
+    #[inline(always)]
+    fn epsilon() -> Dual<T> {
+        Dual::constant(T::epsilon())
+    }
+    #[inline(always)]
+    fn max_value() -> Dual<T> {
+        Dual::constant(T::max_value())
+    }
+    #[inline(always)]
+    fn is_nan(self) -> bool {
+        self.real.is_nan() || self.dual.is_nan()
+    }
+    #[inline(always)]
+    fn is_infinite(self) -> bool {
+        self.real.is_infinite()
+    }
+    #[inline(always)]
+    fn is_finite(self) -> bool {
+        self.real.is_finite()
+    }
+    #[inline(always)]
+    fn is_normal(self) -> bool {
+        self.real.is_normal()
+    }
+    #[inline(always)]
+    fn classify(self) -> FpCategory {
+        self.real.classify()
+    }
+    #[inline(always)]
+    fn floor(self) -> Dual<T> {
+        Dual::constant(self.real.floor())
+    }
+    #[inline(always)]
+    fn ceil(self) -> Dual<T> {
+        Dual::constant(self.real.ceil())
+    }
+    #[inline(always)]
+    fn round(self) -> Dual<T> {
+        Dual::constant(self.real.round())
+    }
+    #[inline(always)]
+    fn trunc(self) -> Dual<T> {
+        Dual::constant(self.real.trunc())
+    }
+    #[inline(always)]
+    fn fract(self) -> Dual<T> {
+        Dual::new(self.real.fract(), self.dual)
+    }
+    #[inline(always)]
+    fn abs(self) -> Dual<T> {
+        if self.real.is_sign_negative() { -self } else { self }
+    }
+    #[inline(always)]
+    fn signum(self) -> Dual<T> {
+        Dual::constant(self.real.signum())
+    }
+    #[inline(always)]
+    fn is_sign_positive(self) -> bool {
+        self.real.is_sign_positive()
+    }
+    #[inline(always)]
+    fn is_sign_negative(self) -> bool {
+        self.real.is_sign_negative()
+    }
+    #[inline(always)]
+    fn mul_add(self, a: Dual<T>, b: Dual<T>) -> Dual<T> {
+        self.fused_mul_add(a, b)
+    }
+    #[inline(always)]
+    fn recip(self) -> Dual<T> {
+        let r = self.real.recip();
+        self.chain(r, -r * r)
+    }
+    #[inline]
+    fn powi(self, n: i32) -> Dual<T> {
+        let d = if n == 0 {
+            T::zero()
+        } else {
+            <T as NumCast>::from(n).unwrap() * self.real.powi(n - 1)
+        };
+        self.chain(self.real.powi(n), d)
+    }
+    #[inline]
+    fn powf(self, n: Dual<T>) -> Dual<T> {
+        let v = self.real.powf(n.real);
+        let d = n.real * self.real.powf(n.real - T::one()) * self.dual;
+        if n.dual.is_zero() {
+            Dual::new(v, d)
+        } else {
+            Dual::new(v, d + v * self.real.ln() * n.dual)
+        }
+    }
+    #[inline(always)]
+    fn sqrt(self) -> Dual<T> {
+        let s = self.real.sqrt();
+        self.chain(s, (s + s).recip())
+    }
+    #[inline(always)]
+    fn exp(self) -> Dual<T> {
+        let e = self.real.exp();
+        self.chain(e, e)
+    }
+    #[inline(always)]
+    fn exp2(self) -> Dual<T> {
+        let e = self.real.exp2();
+        let ln2 = (T::one() + T::one()).ln();
+        self.chain(e, e * ln2)
+    }
+    #[inline(always)]
+    fn ln(self) -> Dual<T> {
+        self.chain(self.real.ln(), self.real.recip())
+    }
+    #[inline(always)]
+    fn log(self, base: Dual<T>) -> Dual<T> {
+        self.ln() / base.ln()
+    }
+    #[inline(always)]
+    fn log2(self) -> Dual<T> {
+        let ln2 = (T::one() + T::one()).ln();
+        self.chain(self.real.log2(), (self.real * ln2).recip())
+    }
+    #[inline(always)]
+    fn log10(self) -> Dual<T> {
+        let ln10 = <T as NumCast>::from(10).unwrap().ln();
+        self.chain(self.real.log10(), (self.real * ln10).recip())
+    }
+    #[inline(always)]
+    fn to_degrees(self) -> Dual<T> {
+        Dual::new(Float::to_degrees(self.real), Float::to_degrees(self.dual))
+    }
+    #[inline(always)]
+    fn to_radians(self) -> Dual<T> {
+        Dual::new(Float::to_radians(self.real), Float::to_radians(self.dual))
+    }
+    #[inline(always)]
+    fn max(self, other: Dual<T>) -> Dual<T> {
+        BaseNum::max(self, other)
+    }
+    #[inline(always)]
+    fn min(self, other: Dual<T>) -> Dual<T> {
+        BaseNum::min(self, other)
+    }
+    #[inline(always)]
+    fn abs_sub(self, other: Dual<T>) -> Dual<T> {
+        if self.real > other.real { self - other } else { Dual::zero() }
+    }
+    #[inline(always)]
+    fn cbrt(self) -> Dual<T> {
+        let c = self.real.cbrt();
+        let three = <T as NumCast>::from(3).unwrap();
+        self.chain(c, (three * c * c).recip())
+    }
+    #[inline(always)]
+    fn hypot(self, other: Dual<T>) -> Dual<T> {
+        let h = self.real.hypot(other.real);
+        Dual::new(h, (self.real * self.dual + other.real * other.dual) / h)
+    }
+    #[inline(always)]
+    fn sin(self) -> Dual<T> {
+        self.chain(self.real.sin(), self.real.cos())
+    }
+    #[inline(always)]
+    fn cos(self) -> Dual<T> {
+        self.chain(self.real.cos(), -self.real.sin())
+    }
+    #[inline(always)]
+    fn tan(self) -> Dual<T> {
+        let t = self.real.tan();
+        self.chain(t, T::one() + t * t)
+    }
+    #[inline(always)]
+    fn asin(self) -> Dual<T> {
+        let d = (T::one() - self.real * self.real).sqrt().recip();
+        self.chain(self.real.asin(), d)
+    }
+    #[inline(always)]
+    fn acos(self) -> Dual<T> {
+        let d = (T::one() - self.real * self.real).sqrt().recip();
+        self.chain(self.real.acos(), -d)
+    }
+    #[inline(always)]
+    fn atan(self) -> Dual<T> {
+        let d = (T::one() + self.real * self.real).recip();
+        self.chain(self.real.atan(), d)
+    }
+    #[inline(always)]
+    fn atan2(self, other: Dual<T>) -> Dual<T> {
+        let (y, x) = (self, other);
+        let r2 = y.real * y.real + x.real * x.real;
+        Dual::new(
+            y.real.atan2(x.real),
+            (x.real * y.dual - y.real * x.dual) / r2
+        )
+    }
+    #[inline(always)]
+    fn sin_cos(self) -> (Dual<T>, Dual<T>) {
+        (self.sin(), self.cos())
+    }
+    #[inline(always)]
+    fn exp_m1(self) -> Dual<T> {
+        self.chain(self.real.exp_m1(), self.real.exp())
+    }
+    #[inline(always)]
+    fn ln_1p(self) -> Dual<T> {
+        self.chain(self.real.ln_1p(), (T::one() + self.real).recip())
+    }
+    #[inline(always)]
+    fn sinh(self) -> Dual<T> {
+        self.chain(self.real.sinh(), self.real.cosh())
+    }
+    #[inline(always)]
+    fn cosh(self) -> Dual<T> {
+        self.chain(self.real.cosh(), self.real.sinh())
+    }
+    #[inline(always)]
+    fn tanh(self) -> Dual<T> {
+        let t = self.real.tanh();
+        self.chain(t, T::one() - t * t)
+    }
+    #[inline(always)]
+    fn asinh(self) -> Dual<T> {
+        let d = (self.real * self.real + T::one()).sqrt().recip();
+        self.chain(self.real.asinh(), d)
+    }
+    #[inline(always)]
+    fn acosh(self) -> Dual<T> {
+        let d = (self.real * self.real - T::one()).sqrt().recip();
+        self.chain(self.real.acosh(), d)
+    }
+    #[inline(always)]
+    fn atanh(self) -> Dual<T> {
+        let d = (T::one() - self.real * self.real).recip();
+        self.chain(self.real.atanh(), d)
+    }
+    #[inline(always)]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.real.integer_decode()
+    }
+}
+
+impl<T: BaseFloat> Primitive for Dual<T> {}
+
+impl<T: BaseFloat> BaseNum for Dual<T> {
+    #[inline(always)]
+    fn min(self, other: Dual<T>) -> Dual<T> {
+        if other.real < self.real || self.real.is_nan() { other } else { self }
+    }
+    #[inline(always)]
+    fn max(self, other: Dual<T>) -> Dual<T> {
+        if other.real > self.real || self.real.is_nan() { other } else { self }
+    }
+}
+
+impl<T: BaseFloat> SignedNum for Dual<T> {
+    #[inline(always)]
+    fn abs(&self) -> Dual<T> {
+        Float::abs(*self)
+    }
+    #[inline(always)]
+    fn sign(&self) -> Dual<T> {
+        Dual::constant(SignedNum::sign(&self.real))
+    }
+}
+
+impl<T: BaseFloat> ApproxEq for Dual<T> {
+    type BaseType = Dual<T>;
+    #[inline]
+    fn is_close_to(&self, rhs: &Dual<T>, max_diff: Dual<T>) -> bool {
+        self.real.is_close_to(&rhs.real, max_diff.real) &&
+        self.dual.is_close_to(&rhs.dual, max_diff.real)
+    }
+}
+
+impl<T: BaseFloat> BaseFloat for Dual<T> {
+    #[inline(always)]
+    fn to_degrees(self) -> Dual<T> {
+        Float::to_degrees(self)
+    }
+    #[inline(always)]
+    fn to_radians(self) -> Dual<T> {
+        Float::to_radians(self)
+    }
+    #[inline]
+    fn frexp(self) -> (Dual<T>, isize) {
+        let (m, e) = self.real.frexp();
+        let s = T::one().ldexp(-e);
+        (Dual::new(m, self.dual * s), e)
+    }
+    #[inline]
+    fn ldexp(self, exp: isize) -> Dual<T> {
+        Dual::new(self.real.ldexp(exp), self.dual.ldexp(exp))
+    }
+    #[inline(always)]
+    fn rsqrt(self) -> Dual<T> {
+        self.sqrt().recip()
+    }
+    #[inline(always)]
+    fn fused_mul_add(self, a: Dual<T>, b: Dual<T>) -> Dual<T> {
+        Dual::new(
+            self.real.fused_mul_add(a.real, b.real),
+            self.real.fused_mul_add(a.dual, self.dual * a.real) + b.dual
+        )
+    }
+}
+
+impl<T: BaseFloat> NumType for Dual<T> {
+    type Scalar = Dual<T>;
+}
+
+impl<T: BaseFloat> GenNum<Dual<T>> for Dual<T> {
+    #[inline(always)]
+    fn from_s(x: Dual<T>) -> Dual<T> {
+        x
+    }
+    #[inline(always)]
+    fn map<F: Fn(Dual<T>) -> Dual<T>>(self, f: F) -> Dual<T> {
+        f(self)
+    }
+    #[inline(always)]
+    fn zip<F: Fn(Dual<T>, Dual<T>) -> Dual<T>>(self, y: Dual<T>, f: F) -> Dual<T> {
+        f(self, y)
+    }
+    #[inline(always)]
+    fn split<F: Fn(Dual<T>) -> (Dual<T>, Dual<T>)>(self, f: F) -> (Dual<T>, Dual<T>) {
+        f(self)
+    }
+    #[inline(always)]
+    fn map2<F>(self, y: Dual<T>, f: F) -> (Dual<T>, Dual<T>)
+    where F: Fn(Dual<T>, Dual<T>) -> (Dual<T>, Dual<T>) {
+        f(self, y)
+    }
+}
+
+impl<T: BaseFloat> GenFloat<Dual<T>> for Dual<T> {
+    #[inline(always)]
+    fn fma(&self, b: &Dual<T>, c: &Dual<T>) -> Dual<T> {
+        self.fused_mul_add(*b, *c)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use builtin::*;
+    use vec::vec::{ Vector3, dvec3 };
+    use super::Dual;
+
+    #[test]
+    fn test_derivatives() {
+        let x = Dual::variable(0.5_f64);
+        let y = smoothstep(Dual::constant(0.), Dual::constant(1.), x);
+        assert_eq!(y.real, 0.5);
+        assert_eq!(y.dual, 1.5);
+        let y = exp(x * x);
+        assert!((y.dual - 2. * 0.5 * 0.25_f64.exp()).abs() < 1e-12);
+        let y = atan(x);
+        assert!((y.dual - 0.8).abs() < 1e-12_f64);
+    }
+
+    #[test]
+    fn test_gradient() {
+        // distance from the origin to a sphere of radius `1`.
+        let sdf = |p: Vector3<Dual<f64>>| -> Dual<f64> {
+            length(p) - Dual::constant(1.)
+        };
+        let p = dvec3(1., 2., 2.);
+        let (x, y, z) = (Dual::constant(p.x), Dual::constant(p.y), Dual::constant(p.z));
+        let dx = sdf(Vector3::new(Dual::variable(p.x), y, z));
+        let dy = sdf(Vector3::new(x, Dual::variable(p.y), z));
+        assert_eq!(dx.real, 2.);
+        assert!((dx.dual - 1. / 3.).abs() < 1e-12);
+        assert!((dy.dual - 2. / 3.).abs() < 1e-12);
+        let x = Dual::variable(3_f64);
+        let n = normalize(Vector3::new(x, Dual::constant(0.), Dual::constant(4.)));
+        assert!((n.x.dual - 16. / 125.).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,319 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+
+use basenum::{ BaseFloat, ApproxEq };
+use vec::vec::{ Vector3, Vector4 };
+use mat::mat::Matrix4;
+use quat::Quaternion;
+use std::ops::{ Add, Mul, Neg };
+use num::{ One, Zero };
+
+/// A dual quaternion `real + dual·ε`, where `ε² = 0`.
+///
+/// A unit dual quaternion represents a rigid transformation, i.e., a
+/// rotation followed by a translation. Unlike matrices, blending dual
+/// quaternions linearly (see `lerp`) does not shrink the geometry, which
+/// makes them the common choice for skinning.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::DualQuaternion;
+///
+/// let r = Quat::from_axis_angle(vec3(0., 0., 1.), ext::half_pi());
+/// let t = vec3(1., 2., 3.);
+/// let dq = DualQuaternion::from_rotation_translation(r, t);
+/// assert!(is_close_to(&(dq * vec3(1., 0., 0.)), &vec3(1., 3., 3.), 1e-6));
+/// assert!(is_close_to(&dq.translation(), &t, 1e-6));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DualQuaternion<T: BaseFloat> {
+    /// The rotation part.
+    pub real: Quaternion<T>,
+    /// The translation part, i.e., `t * real / 2`, where `t` is the pure
+    /// quaternion of the translation.
+    pub dual: Quaternion<T>,
+}
+
+impl<T: BaseFloat> DualQuaternion<T> {
+
+    /// Constructs a dual quaternion from its parts.
+    #[inline(always)]
+    pub fn new(real: Quaternion<T>, dual: Quaternion<T>) -> DualQuaternion<T> {
+        DualQuaternion { real: real, dual: dual }
+    }
+
+    /// Returns the identity transformation.
+    #[inline(always)]
+    pub fn identity() -> DualQuaternion<T> {
+        DualQuaternion::new(Quaternion::identity(), Quaternion::zero())
+    }
+
+    /// Constructs the transformation that rotates by `rotation`, which must
+    /// be a unit quaternion, and then translates by `translation`.
+    #[inline]
+    pub fn from_rotation_translation(
+        rotation: Quaternion<T>,
+        translation: Vector3<T>
+    ) -> DualQuaternion<T> {
+        let half = T::one() / (T::one() + T::one());
+        let t = Quaternion::from_scalar_vector(T::zero(), translation);
+        DualQuaternion::new(rotation, t * rotation * half)
+    }
+
+    /// Constructs a pure translation.
+    #[inline]
+    pub fn from_translation(translation: Vector3<T>) -> DualQuaternion<T> {
+        DualQuaternion::from_rotation_translation(Quaternion::identity(), translation)
+    }
+
+    /// Returns the rotation part.
+    #[inline(always)]
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.real
+    }
+
+    /// Returns the translation part.
+    ///
+    /// `self` must be normalized.
+    #[inline]
+    pub fn translation(&self) -> Vector3<T> {
+        let two = T::one() + T::one();
+        (self.dual * self.real.conjugate()).vector() * two
+    }
+
+    /// Returns `self` scaled so that the `real` part is a unit quaternion.
+    ///
+    /// Blended dual quaternions must be normalized before they are used to
+    /// transform points.
+    #[inline]
+    pub fn normalize(&self) -> DualQuaternion<T> {
+        let len = self.real.length();
+        DualQuaternion::new(self.real / len, self.dual / len)
+    }
+
+    /// Returns the inverse transformation of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    /// use glm::ext::DualQuaternion;
+    ///
+    /// let r = Quat::from_axis_angle(normalize(vec3(1., 2., 3.)), 1.);
+    /// let dq = DualQuaternion::from_rotation_translation(r, vec3(1., 2., 3.));
+    /// let id = DualQuaternion::identity();
+    /// assert!(is_close_to(&(dq * dq.inverse()), &id, 1e-6));
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> DualQuaternion<T> {
+        let two = T::one() + T::one();
+        let real = self.real.conjugate();
+        let dual = self.dual.conjugate();
+        DualQuaternion::new(real, dual + real * (-two * self.real.dot(&self.dual)))
+    }
+
+    /// Transforms point `p`, i.e., rotates and then translates it.
+    ///
+    /// This is also available as `self * p`.
+    #[inline]
+    pub fn transform_point(&self, p: Vector3<T>) -> Vector3<T> {
+        self.real.rotate(p) + self.translation()
+    }
+
+    /// Transforms direction `v`, i.e., only rotates it.
+    #[inline]
+    pub fn transform_vector(&self, v: Vector3<T>) -> Vector3<T> {
+        self.real.rotate(v)
+    }
+
+    /// Linear blending of `self` and `rhs`, taking the shortest path.
+    ///
+    /// The result is not normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    /// use glm::ext::DualQuaternion;
+    ///
+    /// let a = DualQuaternion::from_translation(vec3(0., 0., 0.));
+    /// let b = DualQuaternion::from_translation(vec3(2., 4., 6.));
+    /// let c = a.lerp(&b, 0.5).normalize();
+    /// assert!(is_close_to(&c.translation(), &vec3(1., 2., 3.), 1e-6));
+    /// ```
+    #[inline]
+    pub fn lerp(&self, rhs: &DualQuaternion<T>, t: T) -> DualQuaternion<T> {
+        let k = if self.real.dot(&rhs.real) < T::zero() { -t } else { t };
+        *self * (T::one() - t) + *rhs * k
+    }
+
+    /// Converts `self`, which must be normalized, to a homogeneous
+    /// transformation matrix.
+    #[inline]
+    pub fn to_mat4(&self) -> Matrix4<T> {
+        let m = self.real.to_mat3();
+        let ling = T::zero();
+        Matrix4::new(
+            m.c0.extend(ling),
+            m.c1.extend(ling),
+            m.c2.extend(ling),
+            self.translation().extend(T::one())
+        )
+    }
+}
+
+impl<T: BaseFloat> From<DualQuaternion<T>> for Matrix4<T> {
+    #[inline(always)]
+    fn from(dq: DualQuaternion<T>) -> Matrix4<T> {
+        dq.to_mat4()
+    }
+}
+
+impl<T: BaseFloat> ApproxEq for DualQuaternion<T> {
+    type BaseType = T;
+    #[inline]
+    fn is_close_to(&self, rhs: &DualQuaternion<T>, max_diff: T) -> bool {
+        self.real.is_close_to(&rhs.real, max_diff) &&
+        self.dual.is_close_to(&rhs.dual, max_diff)
+    }
+}
+
+impl<T: BaseFloat> Zero for DualQuaternion<T> {
+    #[inline(always)]
+    fn zero() -> DualQuaternion<T> {
+        DualQuaternion::new(Quaternion::zero(), Quaternion::zero())
+    }
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.dual.is_zero()
+    }
+}
+
+impl<T: BaseFloat> One for DualQuaternion<T> {
+    #[inline(always)]
+    fn one() -> DualQuaternion<T> {
+        DualQuaternion::identity()
+    }
+}
+
+impl<T: BaseFloat> Neg for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    #[inline(always)]
+    fn neg(self) -> DualQuaternion<T> {
+        DualQuaternion::new(-self.real, -self.dual)
+    }
+}
+
+impl<T: BaseFloat> Add<DualQuaternion<T>> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    #[inline(always)]
+    fn add(self, rhs: DualQuaternion<T>) -> DualQuaternion<T> {
+        DualQuaternion::new(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+
+impl<T: BaseFloat> Mul<T> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    #[inline(always)]
+    fn mul(self, rhs: T) -> DualQuaternion<T> {
+        DualQuaternion::new(self.real * rhs, self.dual * rhs)
+    }
+}
+
+/// Composition of transformations, `rhs` is applied first.
+impl<T: BaseFloat> Mul<DualQuaternion<T>> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    #[inline]
+    fn mul(self, rhs: DualQuaternion<T>) -> DualQuaternion<T> {
+        DualQuaternion::new(
+            self.real * rhs.real,
+            self.real * rhs.dual + self.dual * rhs.real
+        )
+    }
+}
+
+impl<T: BaseFloat> Mul<Vector3<T>> for DualQuaternion<T> {
+    type Output = Vector3<T>;
+    #[inline(always)]
+    fn mul(self, rhs: Vector3<T>) -> Vector3<T> {
+        self.transform_point(rhs)
+    }
+}
+
+impl<T: BaseFloat> Mul<Vector4<T>> for DualQuaternion<T> {
+    type Output = Vector4<T>;
+    /// Transforms homogeneous point `rhs`, i.e., the translation is scaled
+    /// by `rhs.w`.
+    #[inline]
+    fn mul(self, rhs: Vector4<T>) -> Vector4<T> {
+        let v = self.real.rotate(rhs.xyz()) + self.translation() * rhs.w;
+        v.extend(rhs.w)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use basenum::{ is_close_to, ApproxEq };
+    use builtin::normalize;
+    use vec::vec::{ dvec3, dvec4 };
+    use mat::mat::DMat4;
+    use quat::DQuat;
+    use super::DualQuaternion;
+
+    #[test]
+    fn test_compose() {
+        let a = DualQuaternion::from_rotation_translation(
+            DQuat::from_axis_angle(normalize(dvec3(1., 2., 3.)), 0.5),
+            dvec3(1., -2., 3.)
+        );
+        let b = DualQuaternion::from_rotation_translation(
+            DQuat::from_axis_angle(normalize(dvec3(-1., 0., 2.)), 2.),
+            dvec3(4., 5., -6.)
+        );
+        let p = dvec3(0.5, 1.5, -2.5);
+        assert_close_to!((a * b) * p, a * (b * p), 1e-12);
+        assert_close_to!(a.inverse() * (a * p), p, 1e-12);
+        let m: DMat4 = (a * b).into();
+        assert_close_to!(m, a.to_mat4() * b.to_mat4(), 1e-12);
+        assert_close_to!(a * p.extend(0.), a.transform_vector(p).extend(0.), 1e-12);
+        assert_close_to!(m * dvec4(1., 2., 3., 1.), (a * b) * dvec4(1., 2., 3., 1.), 1e-12);
+    }
+
+    #[test]
+    fn test_blend() {
+        let a = DualQuaternion::from_rotation_translation(
+            DQuat::from_axis_angle(dvec3(0., 0., 1.), 0.2), dvec3(1., 0., 0.)
+        );
+        // the same transformation, with the opposite sign.
+        let b = -a;
+        let c = a.lerp(&b, 0.5).normalize();
+        assert_close_to!(c, a, 1e-12);
+        // weighted sum of skinning.
+        let d = (a * 0.3 + b * -0.7).normalize();
+        assert_close_to!(d, a, 1e-12);
+    }
+}
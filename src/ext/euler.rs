@@ -0,0 +1,204 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+
+use basenum::BaseFloat;
+use vec::vec::Vector4;
+use mat::mat::{ Matrix3, Matrix4 };
+use num::One;
+
+// rotation of `angle` around axis `i` (`0`, `1` and `2` for `x`, `y` and `z`).
+fn rotation<T: BaseFloat>(i: usize, angle: T) -> Matrix3<T> {
+    let (s, c) = (angle.sin(), angle.cos());
+    let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+    let mut m = Matrix3::<T>::one();
+    m[j][j] = c;
+    m[j][k] = s;
+    m[k][j] = -s;
+    m[k][k] = c;
+    m
+}
+
+#[inline]
+fn extend<T: BaseFloat>(m: Matrix3<T>) -> Matrix4<T> {
+    let ling = T::zero();
+    Matrix4::new(
+        m.c0.extend(ling),
+        m.c1.extend(ling),
+        m.c2.extend(ling),
+        Vector4::new(ling, ling, ling, T::one())
+    )
+}
+
+#[inline]
+fn compose<T: BaseFloat>(
+    i: usize, j: usize, k: usize, t1: T, t2: T, t3: T
+) -> Matrix4<T> {
+    extend(rotation(i, t1) * rotation(j, t2) * rotation(k, t3))
+}
+
+// angles `(t1, t2, t3)` of `m = Ri(t1) * Rj(t2) * Rk(t3)`, where `k` is
+// either the third axis (Tait-Bryan angles) or `i` (proper Euler angles).
+fn extract<T: BaseFloat>(m: &Matrix4<T>, i: usize, j: usize, k: usize) -> (T, T, T) {
+    // element at row `r` and column `c`.
+    let e = |r: usize, c: usize| m[c][r];
+    let l = 3 - i - j;
+    let s = if j == (i + 1) % 3 { T::one() } else { -T::one() };
+    let (t1, t2) = if i == k {
+        let t2 = e(i, j).hypot(e(i, l)).atan2(e(i, i));
+        (e(j, i).atan2(-s * e(l, i)), t2)
+    } else {
+        let t2 = (s * e(i, k)).atan2(e(i, i).hypot(e(i, j)));
+        ((-s * e(j, k)).atan2(e(k, k)), t2)
+    };
+    // `t3` is solved from `Ri(-t1) * m`, which is still accurate when `t1`
+    // and `t3` are not separable (gimbal lock).
+    let (s1, c1) = (t1.sin(), t1.cos());
+    let n = |c: usize| c1 * e(j, c) + s * s1 * e(l, c);
+    let t3 = if i == k {
+        (-s * n(l)).atan2(n(j))
+    } else {
+        (s * n(i)).atan2(n(j))
+    };
+    (t1, t2, t3)
+}
+
+/// Builds a homogeneous rotation matrix of `angle` radians around the `x`
+/// axis.
+#[inline]
+pub fn euler_angle_x<T: BaseFloat>(angle: T) -> Matrix4<T> {
+    extend(rotation(0, angle))
+}
+
+/// Builds a homogeneous rotation matrix of `angle` radians around the `y`
+/// axis.
+#[inline]
+pub fn euler_angle_y<T: BaseFloat>(angle: T) -> Matrix4<T> {
+    extend(rotation(1, angle))
+}
+
+/// Builds a homogeneous rotation matrix of `angle` radians around the `z`
+/// axis.
+#[inline]
+pub fn euler_angle_z<T: BaseFloat>(angle: T) -> Matrix4<T> {
+    extend(rotation(2, angle))
+}
+
+macro_rules! def_euler {
+    ($({
+        $ctor: ident, $extract: ident,
+        $a: expr, $b: expr, $c: expr,
+        $i: expr, $j: expr, $k: expr,
+        $range: expr
+    }),+) => {
+        $(
+            #[doc = concat!(
+                "Builds a homogeneous rotation matrix from Euler angles in ",
+                "radians, i.e., `R", $a, "(t1) * R", $b, "(t2) * R", $c, "(t3)`."
+            )]
+            #[inline]
+            pub fn $ctor<T: BaseFloat>(t1: T, t2: T, t3: T) -> Matrix4<T> {
+                compose($i, $j, $k, t1, t2, t3)
+            }
+            #[doc = concat!(
+                "Extracts Euler angles `(t1, t2, t3)` from the rotation part ",
+                "of `m`, i.e., the inverse of `", stringify!($ctor), "`.\n\n",
+                "`m` must not contain scaling. `t1` and `t3` are in the range ",
+                "of `[-π, π]`, and `t2` is in `", $range, "`."
+            )]
+            #[inline]
+            pub fn $extract<T: BaseFloat>(m: &Matrix4<T>) -> (T, T, T) {
+                extract(m, $i, $j, $k)
+            }
+        )+
+    }
+}
+
+def_euler! {
+    { euler_angle_xyz, extract_euler_angle_xyz, "x", "y", "z", 0, 1, 2, "[-π/2, π/2]" },
+    { euler_angle_xzy, extract_euler_angle_xzy, "x", "z", "y", 0, 2, 1, "[-π/2, π/2]" },
+    { euler_angle_yxz, extract_euler_angle_yxz, "y", "x", "z", 1, 0, 2, "[-π/2, π/2]" },
+    { euler_angle_yzx, extract_euler_angle_yzx, "y", "z", "x", 1, 2, 0, "[-π/2, π/2]" },
+    { euler_angle_zxy, extract_euler_angle_zxy, "z", "x", "y", 2, 0, 1, "[-π/2, π/2]" },
+    { euler_angle_zyx, extract_euler_angle_zyx, "z", "y", "x", 2, 1, 0, "[-π/2, π/2]" },
+    { euler_angle_xyx, extract_euler_angle_xyx, "x", "y", "x", 0, 1, 0, "[0, π]" },
+    { euler_angle_xzx, extract_euler_angle_xzx, "x", "z", "x", 0, 2, 0, "[0, π]" },
+    { euler_angle_yxy, extract_euler_angle_yxy, "y", "x", "y", 1, 0, 1, "[0, π]" },
+    { euler_angle_yzy, extract_euler_angle_yzy, "y", "z", "y", 1, 2, 1, "[0, π]" },
+    { euler_angle_zxz, extract_euler_angle_zxz, "z", "x", "z", 2, 0, 2, "[0, π]" },
+    { euler_angle_zyz, extract_euler_angle_zyz, "z", "y", "z", 2, 1, 2, "[0, π]" }
+}
+
+#[cfg(test)]
+mod test {
+
+    use basenum::{ is_close_to, ApproxEq };
+    use vec::vec::dvec3;
+    use mat::mat::DMat4;
+    use ext::matrix::rotate;
+    use num::one;
+    use super::*;
+
+    #[test]
+    fn test_elementary() {
+        let m: DMat4 = one();
+        assert_close_to!(euler_angle_x(0.3), rotate(&m, 0.3, dvec3(1., 0., 0.)), 1e-12);
+        assert_close_to!(euler_angle_y(0.3), rotate(&m, 0.3, dvec3(0., 1., 0.)), 1e-12);
+        assert_close_to!(euler_angle_z(0.3), rotate(&m, 0.3, dvec3(0., 0., 1.)), 1e-12);
+        assert_close_to!(
+            euler_angle_zyx(0.1, 0.2, 0.3),
+            euler_angle_z(0.1) * euler_angle_y(0.2) * euler_angle_x(0.3),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let fs: [(fn(f64, f64, f64) -> DMat4, fn(&DMat4) -> (f64, f64, f64), bool); 12] = [
+            (euler_angle_xyz, extract_euler_angle_xyz, false),
+            (euler_angle_xzy, extract_euler_angle_xzy, false),
+            (euler_angle_yxz, extract_euler_angle_yxz, false),
+            (euler_angle_yzx, extract_euler_angle_yzx, false),
+            (euler_angle_zxy, extract_euler_angle_zxy, false),
+            (euler_angle_zyx, extract_euler_angle_zyx, false),
+            (euler_angle_xyx, extract_euler_angle_xyx, true),
+            (euler_angle_xzx, extract_euler_angle_xzx, true),
+            (euler_angle_yxy, extract_euler_angle_yxy, true),
+            (euler_angle_yzy, extract_euler_angle_yzy, true),
+            (euler_angle_zxz, extract_euler_angle_zxz, true),
+            (euler_angle_zyz, extract_euler_angle_zyz, true),
+        ];
+        for &(ctor, ext, proper) in fs.iter() {
+            let t2 = if proper { 0.7 } else { -0.7 };
+            let (a, b, c) = ext(&ctor(0.3, t2, -1.1));
+            assert!(is_close_to(&a, &0.3, 1e-12));
+            assert!(is_close_to(&b, &t2, 1e-12));
+            assert!(is_close_to(&c, &-1.1, 1e-12));
+            // gimbal lock, only the matrix is recovered.
+            let t2 = if proper { 0. } else { ::std::f64::consts::FRAC_PI_2 };
+            let m = ctor(0.3, t2, -1.1);
+            let (a, b, c) = ext(&m);
+            assert_close_to!(ctor(a, b, c), m, 1e-12);
+        }
+    }
+}
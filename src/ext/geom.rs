@@ -21,10 +21,15 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
-use basenum::BaseFloat;
+use basenum::{ BaseNum, SignedNum, BaseFloat };
 use traits::GenFloat;
 use vec::traits::GenFloatVec;
+use vec::vec::{ Vector2, Vector3, IVec2, IVec3, IVec4, UVec2, UVec3, UVec4 };
+use mat::mat::Matrix3;
+use quat::Quaternion;
+use num::Zero;
 use builtin as bif;
+use error::GlmError;
 
 /// Returns the squre of the length of vector `x`.
 ///
@@ -73,6 +78,106 @@ pub fn normalize_to<F: BaseFloat + GenFloat<F>, T: GenFloatVec<F>>(x: T, len: F)
     bif::normalize(x) * len
 }
 
+/// Returns the direction (i.e., the normalized vector) and the length of
+/// vector `x`, computing the square root only once.
+///
+/// If `x` has zero length, the direction is a zero vector.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert_eq!(direction_and_length(dvec2(0., -3.)), (dvec2(0., -1.), 3.));
+/// assert_eq!(direction_and_length(vec3(0., 0., 0.)), (vec3(0., 0., 0.), 0.));
+/// ```
+#[inline]
+pub fn direction_and_length<F: BaseFloat, T: GenFloatVec<F>>(x: T) -> (T, F) {
+    let len = bif::dot(x, x).sqrt();
+    if len > F::zero() {
+        (x * len.recip(), len)
+    } else {
+        (T::zero(), len)
+    }
+}
+
+/// Returns vector `x` rescaled to length `max_len` if it is longer than that,
+/// otherwise `x` itself.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert_eq!(clamp_length(vec2(3., 4.), 2.5), vec2(1.5, 2.));
+/// assert_eq!(clamp_length(vec2(3., 4.), 10.), vec2(3., 4.));
+/// ```
+#[inline]
+pub fn clamp_length<F: BaseFloat, T: GenFloatVec<F>>(x: T, max_len: F) -> T {
+    let l2 = bif::dot(x, x);
+    if l2 > max_len * max_len {
+        x * (max_len / l2.sqrt())
+    } else {
+        x
+    }
+}
+
+/// Returns vector `x` rescaled so that its length is in
+/// `[min_len, max_len]`. `x` is returned unchanged if its length is in the
+/// range already.
+///
+/// A zero vector has no direction, so it is never lengthened.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let v = vec2(3., 4.);
+/// assert_eq!(clamp_length_between(v, 10., 20.), vec2(6., 8.));
+/// assert_eq!(clamp_length_between(v, 1., 2.5), vec2(1.5, 2.));
+/// assert_eq!(clamp_length_between(v, 1., 10.), v);
+/// ```
+#[inline]
+pub fn clamp_length_between<F: BaseFloat, T: GenFloatVec<F>>(x: T, min_len: F, max_len: F) -> T {
+    let l2 = bif::dot(x, x);
+    if l2 > max_len * max_len {
+        x * (max_len / l2.sqrt())
+    } else if l2 < min_len * min_len && l2 > F::zero() {
+        x * (min_len / l2.sqrt())
+    } else {
+        x
+    }
+}
+
+/// Returns `x` normalized, or `Err(GlmError::DegenerateInput)` if `x` has
+/// zero length or non-finite components.
+///
+/// This is the fallible version of the built-in function `normalize`, which
+/// returns a vector of `NaN`s in this case.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ dvec2, GlmError };
+/// use glm::ext::try_normalize;
+///
+/// assert_eq!(try_normalize(dvec2(0., 4.)), Ok(dvec2(0., 1.)));
+/// assert_eq!(try_normalize(dvec2(0., 0.)), Err(GlmError::DegenerateInput));
+/// ```
+#[inline]
+pub fn try_normalize<F: BaseFloat + GenFloat<F>, T: GenFloatVec<F>>(x: T) -> Result<T, GlmError> {
+    let l2 = sqlength(x);
+    if l2 > F::zero() && l2.is_finite() {
+        Ok(bif::normalize(x))
+    } else {
+        Err(GlmError::DegenerateInput)
+    }
+}
+
 /// Projects `x` on `y`.
 ///
 /// # Example
@@ -141,3 +246,556 @@ pub fn angle<F: BaseFloat + GenFloat<F>, T: GenFloatVec<F>>(x: T, y: T) -> F {
         (bif::dot(x, y) * bif::inversesqrt(sqmag)).acos()
     }
 }
+
+/// Returns the shortest rotation that rotates direction `from` to direction
+/// `to`.
+///
+/// Both `from` and `to` must be normalized. If they are opposite, the
+/// rotation is `π` radians around an arbitrary axis perpendicular to
+/// `from`. Use `to_mat3` or `into()` of the result to get a matrix.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let (a, b) = (vec3(1., 0., 0.), normalize(vec3(1., 1., 1.)));
+/// assert!(is_close_to(&(rotation(a, b) * a), &b, 1e-6));
+/// // antiparallel.
+/// let z = vec3(0., 0., 1.);
+/// assert!(is_close_to(&(rotation(z, -z) * z), &-z, 1e-6));
+/// let m: Mat3 = rotation(a, -a).into();
+/// assert!(is_close_to(&(m * a), &-a, 1e-6));
+/// ```
+pub fn rotation<F: BaseFloat>(from: Vector3<F>, to: Vector3<F>) -> Quaternion<F> {
+    let (ling, yi) = (F::zero(), F::one());
+    let two = yi + yi;
+    let c = bif::dot(from, to);
+    if c >= yi - F::epsilon() {
+        return Quaternion::identity();
+    }
+    if c <= -yi + F::epsilon() {
+        // any axis perpendicular to `from`.
+        let mut axis = bif::cross(Vector3::new(ling, ling, yi), from);
+        if bif::dot(axis, axis) < F::epsilon() {
+            axis = bif::cross(Vector3::new(yi, ling, ling), from);
+        }
+        return Quaternion::from_scalar_vector(ling, axis / bif::length(axis));
+    }
+    let s = ((yi + c) * two).sqrt();
+    Quaternion::from_scalar_vector(s / two, bif::cross(from, to) / s)
+}
+
+/// Returns the perp-dot product of 2D vectors `a` and `b`, i.e.,
+/// `a.x * b.y - a.y * b.x`.
+///
+/// This is the `z` component of the cross product of `a` and `b` extended
+/// to 3D. It is positive if `b` is counterclockwise from `a`, negative if
+/// clockwise, and zero if they are parallel.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert_eq!(perp_dot(vec2(1., 0.), vec2(0., 1.)), 1.);
+/// assert_eq!(perp_dot(ivec2(0, 1), ivec2(1, 0)), -1);
+/// assert_eq!(perp_dot(vec2(1., 2.), vec2(2., 4.)), 0.);
+/// ```
+#[inline(always)]
+pub fn perp_dot<T: BaseNum + SignedNum>(a: Vector2<T>, b: Vector2<T>) -> T {
+    a.x * b.y - a.y * b.x
+}
+
+/// 2D cross product. An alias of `perp_dot`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert_eq!(cross2(vec2(2., 0.), vec2(0., 3.)), 6.);
+/// ```
+#[inline(always)]
+pub fn cross2<T: BaseNum + SignedNum>(a: Vector2<T>, b: Vector2<T>) -> T {
+    perp_dot(a, b)
+}
+
+/// Computes the tangent and bitangent vectors of triangle `p0`, `p1`, `p2`
+/// with texture coordinates `uv0`, `uv1`, `uv2`.
+///
+/// Returns `(tangent, bitangent)`, which point to the directions of
+/// increasing `u` and `v` respectively.
+///
+/// # Note
+///
+/// - The returned vectors are not normalized. Sum them over the triangles
+///   sharing a vertex, then normalize.
+/// - If the texture coordinates are degenerate, i.e., the triangle has zero
+///   area in the texture space, zero vectors are returned.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let (t, b) = compute_tangent_bitangent(
+///     vec3(0., 0., 0.), vec3(2., 0., 0.), vec3(0., 0., -2.),
+///     vec2(0., 0.), vec2(1., 0.), vec2(0., 1.));
+/// assert_eq!(t, vec3(2., 0., 0.));
+/// assert_eq!(b, vec3(0., 0., -2.));
+/// ```
+pub fn compute_tangent_bitangent<F: BaseFloat>(
+    p0: Vector3<F>, p1: Vector3<F>, p2: Vector3<F>,
+    uv0: Vector2<F>, uv1: Vector2<F>, uv2: Vector2<F>
+) -> (Vector3<F>, Vector3<F>) {
+    let e1 = p1 - p0;
+    let e2 = p2 - p0;
+    let d1 = uv1 - uv0;
+    let d2 = uv2 - uv0;
+    let det = d1.x * d2.y - d2.x * d1.y;
+    if det.is_approx_eq(&F::zero()) {
+        (Vector3::zero(), Vector3::zero())
+    } else {
+        let r = det.recip();
+        ((e1 * d2.y - e2 * d1.y) * r, (e2 * d1.x - e1 * d2.x) * r)
+    }
+}
+
+/// Builds the matrix that transforms vectors from the tangent space to the
+/// space where normal `n`, tangent `t` and bitangent `b` are defined.
+///
+/// The columns of the returned matrix are `t`, `b` and `n`. Use its
+/// transpose for the inverse transform if the vectors are orthonormal.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = tbn_matrix(vec3(0., 0., 1.), vec3(1., 0., 0.), vec3(0., 1., 0.));
+/// assert_eq!(m * vec3(1., 2., 3.), vec3(1., 2., 3.));
+/// ```
+#[inline]
+pub fn tbn_matrix<F: BaseFloat>(
+    n: Vector3<F>,
+    t: Vector3<F>,
+    b: Vector3<F>
+) -> Matrix3<F> {
+    Matrix3::new(t, b, n)
+}
+
+/// Computes Schlick's approximation of the Fresnel reflectance.
+///
+/// `cos_theta` is the cosine of the angle between the incident direction and
+/// the surface normal, and `f0` is the reflectance at normal incidence,
+/// either a scalar or a color.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert_eq!(fresnel_schlick(1., 0.04), 0.04);
+/// assert_eq!(fresnel_schlick(0., 0.04), 1.);
+/// assert_eq!(fresnel_schlick(1., vec3(0.9, 0.6, 0.2)), vec3(0.9, 0.6, 0.2));
+/// ```
+#[inline]
+pub fn fresnel_schlick<F: BaseFloat, T: GenFloat<F>>(cos_theta: F, f0: T) -> T {
+    let m = F::one() - cos_theta;
+    let m2 = m * m;
+    let m5 = m2 * m2 * m;
+    f0 * (F::one() - m5) + m5
+}
+
+/// Returns the refraction vector of incident vector `i`, surface normal `n`
+/// and the ratio of indices of refraction `eta`, or `None` in case of total
+/// internal reflection.
+///
+/// Unlike the built-in function `refract`, which returns a zero vector on
+/// total internal reflection, this function lets the caller branch
+/// explicitly.
+///
+/// # Note
+///
+/// `i` and `n` must be normalized.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let n = vec3(0., 0., 1.);
+/// let i = vec3(0., 0., -1.);
+/// assert_eq!(refract_checked(i, n, 1. / 1.5), Some(vec3(0., 0., -1.)));
+/// let i = normalize(vec3(1., 0., -0.1));
+/// assert_eq!(refract_checked(i, n, 1.5), None);
+/// ```
+#[inline]
+pub fn refract_checked<F: BaseFloat, T: GenFloatVec<F>>(i: T, n: T, eta: F) -> Option<T> {
+    let d = bif::dot(n, i);
+    let k = F::one() - eta * eta * (F::one() - d * d);
+    if k < F::zero() {
+        None
+    } else {
+        Some(i * eta - n * (eta * d + k.sqrt()))
+    }
+}
+
+/// Integer vectors whose dot products are computed in a wider integer type.
+pub trait IntDot: Copy {
+    /// The wider integer type of the results, i.e., `i64` for `IVec*` and
+    /// `u64` for `UVec*`.
+    type Wide;
+
+    /// Returns the dot product of `self` and `rhs`.
+    fn dot_wide(self, rhs: Self) -> Self::Wide;
+
+    /// Returns the squared distance between `self` and `rhs`.
+    fn sqdistance_wide(self, rhs: Self) -> Self::Wide;
+}
+
+macro_rules! impl_IntDot {
+    ($({ $v: ident, $w: ty, $($field: ident),+ }),+) => {
+        $(
+            impl IntDot for $v {
+                type Wide = $w;
+                #[inline]
+                fn dot_wide(self, rhs: $v) -> $w {
+                    0 $(+ (self.$field as $w) * (rhs.$field as $w))+
+                }
+                #[inline]
+                fn sqdistance_wide(self, rhs: $v) -> $w {
+                    let d = |a: $w, b: $w| -> $w { if a > b { a - b } else { b - a } };
+                    0 $(+ d(self.$field as $w, rhs.$field as $w) * d(self.$field as $w, rhs.$field as $w))+
+                }
+            }
+        )+
+    }
+}
+
+impl_IntDot! {
+    { IVec2, i64, x, y },
+    { IVec3, i64, x, y, z },
+    { IVec4, i64, x, y, z, w },
+    { UVec2, u64, x, y },
+    { UVec3, u64, x, y, z },
+    { UVec4, u64, x, y, z, w }
+}
+
+/// Returns the dot product of integer vectors `x` and `y`, computed in `i64`
+/// (for `IVec*`) or `u64` (for `UVec*`).
+///
+/// The result is exact unless it exceeds the range of the wide type, which
+/// only happens for components close to the limits of `i32` or `u32`.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ ivec3, uvec2 };
+/// use glm::ext::int_dot;
+///
+/// assert_eq!(int_dot(ivec3(1, -2, 3), ivec3(4, 5, 6)), 12_i64);
+/// assert_eq!(int_dot(uvec2(0xFFFFFFFF, 1), uvec2(2, 1)), 0x1FFFFFFFF_u64);
+/// ```
+#[inline(always)]
+pub fn int_dot<T: IntDot>(x: T, y: T) -> T::Wide {
+    x.dot_wide(y)
+}
+
+/// Returns the squared length of integer vector `x`, computed in a wider
+/// integer type.
+///
+/// See `int_dot` for the result type.
+///
+/// # Example
+///
+/// ```
+/// use glm::ivec2;
+/// use glm::ext::int_sqlength;
+///
+/// assert_eq!(int_sqlength(ivec2(-65536, 65536)), 0x200000000_i64);
+/// ```
+#[inline(always)]
+pub fn int_sqlength<T: IntDot>(x: T) -> T::Wide {
+    x.dot_wide(x)
+}
+
+/// Returns the squared distance between integer vectors `x` and `y`,
+/// computed in a wider integer type.
+///
+/// See `int_dot` for the result type. Unlike `int_sqlength(x - y)`, the
+/// differences of `UVec*` components never underflow.
+///
+/// # Example
+///
+/// ```
+/// use glm::uvec3;
+/// use glm::ext::int_sqdistance;
+///
+/// assert_eq!(int_sqdistance(uvec3(1, 5, 0), uvec3(4, 1, 0)), 25_u64);
+/// ```
+#[inline(always)]
+pub fn int_sqdistance<T: IntDot>(x: T, y: T) -> T::Wide {
+    x.sqdistance_wide(y)
+}
+
+/// Relation between two 2D line segments, returned by
+/// `classify_segments_2d`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SegmentIntersection<F: BaseFloat> {
+    /// The segments have no common point. They may be parallel.
+    Disjoint,
+    /// The segments meet at exactly one point.
+    Point(Vector2<F>),
+    /// The segments are collinear and share the segment between the two
+    /// points.
+    Overlap(Vector2<F>, Vector2<F>),
+}
+
+/// Classifies the relation between segment `a0`-`a1` and segment `b0`-`b1`.
+///
+/// Segments whose directions are parallel within the relative tolerance of
+/// `F::epsilon()` are treated as parallel. Endpoints are included, so
+/// segments that only touch intersect at a point.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let (a0, a1) = (vec2(0., 0.), vec2(4., 0.));
+/// assert_eq!(
+///     classify_segments_2d(a0, a1, vec2(1., -1.), vec2(1., 1.)),
+///     SegmentIntersection::Point(vec2(1., 0.))
+/// );
+/// assert_eq!(
+///     classify_segments_2d(a0, a1, vec2(3., 0.), vec2(6., 0.)),
+///     SegmentIntersection::Overlap(vec2(3., 0.), vec2(4., 0.))
+/// );
+/// assert_eq!(
+///     classify_segments_2d(a0, a1, vec2(0., 1.), vec2(4., 1.)),
+///     SegmentIntersection::Disjoint
+/// );
+/// ```
+pub fn classify_segments_2d<F: BaseFloat>(
+    a0: Vector2<F>,
+    a1: Vector2<F>,
+    b0: Vector2<F>,
+    b1: Vector2<F>
+) -> SegmentIntersection<F> {
+    let (r, s, qp) = (a1 - a0, b1 - b0, b0 - a0);
+    let (rr, ss) = (bif::dot(r, r), bif::dot(s, s));
+    if rr.is_zero() {
+        if ss.is_zero() {
+            return if a0 == b0 {
+                SegmentIntersection::Point(a0)
+            } else {
+                SegmentIntersection::Disjoint
+            };
+        }
+        // let the degenerate segment be the second one.
+        return classify_segments_2d(b0, b1, a0, a1);
+    }
+    let eps = F::epsilon();
+    let denom = cross2(r, s);
+    if denom.abs() > eps * (rr * ss).sqrt() {
+        let t = cross2(qp, s) / denom;
+        let u = cross2(qp, r) / denom;
+        let (ling, yi) = (F::zero(), F::one());
+        return if t >= ling && t <= yi && u >= ling && u <= yi {
+            SegmentIntersection::Point(a0 + r * t)
+        } else {
+            SegmentIntersection::Disjoint
+        };
+    }
+    // parallel, disjoint unless collinear.
+    if cross2(qp, r).abs() > eps * (rr * bif::dot(qp, qp)).sqrt() {
+        return SegmentIntersection::Disjoint;
+    }
+    let t0 = bif::dot(qp, r) / rr;
+    let t1 = t0 + bif::dot(s, r) / rr;
+    let lo = BaseNum::max(BaseNum::min(t0, t1), F::zero());
+    let hi = BaseNum::min(BaseNum::max(t0, t1), F::one());
+    if lo > hi {
+        SegmentIntersection::Disjoint
+    } else if lo == hi {
+        SegmentIntersection::Point(a0 + r * lo)
+    } else {
+        SegmentIntersection::Overlap(a0 + r * lo, a0 + r * hi)
+    }
+}
+
+/// Returns the intersection point of segment `a0`-`a1` and segment
+/// `b0`-`b1`, or `None` if they do not meet at exactly one point.
+///
+/// Use `classify_segments_2d` to tell disjoint segments from overlapping
+/// ones.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let p = intersect_segments_2d(vec2(0., 0.), vec2(2., 2.), vec2(0., 2.), vec2(2., 0.));
+/// assert_eq!(p, Some(vec2(1., 1.)));
+/// let q = intersect_segments_2d(vec2(0., 0.), vec2(1., 1.), vec2(0., 2.), vec2(0.5, 1.5));
+/// assert_eq!(q, None);
+/// ```
+#[inline]
+pub fn intersect_segments_2d<F: BaseFloat>(
+    a0: Vector2<F>,
+    a1: Vector2<F>,
+    b0: Vector2<F>,
+    b1: Vector2<F>
+) -> Option<Vector2<F>> {
+    match classify_segments_2d(a0, a1, b0, b1) {
+        SegmentIntersection::Point(p) => Some(p),
+        _ => None,
+    }
+}
+
+/// Returns the barycentric coordinates `(u, v, w)` of point `p` with respect
+/// to triangle `a`, `b`, `c`, i.e., `p = u * a + v * b + w * c` and
+/// `u + v + w = 1`.
+///
+/// For 3D triangles, the coordinates are of the projection of `p` onto the
+/// plane of the triangle. The coordinates are `NaN`s if the triangle is
+/// degenerate.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let (a, b, c) = (vec2(0., 0.), vec2(4., 0.), vec2(0., 4.));
+/// assert_eq!(barycentric(vec2(1., 2.), a, b, c), vec3(0.25, 0.25, 0.5));
+/// ```
+pub fn barycentric<F: BaseFloat, T: GenFloatVec<F>>(p: T, a: T, b: T, c: T) -> Vector3<F> {
+    let (v0, v1, v2) = (b - a, c - a, p - a);
+    let (d00, d01, d11) = (bif::dot(v0, v0), bif::dot(v0, v1), bif::dot(v1, v1));
+    let (d20, d21) = (bif::dot(v2, v0), bif::dot(v2, v1));
+    let denom = d00 * d11 - d01 * d01;
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    Vector3::new(F::one() - v - w, v, w)
+}
+
+/// Returns `true` if point `p` is inside of triangle `a`, `b`, `c`, or on its
+/// edges.
+///
+/// It works for both 2D and 3D triangles. For 3D triangles, `p` is
+/// projected onto the plane of the triangle first. Degenerate triangles
+/// contain no point.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let (a, b, c) = (vec2(0., 0.), vec2(4., 0.), vec2(0., 4.));
+/// assert!(point_in_triangle(vec2(1., 1.), a, b, c));
+/// assert!(point_in_triangle(vec2(2., 2.), a, b, c));
+/// assert!(!point_in_triangle(vec2(3., 3.), a, b, c));
+///
+/// let (a, b, c) = (vec3(0., 0., 0.), vec3(1., 0., 0.), vec3(0., 1., 0.));
+/// assert!(point_in_triangle(vec3(0.25, 0.25, 5.), a, b, c));
+/// ```
+#[inline]
+pub fn point_in_triangle<F: BaseFloat, T: GenFloatVec<F>>(p: T, a: T, b: T, c: T) -> bool {
+    let uvw = barycentric(p, a, b, c);
+    let ling = F::zero();
+    uvw.x >= ling && uvw.y >= ling && uvw.z >= ling
+}
+
+/// Returns `true` if point `p` is inside of the polygon whose vertices are
+/// `poly`, by the non-zero winding rule.
+///
+/// The polygon is closed implicitly, i.e., the last vertex connects to the
+/// first one. It can be concave or self-intersecting. Points exactly on the
+/// boundary may be classified either way.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// // a concave "L" shape.
+/// let poly = [
+///     vec2(0., 0.), vec2(2., 0.), vec2(2., 1.),
+///     vec2(1., 1.), vec2(1., 2.), vec2(0., 2.)
+/// ];
+/// assert!(point_in_polygon(&poly, vec2(0.5, 1.5)));
+/// assert!(point_in_polygon(&poly, vec2(1.5, 0.5)));
+/// assert!(!point_in_polygon(&poly, vec2(1.5, 1.5)));
+/// ```
+pub fn point_in_polygon<F: BaseFloat>(poly: &[Vector2<F>], p: Vector2<F>) -> bool {
+    let n = poly.len();
+    let ling = F::zero();
+    let mut winding = 0_i32;
+    for i in 0..n {
+        let (a, b) = (poly[i], poly[(i + 1) % n]);
+        let side = cross2(b - a, p - a);
+        if a.y <= p.y {
+            if b.y > p.y && side > ling {
+                winding += 1;
+            }
+        } else if b.y <= p.y && side < ling {
+            winding -= 1;
+        }
+    }
+    winding != 0
+}
+
+/// Returns the covariance matrix of `points`, together with their mean.
+///
+/// The covariance is the population one, i.e., it is divided by the number
+/// of points. The eigenvectors of it are the principal axes of the point
+/// set, e.g., for fitting oriented bounding boxes, or estimating normals
+/// (the eigenvector of the smallest eigenvalue).
+///
+/// If `points` is empty, zeros are returned.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let ps = [vec3(1., 0., 0.), vec3(-1., 0., 0.), vec3(0., 2., 1.), vec3(0., -2., 1.)];
+/// let (cov, mean) = covariance(&ps);
+/// assert_eq!(mean, vec3(0., 0., 0.5));
+/// assert_eq!(cov, mat3(0.5, 0., 0., 0., 2., 0., 0., 0., 0.25));
+/// ```
+pub fn covariance<F: BaseFloat>(points: &[Vector3<F>]) -> (Matrix3<F>, Vector3<F>) {
+    let mut mean = Vector3::<F>::zero();
+    let mut cov = Matrix3::<F>::zero();
+    if points.is_empty() {
+        return (cov, mean);
+    }
+    let n = F::from(points.len()).unwrap();
+    for p in points {
+        mean += *p;
+    }
+    mean /= n;
+    // the second pass is more accurate than accumulating `p * p'`.
+    for p in points {
+        let d = *p - mean;
+        cov.c0 += d * d.x;
+        cov.c1 += d * d.y;
+        cov.c2 += d * d.z;
+    }
+    (cov / n, mean)
+}
@@ -0,0 +1,502 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+use std::cell::Cell;
+use basenum::{ BaseFloat, BaseInt };
+use traits::{ GenFloat, GenInt };
+use vec::vec::{ Vector2, Vector3, Vector4 };
+
+/// Integer types with overflow-aware exponentiation.
+pub trait IntPow: BaseInt {
+    /// Returns `self` raised to the power `e`, or `None` if it overflows.
+    fn checked_pow(self, e: u32) -> Option<Self>;
+    /// Returns `self` raised to the power `e`, wrapping around on overflow.
+    fn wrapping_pow(self, e: u32) -> Self;
+}
+
+macro_rules! impl_IntPow {
+    ($($t: ty),+) => {
+        $(
+            impl IntPow for $t {
+                #[inline(always)]
+                fn checked_pow(self, e: u32) -> Option<$t> {
+                    <$t>::checked_pow(self, e)
+                }
+                #[inline(always)]
+                fn wrapping_pow(self, e: u32) -> $t {
+                    <$t>::wrapping_pow(self, e)
+                }
+            }
+        )+
+    }
+}
+
+impl_IntPow! { i32, u32 }
+
+/// Bit counts that can be applied to integer type `T`, i.e., a scalar count
+/// for all components, or a vector of per-component counts.
+pub trait BitCounts<I: BaseInt, T: GenInt<I>>: Copy {
+    /// Applies `f` to each component of `x` and the count for it.
+    fn zip_counts<F: Fn(I, u32) -> I>(self, x: T, f: F) -> T;
+}
+
+macro_rules! impl_scalar_BitCounts {
+    ($($t: ty),+) => {
+        $(
+            impl<I: BaseInt, T: GenInt<I>> BitCounts<I, T> for $t {
+                #[inline(always)]
+                fn zip_counts<F: Fn(I, u32) -> I>(self, x: T, f: F) -> T {
+                    x.map(|c| -> I { f(c, self as u32) })
+                }
+            }
+        )+
+    }
+}
+
+impl_scalar_BitCounts! { i32, u32 }
+
+macro_rules! impl_vec_BitCounts {
+    ($({ $v: ident, $($field: ident),+ }),+) => {
+        $(
+            impl<I: BaseInt> BitCounts<I, $v<I>> for $v<u32> {
+                #[inline]
+                fn zip_counts<F: Fn(I, u32) -> I>(self, x: $v<I>, f: F) -> $v<I> {
+                    $v::new($(f(x.$field, self.$field)),+)
+                }
+            }
+            impl<I: BaseInt> BitCounts<I, $v<I>> for $v<i32> {
+                #[inline]
+                fn zip_counts<F: Fn(I, u32) -> I>(self, x: $v<I>, f: F) -> $v<I> {
+                    $v::new($(f(x.$field, self.$field as u32)),+)
+                }
+            }
+        )+
+    }
+}
+
+impl_vec_BitCounts! {
+    { Vector2, x, y },
+    { Vector3, x, y, z },
+    { Vector4, x, y, z, w }
+}
+
+/// Rotates the bits of each component of `x` to the left by `n` bits.
+///
+/// `n` is either a scalar count for all components, or an integer vector of
+/// per-component counts. Counts are taken modulo the bit width, so a negative
+/// count rotates to the right.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ uvec2, ivec2 };
+/// use glm::ext::rotate_left;
+///
+/// assert_eq!(rotate_left(0x80000001_u32, 4), 0x00000018);
+/// assert_eq!(rotate_left(uvec2(1, 0x80000000), 1), uvec2(2, 1));
+/// assert_eq!(rotate_left(uvec2(1, 1), ivec2(3, -1)), uvec2(8, 0x80000000));
+/// ```
+#[inline]
+pub fn rotate_left<I: BaseInt, T: GenInt<I>, N: BitCounts<I, T>>(x: T, n: N) -> T {
+    n.zip_counts(x, |c, k| -> I { c.rotate_left(k) })
+}
+
+/// Rotates the bits of each component of `x` to the right by `n` bits.
+///
+/// See `rotate_left` for the meaning of `n`.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ ivec2, uvec2 };
+/// use glm::ext::rotate_right;
+///
+/// assert_eq!(rotate_right(0x80000001_u32, 4), 0x18000000);
+/// assert_eq!(rotate_right(ivec2(1, 2), uvec2(1, 1)), ivec2(i32::min_value(), 1));
+/// ```
+#[inline]
+pub fn rotate_right<I: BaseInt, T: GenInt<I>, N: BitCounts<I, T>>(x: T, n: N) -> T {
+    n.zip_counts(x, |c, k| -> I { c.rotate_right(k) })
+}
+
+/// Returns the number of leading zero bits of each component of `x`.
+///
+/// Unlike `findMSB`, a zero component gives the bit width (i.e., `32`)
+/// instead of `-1`.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ ivec3, uvec2 };
+/// use glm::ext::clz;
+///
+/// assert_eq!(clz(1_u32), 31);
+/// assert_eq!(clz(uvec2(0, 0x00010000)), uvec2(32, 15));
+/// assert_eq!(clz(ivec3(-1, 0, 0x7FFFFFFF)), ivec3(0, 32, 1));
+/// ```
+#[inline]
+pub fn clz<I: BaseInt, T: GenInt<I>>(x: T) -> T {
+    x.map(|c| -> I { I::from(c.leading_zeros()).unwrap() })
+}
+
+/// Returns the number of trailing zero bits of each component of `x`.
+///
+/// Unlike `findLSB`, a zero component gives the bit width (i.e., `32`)
+/// instead of `-1`.
+///
+/// # Example
+///
+/// ```
+/// use glm::uvec3;
+/// use glm::ext::ctz;
+///
+/// assert_eq!(ctz(8_i32), 3);
+/// assert_eq!(ctz(uvec3(0, 1, 0x80000000)), uvec3(32, 0, 31));
+/// ```
+#[inline]
+pub fn ctz<I: BaseInt, T: GenInt<I>>(x: T) -> T {
+    x.map(|c| -> I { I::from(c.trailing_zeros()).unwrap() })
+}
+
+/// Returns the number of bits set to `0` in each component of `x`.
+///
+/// This complements the built-in function `bitCount`.
+///
+/// # Example
+///
+/// ```
+/// use glm::ivec2;
+/// use glm::ext::count_zeros;
+///
+/// assert_eq!(count_zeros(0xFF_u32), 24);
+/// assert_eq!(count_zeros(ivec2(0, -1)), ivec2(32, 0));
+/// ```
+#[inline]
+pub fn count_zeros<I: BaseInt, T: GenInt<I>>(x: T) -> T {
+    x.map(|c| -> I { I::from(c.count_zeros()).unwrap() })
+}
+
+/// Euclidean division of `x` by `y`, component-wise.
+///
+/// The quotient `q` satisfies `x = q * y + r` with `0 ≤ r < |y|`, which is
+/// the same as `i32::div_euclid`.
+///
+/// # Panic
+///
+/// It is a panic if a component of `y` is `0`.
+///
+/// # Example
+///
+/// ```
+/// use glm::ivec4;
+/// use glm::ext::div_euclid;
+///
+/// let x = ivec4(7, -7, 7, -7);
+/// let y = ivec4(4, 4, -4, -4);
+/// assert_eq!(div_euclid(x, y), ivec4(1, -2, -1, 2));
+/// ```
+#[inline]
+pub fn div_euclid<I: BaseInt, T: GenInt<I>>(x: T, y: T) -> T {
+    x.zip(y, |a, b| -> I {
+        let q = a / b;
+        if a % b < I::zero() {
+            if b > I::zero() { q - I::one() } else { q + I::one() }
+        } else {
+            q
+        }
+    })
+}
+
+/// Euclidean remainder of `x` divided by `y`, component-wise.
+///
+/// The result is never negative, which is the same as `i32::rem_euclid`.
+/// E.g., it maps negative world positions to local coordinates of chunks.
+///
+/// # Panic
+///
+/// It is a panic if a component of `y` is `0`.
+///
+/// # Example
+///
+/// ```
+/// use glm::ivec4;
+/// use glm::ext::rem_euclid;
+///
+/// let x = ivec4(7, -7, 7, -7);
+/// let y = ivec4(4, 4, -4, -4);
+/// assert_eq!(rem_euclid(x, y), ivec4(3, 1, 3, 1));
+/// ```
+#[inline]
+pub fn rem_euclid<I: BaseInt, T: GenInt<I>>(x: T, y: T) -> T {
+    x.zip(y, |a, b| -> I {
+        let r = a % b;
+        if r < I::zero() {
+            if b < I::zero() { r - b } else { r + b }
+        } else {
+            r
+        }
+    })
+}
+
+/// Division of `x` by `y` rounded towards negative infinity, component-wise.
+///
+/// # Panic
+///
+/// It is a panic if a component of `y` is `0`.
+///
+/// # Example
+///
+/// ```
+/// use glm::ivec4;
+/// use glm::ext::div_floor;
+///
+/// let x = ivec4(7, -7, 7, -7);
+/// let y = ivec4(4, 4, -4, -4);
+/// assert_eq!(div_floor(x, y), ivec4(1, -2, -2, 1));
+/// ```
+#[inline]
+pub fn div_floor<I: BaseInt, T: GenInt<I>>(x: T, y: T) -> T {
+    x.zip(y, |a, b| -> I {
+        let (q, r) = (a / b, a % b);
+        if !r.is_zero() && ((r < I::zero()) != (b < I::zero())) {
+            q - I::one()
+        } else {
+            q
+        }
+    })
+}
+
+/// Raises each component of `x` to the power `e`.
+///
+/// `e` is either a scalar exponent for all components, or an integer vector
+/// of per-component exponents. Exponents are interpreted as `u32`.
+///
+/// Overflow behaves like the `*` operator, i.e., it panics in debug builds.
+/// Use `checked_ipow` or `wrapping_ipow` if overflow is expected.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ ivec3, uvec3 };
+/// use glm::ext::ipow;
+///
+/// assert_eq!(ipow(3_i32, 4), 81);
+/// assert_eq!(ipow(ivec3(2, -3, 7), 3), ivec3(8, -27, 343));
+/// assert_eq!(ipow(uvec3(2, 10, 5), uvec3(10, 3, 0)), uvec3(1024, 1000, 1));
+/// ```
+#[inline]
+pub fn ipow<I: BaseInt, T: GenInt<I>, N: BitCounts<I, T>>(x: T, e: N) -> T {
+    e.zip_counts(x, |c, k| -> I { c.pow(k) })
+}
+
+/// Raises each component of `x` to the power `e`, or returns `None` if any
+/// component overflows.
+///
+/// See `ipow` for the meaning of `e`.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ ivec2, uvec2 };
+/// use glm::ext::checked_ipow;
+///
+/// assert_eq!(checked_ipow(ivec2(2, -2), 30), Some(ivec2(1 << 30, 1 << 30)));
+/// assert_eq!(checked_ipow(ivec2(2, -2), 31), None);
+/// assert_eq!(checked_ipow(uvec2(2, 3), uvec2(31, 21)), None);
+/// ```
+#[inline]
+pub fn checked_ipow<I: IntPow, T: GenInt<I>, N: BitCounts<I, T>>(x: T, e: N) -> Option<T> {
+    let overflow = Cell::new(false);
+    let r = e.zip_counts(x, |c, k| -> I {
+        c.checked_pow(k).unwrap_or_else(|| {
+            overflow.set(true);
+            I::zero()
+        })
+    });
+    if overflow.get() { None } else { Some(r) }
+}
+
+/// Raises each component of `x` to the power `e`, wrapping around at the
+/// boundary of the type.
+///
+/// See `ipow` for the meaning of `e`.
+///
+/// # Example
+///
+/// ```
+/// use glm::uvec2;
+/// use glm::ext::wrapping_ipow;
+///
+/// assert_eq!(wrapping_ipow(2_i32, 31), i32::min_value());
+/// assert_eq!(wrapping_ipow(uvec2(2, 3), 32), uvec2(0, 3_u32.wrapping_pow(32)));
+/// ```
+#[inline]
+pub fn wrapping_ipow<I: IntPow, T: GenInt<I>, N: BitCounts<I, T>>(x: T, e: N) -> T {
+    e.zip_counts(x, |c, k| -> I { c.wrapping_pow(k) })
+}
+
+/// Float types that can be rounded into the integer type of the same shape,
+/// i.e., `f32` into `i32`, and `Vector3<f64>` into `IVec3`.
+pub trait IntRound<F: BaseFloat>: GenFloat<F> {
+    /// Integer type of the same shape with signed components.
+    type Int: GenInt<i32>;
+    /// Integer type of the same shape with unsigned components.
+    type UInt: GenInt<u32>;
+    /// Converts each component with `f` into a signed integer.
+    fn map_int<G: Fn(F) -> F>(self, f: G) -> Self::Int;
+    /// Converts each component with `f` into an unsigned integer.
+    fn map_uint<G: Fn(F) -> F>(self, f: G) -> Self::UInt;
+}
+
+// Casts from float to integer saturate, and `NaN` is converted to `0`.
+#[inline(always)]
+fn to_i32<F: BaseFloat>(x: F) -> i32 {
+    x.to_f64().unwrap() as i32
+}
+
+#[inline(always)]
+fn to_u32<F: BaseFloat>(x: F) -> u32 {
+    x.to_f64().unwrap() as u32
+}
+
+macro_rules! impl_scalar_IntRound {
+    ($($t: ty),+) => {
+        $(
+            impl IntRound<$t> for $t {
+                type Int = i32;
+                type UInt = u32;
+                #[inline(always)]
+                fn map_int<G: Fn($t) -> $t>(self, f: G) -> i32 {
+                    to_i32(f(self))
+                }
+                #[inline(always)]
+                fn map_uint<G: Fn($t) -> $t>(self, f: G) -> u32 {
+                    to_u32(f(self))
+                }
+            }
+        )+
+    }
+}
+
+impl_scalar_IntRound! { f32, f64 }
+
+macro_rules! impl_vec_IntRound {
+    ($({ $v: ident, $($field: ident),+ }),+) => {
+        $(
+            impl<F: BaseFloat> IntRound<F> for $v<F> {
+                type Int = $v<i32>;
+                type UInt = $v<u32>;
+                #[inline]
+                fn map_int<G: Fn(F) -> F>(self, f: G) -> $v<i32> {
+                    $v::new($(to_i32(f(self.$field))),+)
+                }
+                #[inline]
+                fn map_uint<G: Fn(F) -> F>(self, f: G) -> $v<u32> {
+                    $v::new($(to_u32(f(self.$field))),+)
+                }
+            }
+        )+
+    }
+}
+
+impl_vec_IntRound! {
+    { Vector2, x, y },
+    { Vector3, x, y, z },
+    { Vector4, x, y, z, w }
+}
+
+/// Rounds each component of `x` to the nearest integer, and returns the
+/// result as signed integers.
+///
+/// Halfway cases are rounded away from `0`, which is the same as `round`.
+/// Out of range components saturate to `i32::MIN` or `i32::MAX`, and `NaN`
+/// components are converted to `0`, so unlike `to_ivec3(round(x))` this
+/// function never panics.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ ivec3, vec3 };
+/// use glm::ext::iround;
+///
+/// assert_eq!(iround(2.5_f64), 3);
+/// assert_eq!(iround(vec3(-1.5, 0.49, 1e10)), ivec3(-2, 0, i32::max_value()));
+/// ```
+#[inline]
+pub fn iround<F: BaseFloat, T: IntRound<F>>(x: T) -> T::Int {
+    x.map_int(|c| -> F { c.round() })
+}
+
+/// Rounds each component of `x` to the nearest integer, and returns the
+/// result as unsigned integers.
+///
+/// Negative components are converted to `0`. See `iround` for the details.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ dvec2, uvec2 };
+/// use glm::ext::uround;
+///
+/// assert_eq!(uround(dvec2(-3., 254.5)), uvec2(0, 255));
+/// ```
+#[inline]
+pub fn uround<F: BaseFloat, T: IntRound<F>>(x: T) -> T::UInt {
+    x.map_uint(|c| -> F { c.round() })
+}
+
+/// Returns the largest integer less than or equal to each component of `x`
+/// as signed integers.
+///
+/// See `iround` for the handling of out of range components.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ ivec2, vec2 };
+/// use glm::ext::ifloor;
+///
+/// assert_eq!(ifloor(vec2(-0.5, 1.9)), ivec2(-1, 1));
+/// ```
+#[inline]
+pub fn ifloor<F: BaseFloat, T: IntRound<F>>(x: T) -> T::Int {
+    x.map_int(|c| -> F { c.floor() })
+}
+
+/// Returns the smallest integer greater than or equal to each component of
+/// `x` as signed integers.
+///
+/// See `iround` for the handling of out of range components.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ ivec2, vec2 };
+/// use glm::ext::iceil;
+///
+/// assert_eq!(iceil(vec2(-0.5, 1.1)), ivec2(0, 2));
+/// ```
+#[inline]
+pub fn iceil<F: BaseFloat, T: IntRound<F>>(x: T) -> T::Int {
+    x.map_int(|c| -> F { c.ceil() })
+}
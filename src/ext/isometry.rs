@@ -0,0 +1,172 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+use basenum::{ BaseFloat, ApproxEq };
+use vec::vec::Vector3;
+use mat::mat::{ Matrix3, Matrix4 };
+use mat::traits::GenMat;
+use std::ops::Mul;
+use num::{ One, Zero };
+
+/// A rigid transformation in 3D space, i.e., a rotation followed by a
+/// translation.
+///
+/// Unlike a general `Matrix4`, an isometry never scales or shears, so
+/// lengths and angles are preserved, and its inverse is cheap.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let rot = mat3(0., 1., 0., -1., 0., 0., 0., 0., 1.);
+/// let iso = Isometry3::new(rot, vec3(1., 2., 3.));
+/// let p = iso.transform_point(vec3(1., 0., 0.));
+/// assert_eq!(p, vec3(1., 3., 3.));
+/// assert_eq!(iso.inverse().transform_point(p), vec3(1., 0., 0.));
+/// assert_eq!(iso.transform_vector(vec3(1., 0., 0.)), vec3(0., 1., 0.));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Isometry3<T: BaseFloat> {
+    /// The rotation part, an orthonormal matrix.
+    pub rotation: Matrix3<T>,
+    /// The translation part.
+    pub translation: Vector3<T>,
+}
+
+impl<T: BaseFloat> Isometry3<T> {
+    /// Constructs an isometry from a rotation matrix and a translation.
+    ///
+    /// # Note
+    ///
+    /// `rotation` must be orthonormal with determinant `1`. It is not
+    /// checked.
+    #[inline(always)]
+    pub fn new(rotation: Matrix3<T>, translation: Vector3<T>) -> Isometry3<T> {
+        Isometry3 { rotation: rotation, translation: translation }
+    }
+
+    /// Returns the identity transformation.
+    #[inline]
+    pub fn identity() -> Isometry3<T> {
+        Isometry3::new(Matrix3::one(), Vector3::zero())
+    }
+
+    /// Constructs an isometry that only translates.
+    #[inline]
+    pub fn from_translation(translation: Vector3<T>) -> Isometry3<T> {
+        Isometry3::new(Matrix3::one(), translation)
+    }
+
+    /// Constructs an isometry that only rotates.
+    #[inline]
+    pub fn from_rotation(rotation: Matrix3<T>) -> Isometry3<T> {
+        Isometry3::new(rotation, Vector3::zero())
+    }
+
+    /// Returns the inverse transformation.
+    ///
+    /// Since the rotation is orthonormal, this only transposes the rotation,
+    /// and no general matrix inversion is involved.
+    #[inline]
+    pub fn inverse(&self) -> Isometry3<T> {
+        let r = self.rotation.transpose();
+        let t = r.mul_v(&self.translation);
+        Isometry3::new(r, -t)
+    }
+
+    /// Transforms point `p`, i.e., rotates and then translates it.
+    #[inline]
+    pub fn transform_point(&self, p: Vector3<T>) -> Vector3<T> {
+        self.rotation.mul_v(&p) + self.translation
+    }
+
+    /// Transforms vector `v`, i.e., only rotates it.
+    #[inline]
+    pub fn transform_vector(&self, v: Vector3<T>) -> Vector3<T> {
+        self.rotation.mul_v(&v)
+    }
+
+    /// Returns the equivalent 4 * 4 matrix.
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix4<T> {
+        let zero = T::zero();
+        let r = &self.rotation;
+        Matrix4::new(
+            r.c0.extend(zero),
+            r.c1.extend(zero),
+            r.c2.extend(zero),
+            self.translation.extend(T::one())
+        )
+    }
+}
+
+/// Composition. `a * b` applies `b` first, then `a`.
+impl<T: BaseFloat> Mul<Isometry3<T>> for Isometry3<T> {
+    type Output = Isometry3<T>;
+    #[inline]
+    fn mul(self, rhs: Isometry3<T>) -> Isometry3<T> {
+        Isometry3::new(
+            self.rotation.mul_m(&rhs.rotation),
+            self.transform_point(rhs.translation))
+    }
+}
+
+impl<T: BaseFloat> From<Isometry3<T>> for Matrix4<T> {
+    #[inline]
+    fn from(iso: Isometry3<T>) -> Matrix4<T> {
+        iso.to_matrix()
+    }
+}
+
+impl<T: BaseFloat> ApproxEq for Isometry3<T> {
+    type BaseType = T;
+    #[inline]
+    fn is_close_to(&self, rhs: &Isometry3<T>, max_diff: T) -> bool {
+        self.rotation.is_close_to(&rhs.rotation, max_diff) &&
+        self.translation.is_close_to(&rhs.translation, max_diff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use basenum::is_approx_eq;
+    use vec::vec::{ vec3, vec4 };
+    use mat::ctor::mat3;
+    use mat::mat::Mat4;
+    use super::*;
+
+    #[test]
+    fn test_compose() {
+        let a = Isometry3::new(mat3(0., 1., 0., -1., 0., 0., 0., 0., 1.), vec3(1., 2., 3.));
+        let b = Isometry3::new(mat3(1., 0., 0., 0., 0., 1., 0., -1., 0.), vec3(-4., 0., 2.));
+        let p = vec3(0.5, -1., 2.);
+        let ab = a * b;
+        assert!(is_approx_eq(&ab.transform_point(p), &a.transform_point(b.transform_point(p))));
+        assert!(is_approx_eq(&(ab * ab.inverse()), &Isometry3::identity()));
+        let m: Mat4 = ab.into();
+        assert!(is_approx_eq(&(m * vec4(p.x, p.y, p.z, 1.)).xyz(), &ab.transform_point(p)));
+    }
+}
@@ -0,0 +1,303 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+
+use basenum::BaseFloat;
+use builtin::{ cross, dot, length };
+use vec::vec::{ Vector3, Vector4 };
+use mat::traits::{ GenMat, GenSquareMat };
+use mat::mat::{ Matrix3, Matrix4 };
+use quat::Quaternion;
+use num::One;
+
+/// Components of a 4 * 4 transformation matrix, as returned by
+/// `Matrix4::decompose`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Decomposed<T: BaseFloat> {
+    /// Scaling factors along the local axes.
+    pub scale: Vector3<T>,
+    /// Rotation.
+    pub orientation: Quaternion<T>,
+    /// Translation.
+    pub translation: Vector3<T>,
+    /// Shear factors of the `yz`, `xz` and `xy` planes.
+    pub skew: Vector3<T>,
+    /// The last row of the matrix with the other components removed, which
+    /// is `(0, 0, 0, 1)` for affine transformations.
+    pub perspective: Vector4<T>,
+}
+
+impl<T: BaseFloat> Decomposed<T> {
+    /// Rebuilds the matrix, i.e., the inverse of `Matrix4::decompose`.
+    #[inline]
+    pub fn recompose(&self) -> Matrix4<T> {
+        Matrix4::recompose(
+            self.scale, self.orientation, self.translation, self.skew,
+            self.perspective
+        )
+    }
+}
+
+impl<T: BaseFloat> Matrix4<T> {
+    /// Decomposes `self` into scaling, rotation, translation, skew and
+    /// perspective components, as *GLM*'s `decompose` does.
+    ///
+    /// Returns `None` if `self` is singular or its bottom-right element is
+    /// zero (e.g., a perspective projection).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate num;
+    /// # extern crate glm;
+    /// # fn main() {
+    /// use glm::*;
+    /// use glm::ext::*;
+    ///
+    /// let q = Quat::from_axis_angle(normalize(vec3(1., 2., 3.)), 1.);
+    /// let m = scale(&world_from_pos_rot(vec3(1., 2., 3.), q), vec3(2., 3., 4.));
+    /// let d = m.decompose().unwrap();
+    /// assert!(is_close_to(&d.scale, &vec3(2., 3., 4.), 1e-5));
+    /// assert!(is_close_to(&d.orientation, &q, 1e-5));
+    /// assert!(is_close_to(&d.translation, &vec3(1., 2., 3.), 1e-5));
+    /// assert!(is_close_to(&d.skew, &num::zero(), 1e-5));
+    /// assert_eq!(d.perspective, vec4(0., 0., 0., 1.));
+    /// # }
+    /// ```
+    pub fn decompose(&self) -> Option<Decomposed<T>> {
+        let (ling, yi) = (T::zero(), T::one());
+        if self.c3.w.is_approx_eq(&ling) {
+            return None;
+        }
+        let mut m = *self / self.c3.w;
+        // `m` without perspective.
+        let mut p = m;
+        p.c0.w = ling;
+        p.c1.w = ling;
+        p.c2.w = ling;
+        p.c3.w = yi;
+        if p.determinant().is_approx_eq(&ling) {
+            return None;
+        }
+        let perspective =
+            if m.c0.w.is_zero() && m.c1.w.is_zero() && m.c2.w.is_zero() {
+                Vector4::new(ling, ling, ling, yi)
+            } else {
+                // `m = P * p`, where `P` is the identity with the last row
+                // replaced by `perspective`.
+                let rhs = Vector4::new(m.c0.w, m.c1.w, m.c2.w, m.c3.w);
+                let pers = p.inverse()?.transpose() * rhs;
+                m = p;
+                pers
+            };
+        let translation = m.c3.xyz();
+        // Gram-Schmidt process of the columns of the upper-left block.
+        let mut c0 = m.c0.xyz();
+        let mut c1 = m.c1.xyz();
+        let mut c2 = m.c2.xyz();
+        let mut scale = Vector3::new(length(c0), ling, ling);
+        c0 /= scale.x;
+        let mut skew = Vector3::new(ling, ling, dot(c0, c1));
+        c1 -= c0 * skew.z;
+        scale.y = length(c1);
+        c1 /= scale.y;
+        skew.z = skew.z / scale.y;
+        skew.y = dot(c0, c2);
+        c2 -= c0 * skew.y;
+        skew.x = dot(c1, c2);
+        c2 -= c1 * skew.x;
+        scale.z = length(c2);
+        c2 /= scale.z;
+        skew.y = skew.y / scale.z;
+        skew.x = skew.x / scale.z;
+        // flips all axes if the coordinate system is flipped.
+        if dot(c0, cross(c1, c2)) < ling {
+            scale = -scale;
+            c0 = -c0;
+            c1 = -c1;
+            c2 = -c2;
+        }
+        Some(Decomposed {
+            scale: scale,
+            orientation: Quaternion::from_mat3(&Matrix3::new(c0, c1, c2)),
+            translation: translation,
+            skew: skew,
+            perspective: perspective,
+        })
+    }
+
+    /// Builds a transformation matrix from its components, i.e., the inverse
+    /// of `decompose`.
+    ///
+    /// The result is `P * T * R * K * S`, where `P` is the identity with the
+    /// last row replaced by `perspective`, `T` is the translation, `R` is the
+    /// rotation, `K` is the skew and `S` is the scaling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate num;
+    /// # extern crate glm;
+    /// # fn main() {
+    /// use glm::*;
+    /// use glm::ext::*;
+    ///
+    /// let m = scale(&rotate(&translate(&num::one(), vec3(1., 2., 3.)), 1., vec3(1., 1., 0.)), vec3(2., 3., 4.));
+    /// let mut d = m.decompose().unwrap();
+    /// assert!(is_close_to(&d.recompose(), &m, 1e-5));
+    /// // doubles the scaling only.
+    /// d.scale = d.scale * 2.;
+    /// let s = Mat4::recompose(d.scale, d.orientation, d.translation, d.skew, d.perspective);
+    /// assert!(is_close_to(&s, &scale(&m, vec3(2., 2., 2.)), 1e-5));
+    /// # }
+    /// ```
+    pub fn recompose(
+        scale: Vector3<T>,
+        orientation: Quaternion<T>,
+        translation: Vector3<T>,
+        skew: Vector3<T>,
+        perspective: Vector4<T>
+    ) -> Matrix4<T> {
+        let ling = T::zero();
+        let r = orientation.to_mat3();
+        // columns of `R * K * S`.
+        let c0 = r.c0 * scale.x;
+        let c1 = (r.c1 + r.c0 * skew.z) * scale.y;
+        let c2 = (r.c2 + r.c1 * skew.x + r.c0 * skew.y) * scale.z;
+        let m = Matrix4::new(
+            c0.extend(ling),
+            c1.extend(ling),
+            c2.extend(ling),
+            translation.extend(T::one())
+        );
+        let mut pers = Matrix4::<T>::one();
+        pers.c0.w = perspective.x;
+        pers.c1.w = perspective.y;
+        pers.c2.w = perspective.z;
+        pers.c3.w = perspective.w;
+        pers * m
+    }
+}
+
+/// Interpolates transformations `a` and `b`.
+///
+/// Both matrices are decomposed (see `Matrix4::decompose`), the scaling,
+/// translation, skew and perspective components are interpolated linearly,
+/// the rotations are interpolated spherically, and then the components are
+/// recomposed. Unlike mixing the matrices element-wise, the rotation and
+/// scaling of intermediate transformations are preserved.
+///
+/// If either matrix can not be decomposed, the matrices are mixed
+/// element-wise.
+///
+/// # Example
+///
+/// ```
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let id: Mat4 = num::one();
+/// let a = translate(&id, vec3(1., 0., 0.));
+/// let b = rotate(&translate(&id, vec3(3., 0., 0.)), half_pi(), vec3(0., 0., 1.));
+/// let m = interpolate(&a, &b, 0.5);
+/// let r = rotate(&translate(&id, vec3(2., 0., 0.)), quarter_pi(), vec3(0., 0., 1.));
+/// assert!(is_close_to(&m, &r, 1e-5));
+/// # }
+/// ```
+pub fn interpolate<T: BaseFloat>(a: &Matrix4<T>, b: &Matrix4<T>, t: T) -> Matrix4<T> {
+    let u = T::one() - t;
+    match (a.decompose(), b.decompose()) {
+        (Some(da), Some(db)) => Matrix4::recompose(
+            da.scale * u + db.scale * t,
+            da.orientation.slerp(&db.orientation, t),
+            da.translation * u + db.translation * t,
+            da.skew * u + db.skew * t,
+            da.perspective * u + db.perspective * t
+        ),
+        _ => *a * u + *b * t,
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use basenum::is_close_to;
+    use vec::vec::{ dvec3, dvec4 };
+    use mat::mat::DMat4;
+    use quat::DQuat;
+    use builtin::normalize;
+    use super::interpolate;
+
+    #[test]
+    fn test_round_trip() {
+        let q = DQuat::from_axis_angle(normalize(dvec3(-1., 2., 1.)), 2.5);
+        let m = DMat4::recompose(
+            dvec3(2., 3., 0.5), q, dvec3(4., 5., 6.), dvec3(0.1, -0.2, 0.3),
+            dvec4(0., 0., 0., 1.)
+        );
+        let d = m.decompose().unwrap();
+        assert_close_to!(d.recompose(), m, 1e-12);
+        assert_close_to!(d.scale, dvec3(2., 3., 0.5), 1e-12);
+        assert_close_to!(d.orientation, q, 1e-12);
+        assert_close_to!(d.skew, dvec3(0.1, -0.2, 0.3), 1e-12);
+        // a flipped coordinate system negates all the scaling factors.
+        let m = DMat4::recompose(
+            dvec3(2., -3., 0.5), q, dvec3(4., 5., 6.), dvec3(0., 0., 0.),
+            dvec4(0., 0., 0., 1.)
+        );
+        let d = m.decompose().unwrap();
+        assert_close_to!(d.scale, dvec3(-2., -3., -0.5), 1e-12);
+        assert_close_to!(d.recompose(), m, 1e-12);
+        // the result is normalized so that the bottom-right element is `1`.
+        let m = DMat4::recompose(
+            dvec3(2., 3., 0.5), q, dvec3(4., 5., 6.), dvec3(0.1, -0.2, 0.3),
+            dvec4(0.01, -0.02, 0.03, 1.)
+        );
+        let d = m.decompose().unwrap();
+        assert_close_to!(d.recompose(), m / m.c3.w, 1e-12);
+        assert_close_to!(d.skew, dvec3(0.1, -0.2, 0.3), 1e-12);
+        let mut s = m;
+        s.c3.w = 0.;
+        assert_eq!(s.decompose(), None);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let qa = DQuat::from_axis_angle(normalize(dvec3(1., 1., 0.)), 0.5);
+        let qb = DQuat::from_axis_angle(normalize(dvec3(0., 1., 1.)), 2.);
+        let k = dvec3(0., 0., 0.);
+        let p = dvec4(0., 0., 0., 1.);
+        let a = DMat4::recompose(dvec3(1., 2., 3.), qa, dvec3(1., 2., 3.), k, p);
+        let b = DMat4::recompose(dvec3(3., 2., 1.), qb, dvec3(-1., 0., 5.), k, p);
+        assert_close_to!(interpolate(&a, &b, 0.), a, 1e-12);
+        assert_close_to!(interpolate(&a, &b, 1.), b, 1e-12);
+        let m = DMat4::recompose(dvec3(2., 2., 2.), qa.slerp(&qb, 0.5), dvec3(0., 1., 4.), k, p);
+        assert_close_to!(interpolate(&a, &b, 0.5), m, 1e-12);
+        // falls back to the element-wise mix.
+        let z = DMat4::new(a.c0, a.c1, a.c2, dvec4(0., 0., 0., 0.));
+        assert_close_to!(interpolate(&z, &b, 0.5), (z + b) * 0.5, 1e-12);
+    }
+}
@@ -21,13 +21,20 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
-use basenum::BaseFloat;
-use vec::traits::GenFloatVec;
-use mat::traits::GenSquareMat;
+use basenum::{ BaseNum, SignedNum, BaseFloat };
+use vec::traits::{ GenVec, GenFloatVec };
+use mat::traits::{ GenMat, GenSquareMat };
+use mat::mat::Matrix4;
+use builtin as bif;
+use error::GlmError;
+use num::Zero;
+use std::ops::Range;
 
 pub use self::transform::*;
+pub use self::decompose::{ Decomposed, interpolate };
 
 mod transform;
+mod decompose;
 
 /// Returns the trace of a square matrix `m`.
 ///
@@ -42,7 +49,7 @@ mod transform;
 /// ```
 #[inline]
 pub fn trace<F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>>(m: &M) -> F {
-    let s = C::dim();
+    let s = C::DIM;
     let mut tr = F::zero();
     for i in 0..s {
         tr = tr + m[i][i];
@@ -72,3 +79,615 @@ F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>
     let y = F::zero();
     !m.determinant().is_approx_eq(&y)
 }
+
+/// Returns the inverse of the square matrix `m`, or
+/// `Err(GlmError::SingularMatrix)` if `m` is not invertible.
+///
+/// This is the fallible version of the built-in function `inverse`, which
+/// panics in this case.
+///
+/// # Example
+///
+/// ```
+/// use glm::{ mat2, GlmError };
+/// use glm::ext::try_inverse;
+///
+/// assert_eq!(try_inverse(&mat2(1., 2., 3., 6.)), Err(GlmError::SingularMatrix));
+/// assert_eq!(try_inverse(&mat2(2., 0., 0., 4.)), Ok(mat2(0.5, 0., 0., 0.25)));
+/// ```
+#[inline]
+pub fn try_inverse
+<
+F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>
+>(m: &M) -> Result<M, GlmError> {
+    m.inverse().ok_or(GlmError::SingularMatrix)
+}
+
+/// Returns the minor of the element at row `i` and column `j` of the square
+/// matrix `m`.
+///
+/// # Panic
+///
+/// It is a panic if `i` or `j` is out of range.
+///
+/// # Example
+///
+/// ```
+/// use glm::mat2;
+/// use glm::ext::minor;
+///
+/// let m2 = mat2(1., 3., 2., 4.);
+/// assert_eq!(minor(&m2, 0, 1), 3.);
+/// ```
+#[inline(always)]
+pub fn minor
+<
+F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>
+>(m: &M, i: usize, j: usize) -> F {
+    m.minor(i, j)
+}
+
+/// Returns the cofactor of the element at row `i` and column `j` of the
+/// square matrix `m`.
+///
+/// # Panic
+///
+/// It is a panic if `i` or `j` is out of range.
+///
+/// # Example
+///
+/// ```
+/// use glm::mat2;
+/// use glm::ext::cofactor;
+///
+/// let m2 = mat2(1., 3., 2., 4.);
+/// assert_eq!(cofactor(&m2, 0, 1), -3.);
+/// ```
+#[inline(always)]
+pub fn cofactor
+<
+F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>
+>(m: &M, i: usize, j: usize) -> F {
+    m.cofactor(i, j)
+}
+
+/// Returns the rank of matrix `m`, i.e., the number of linearly independent
+/// columns (or rows).
+///
+/// The rank is computed by a Gaussian elimination with partial pivoting.
+/// A pivot is considered as zero if its absolute value is not larger than
+/// `eps` times the largest absolute value of elements of `m`, so the result
+/// does not depend on the scale of `m`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::rank;
+///
+/// assert_eq!(rank(&mat3(1., 2., 3., 2., 4., 6., 0., 1., 0.), 1e-6), 2);
+/// assert_eq!(rank(&mat2x3(1., 0., 0., 0., 1., 0.), 1e-6), 2);
+/// assert_eq!(rank(&mat2(0., 0., 0., 0.), 1e-6), 0);
+/// ```
+pub fn rank<F: BaseFloat, C: GenFloatVec<F>, M: GenMat<F, C>>(m: &M, eps: F) -> usize {
+    let rows = C::DIM;
+    let cols = M::R::DIM;
+    let ling = F::zero();
+    // row major copy of `m`.
+    let mut a = [[ling; 4]; 4];
+    let mut max_abs = ling;
+    for j in 0..cols {
+        for i in 0..rows {
+            a[i][j] = m[j][i];
+            max_abs = BaseNum::max(max_abs, a[i][j].abs());
+        }
+    }
+    let tol = eps * max_abs;
+    let mut r = 0;
+    for j in 0..cols {
+        if r == rows {
+            break;
+        }
+        let mut p = r;
+        for i in (r + 1)..rows {
+            if a[i][j].abs() > a[p][j].abs() {
+                p = i;
+            }
+        }
+        if a[p][j].abs() <= tol {
+            continue;
+        }
+        a.swap(r, p);
+        for i in (r + 1)..rows {
+            let f = a[i][j] / a[r][j];
+            sub_row(&mut a, i, r, f, j..cols);
+        }
+        r += 1;
+    }
+    r
+}
+
+// Subtracts `f` times row `src` of `a` from row `dst`, in columns `cols`.
+#[inline]
+fn sub_row<F: BaseFloat>(a: &mut [[F; 4]; 4], dst: usize, src: usize, f: F, cols: Range<usize>) {
+    let s = a[src];
+    for (x, y) in a[dst][cols.clone()].iter_mut().zip(&s[cols]) {
+        *x = *x - f * *y;
+    }
+}
+
+/// Returns `true` if the square matrix `m` is singular, i.e., its rank is
+/// less than its dimension.
+///
+/// See [`rank`](fn.rank.html) for the meaning of `eps`.
+///
+/// # Note
+///
+/// Unlike [`is_invertible`](fn.is_invertible.html), which compares the
+/// determinant with `0`, this function is not sensitive to the scale of `m`.
+///
+/// # Example
+///
+/// ```
+/// use glm::mat2;
+/// use glm::ext::{ is_invertible, is_singular };
+///
+/// let m = mat2(1e-4, 0., 0., 1e-4);
+/// assert!(!is_invertible(&m));
+/// assert!(!is_singular(&m, 1e-6));
+/// assert!(is_singular(&mat2(1., 2., 2., 4.), 1e-6));
+/// ```
+#[inline]
+pub fn is_singular
+<
+F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>
+>(m: &M, eps: F) -> bool {
+    rank(m, eps) < C::DIM
+}
+
+// the maximum absolute column sum.
+#[inline]
+fn norm1<F: BaseFloat, C: GenFloatVec<F>, M: GenMat<F, C>>(m: &M) -> F {
+    let mut n = F::zero();
+    for j in 0..M::R::DIM {
+        n = BaseNum::max(n, m[j].abs().sum());
+    }
+    n
+}
+
+/// Returns the condition number of the square matrix `m` with respect to
+/// the 1-norm, i.e., `‖m‖₁ ⋅ ‖m⁻¹‖₁`.
+///
+/// The larger the result, the more sensitive the solutions of linear systems
+/// of `m` (including the inverse) are to errors in input. Infinity is
+/// returned if `m` is not invertible.
+///
+/// The inverse is computed by `inverse_pivoted` with tolerance `F::epsilon()`,
+/// so that the result does not depend on the scale of `m`.
+///
+/// # Example
+///
+/// ```
+/// # extern crate glm;
+/// # extern crate num;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::condition_number;
+/// use num::One;
+///
+/// assert_eq!(condition_number(&Mat3::one()), 1.);
+/// // a uniformly scaled identity is perfectly conditioned, however small.
+/// assert!(is_close_to(&condition_number(&(Mat4::one() * 1e-3)), &1., 1e-6));
+/// assert!(is_close_to(&condition_number(&(DMat4::one() * 1e-30)), &1., 1e-12));
+/// assert_eq!(condition_number(&mat2(1., 0., 0., 100.)), 100.);
+/// assert!(condition_number(&dmat2(1., 1., 1., 1. + 1e-10)) > 1e10);
+/// assert_eq!(condition_number(&mat2(1., 2., 2., 4.)), std::f32::INFINITY);
+/// # }
+/// ```
+pub fn condition_number
+<
+F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>
+>(m: &M) -> F {
+    match inverse_pivoted(m, F::epsilon()) {
+        Some(inv) => norm1(m) * norm1(&inv),
+        None => F::infinity(),
+    }
+}
+
+// Solves the `n * n` linear system `a * x = b` by Gaussian elimination with
+// partial pivoting. Returns `None` if `a` is (numerically) singular.
+fn solve<F: BaseFloat>(mut a: [[F; 4]; 4], mut b: [F; 4], n: usize) -> Option<[F; 4]> {
+    let ling = F::zero();
+    let mut max_abs = ling;
+    for row in &a[..n] {
+        for x in &row[..n] {
+            max_abs = BaseNum::max(max_abs, x.abs());
+        }
+    }
+    let tol = F::epsilon() * max_abs * F::from(n).unwrap();
+    for j in 0..n {
+        let mut p = j;
+        for i in (j + 1)..n {
+            if a[i][j].abs() > a[p][j].abs() {
+                p = i;
+            }
+        }
+        if a[p][j].abs() <= tol {
+            return None;
+        }
+        a.swap(j, p);
+        b.swap(j, p);
+        for i in (j + 1)..n {
+            let f = a[i][j] / a[j][j];
+            sub_row(&mut a, i, j, f, j..n);
+            b[i] = b[i] - f * b[j];
+        }
+    }
+    let mut x = [ling; 4];
+    for i in (0..n).rev() {
+        let mut s = b[i];
+        for k in (i + 1)..n {
+            s = s - a[i][k] * x[k];
+        }
+        x[i] = s / a[i][i];
+    }
+    Some(x)
+}
+
+/// Solves the linear system `a * x = b` in the least-squares sense.
+///
+/// - If `a` has more rows than columns (over-determined), returns the `x`
+///   that minimizes `‖a * x - b‖`.
+/// - If `a` has less rows than columns (under-determined), returns the
+///   solution `x` that has the minimal length.
+/// - If `a` is square, returns the exact solution.
+///
+/// Returns `None` if `a` does not have full rank.
+///
+/// # Note
+///
+/// The solution is computed from the normal equations, which square the
+/// condition number of `a`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::lstsq;
+///
+/// // fits line `y = k * x + d` to points (0, 1), (1, 3) and (2, 5).
+/// let a = mat2x3(0., 1., 2., 1., 1., 1.);
+/// let kd = lstsq(&a, vec3(1., 3., 5.)).unwrap();
+/// assert!(is_close_to(&kd, &vec2(2., 1.), 1e-5));
+///
+/// // the point closest to the origin that satisfies `x + y + z = 3` and
+/// // `y = 0`.
+/// let p = lstsq(&mat3x2(1., 0., 1., 1., 1., 0.), vec2(3., 0.));
+/// assert!(is_close_to(&p.unwrap(), &vec3(1.5, 0., 1.5), 1e-5));
+/// ```
+pub fn lstsq<F: BaseFloat, C: GenFloatVec<F>, M: GenMat<F, C>>(a: &M, b: C) -> Option<M::R> {
+    let rows = C::DIM;
+    let cols = M::R::DIM;
+    let ling = F::zero();
+    let mut n = [[ling; 4]; 4];
+    let mut rhs = [ling; 4];
+    if rows >= cols {
+        // (aᵀ * a) * x = aᵀ * b
+        for i in 0..cols {
+            for j in 0..cols {
+                n[i][j] = bif::dot(a[i], a[j]);
+            }
+            rhs[i] = bif::dot(a[i], b);
+        }
+        solve(n, rhs, cols).map(|y| {
+            let mut x = M::R::zero();
+            for i in 0..cols {
+                x[i] = y[i];
+            }
+            x
+        })
+    } else {
+        // x = aᵀ * y, where (a * aᵀ) * y = b
+        for i in 0..rows {
+            for j in 0..rows {
+                let mut d = ling;
+                for k in 0..cols {
+                    d = d + a[k][i] * a[k][j];
+                }
+                n[i][j] = d;
+            }
+            rhs[i] = b[i];
+        }
+        solve(n, rhs, rows).map(|y| {
+            let mut x = M::R::zero();
+            for k in 0..cols {
+                let mut d = ling;
+                for i in 0..rows {
+                    d = d + a[k][i] * y[i];
+                }
+                x[k] = d;
+            }
+            x
+        })
+    }
+}
+
+/// Returns the component-wise absolute value of matrix `m`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert_eq!(abs_m(&mat2(-1., 2., -3., 0.)), mat2(1., 2., 3., 0.));
+/// ```
+#[inline]
+pub fn abs_m<F: BaseFloat, C: GenFloatVec<F>, M: GenMat<F, C>>(m: &M) -> M {
+    m.map(|x| SignedNum::abs(&x))
+}
+
+/// Returns the component-wise minimum of matrices `x` and `y`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let x = mat2(1., 5., 3., 0.);
+/// let y = mat2(2., 4., 3., -1.);
+/// assert_eq!(min_m(&x, &y), mat2(1., 4., 3., -1.));
+/// ```
+#[inline]
+pub fn min_m<F: BaseFloat, C: GenFloatVec<F>, M: GenMat<F, C>>(x: &M, y: &M) -> M {
+    x.zip(y, BaseNum::min)
+}
+
+/// Returns the component-wise maximum of matrices `x` and `y`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let x = mat2(1., 5., 3., 0.);
+/// let y = mat2(2., 4., 3., -1.);
+/// assert_eq!(max_m(&x, &y), mat2(2., 5., 3., 0.));
+/// ```
+#[inline]
+pub fn max_m<F: BaseFloat, C: GenFloatVec<F>, M: GenMat<F, C>>(x: &M, y: &M) -> M {
+    x.zip(y, BaseNum::max)
+}
+
+/// Constrains each element of matrix `x` to lie between the corresponding
+/// elements of `min_val` and `max_val`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = mat2(-2., 0.5, 3., 1.);
+/// let lo = mat2(0., 0., 0., 0.);
+/// let hi = mat2(1., 1., 1., 1.);
+/// assert_eq!(clamp_m(&m, &lo, &hi), mat2(0., 0.5, 1., 1.));
+/// ```
+#[inline]
+pub fn clamp_m<F: BaseFloat, C: GenFloatVec<F>, M: GenMat<F, C>>(
+    x: &M,
+    min_val: &M,
+    max_val: &M
+) -> M {
+    min_m(&max_m(x, min_val), max_val)
+}
+
+/// Returns the component-wise linear blend of matrices `x` and `y`, i.e.,
+/// `x * (1 − a) + y * a`, where `a` is also a matrix.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let x = mat2(0., 0., 0., 0.);
+/// let y = mat2(2., 4., 6., 8.);
+/// let a = mat2(0.5, 0.5, 0., 1.);
+/// assert_eq!(mix_m(&x, &y, &a), mat2(1., 2., 0., 8.));
+/// ```
+#[inline]
+pub fn mix_m<F: BaseFloat, C: GenFloatVec<F>, M: GenMat<F, C>>(x: &M, y: &M, a: &M) -> M {
+    let xa = x.zip(a, |x, a| x * (F::one() - a));
+    let ya = y.mul_c(a);
+    xa + ya
+}
+
+// row major copy of the square matrix `m`.
+#[inline]
+fn to_rows<F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>>(m: &M) -> [[F; 4]; 4] {
+    let n = C::DIM;
+    let mut a = [[F::zero(); 4]; 4];
+    for j in 0..n {
+        for i in 0..n {
+            a[i][j] = m[j][i];
+        }
+    }
+    a
+}
+
+// index of the row in `j..n` whose element at column `j` has the largest
+// absolute value.
+#[inline]
+fn pivot_row<F: BaseFloat>(a: &[[F; 4]; 4], j: usize, n: usize) -> usize {
+    let mut p = j;
+    for i in (j + 1)..n {
+        if a[i][j].abs() > a[p][j].abs() {
+            p = i;
+        }
+    }
+    p
+}
+
+/// Returns the determinant of the square matrix `m`, computed by Gaussian
+/// elimination with partial pivoting.
+///
+/// The cofactor expansion of the built-in `determinant` subtracts products
+/// of similar magnitude, which loses precision for badly conditioned
+/// matrices. This function is slower but more accurate in that case.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::determinant_pivoted;
+///
+/// let m = mat3(2., 0., 1., 1., 3., 2., 1., 1., 1.);
+/// assert!(is_close_to(&determinant_pivoted(&m), &determinant(&m), 1e-6));
+/// ```
+pub fn determinant_pivoted
+<
+F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>
+>(m: &M) -> F {
+    let n = C::DIM;
+    let mut a = to_rows(m);
+    let mut det = F::one();
+    for j in 0..n {
+        let p = pivot_row(&a, j, n);
+        if a[p][j].is_zero() {
+            return F::zero();
+        }
+        if p != j {
+            a.swap(p, j);
+            det = -det;
+        }
+        det = det * a[j][j];
+        for i in (j + 1)..n {
+            let f = a[i][j] / a[j][j];
+            sub_row(&mut a, i, j, f, (j + 1)..n);
+        }
+    }
+    det
+}
+
+/// Returns the inverse of the square matrix `m` computed by Gauss-Jordan
+/// elimination with partial pivoting, or `None` if `m` is singular with
+/// respect to tolerance `eps`.
+///
+/// A pivot is considered as zero if its absolute value is not larger than
+/// `eps` times the largest absolute value of elements of `m`. So unlike the
+/// built-in `inverse`, which compares the determinant with an absolute
+/// threshold, the singularity detection does not depend on the scale of
+/// `m`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::inverse_pivoted;
+///
+/// // the built-in `inverse` considers a tiny but well conditioned matrix
+/// // to be singular.
+/// let m = mat3(1e-3, 0., 0., 0., 2e-3, 0., 0., 0., 4e-3);
+/// assert_eq!(m.inverse(), None);
+/// let inv = inverse_pivoted(&m, 1e-6).unwrap();
+/// assert!(is_close_to(&inv, &mat3(1e3, 0., 0., 0., 500., 0., 0., 0., 250.), 1e-3));
+///
+/// // and a large but nearly singular matrix to be invertible.
+/// let m = dmat3(1e3, 2e3, 3e3, 4e3, 5e3, 6e3, 7e3, 8e3, 9e3 + 1e-9);
+/// assert!(m.inverse().is_some());
+/// assert_eq!(inverse_pivoted(&m, 1e-12), None);
+/// ```
+pub fn inverse_pivoted
+<
+F: BaseFloat, C: GenFloatVec<F>, M: GenSquareMat<F, C>
+>(m: &M, eps: F) -> Option<M> {
+    let n = C::DIM;
+    let (ling, yi) = (F::zero(), F::one());
+    let mut a = to_rows(m);
+    let mut b = [[ling; 4]; 4];
+    let mut max_abs = ling;
+    for (i, row) in a[..n].iter().enumerate() {
+        b[i][i] = yi;
+        for x in &row[..n] {
+            max_abs = BaseNum::max(max_abs, x.abs());
+        }
+    }
+    let tol = eps * max_abs;
+    for j in 0..n {
+        let p = pivot_row(&a, j, n);
+        if a[p][j].abs() <= tol || a[p][j].is_zero() {
+            return None;
+        }
+        a.swap(j, p);
+        b.swap(j, p);
+        let r = a[j][j].recip();
+        for k in 0..n {
+            a[j][k] = a[j][k] * r;
+            b[j][k] = b[j][k] * r;
+        }
+        for i in 0..n {
+            if i == j {
+                continue;
+            }
+            let f = a[i][j];
+            for k in 0..n {
+                a[i][k] = a[i][k] - f * a[j][k];
+                b[i][k] = b[i][k] - f * b[j][k];
+            }
+        }
+    }
+    let mut inv = M::zero();
+    for j in 0..n {
+        for i in 0..n {
+            inv[j][i] = b[i][j];
+        }
+    }
+    Some(inv)
+}
+
+/// Multiplies the matrices of `a` and `b` pair-wise, and writes the products
+/// into `out`, i.e., `out[i] = a[i] * b[i]`.
+///
+/// This is useful for transforming many matrices at once, e.g., for computing
+/// the world transforms (`parent * local`) of all bones of a skeleton.
+///
+/// With feature `simd`, `f32` products keep the columns of `a[i]` in SSE
+/// registers for all four columns of the product.
+///
+/// # Panic
+///
+/// It is a panic if the three slices do not have the same length.
+///
+/// # Example
+///
+/// ```
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::{ multiply_mat4_arrays, translate };
+///
+/// let i: Mat4 = num::one();
+/// let parents = [translate(&i, vec3(1., 0., 0.)), i];
+/// let locals = [translate(&i, vec3(0., 2., 0.)), translate(&i, vec3(0., 0., 3.))];
+/// let mut out = [i; 2];
+/// multiply_mat4_arrays(&parents, &locals, &mut out);
+/// assert_eq!(out[0][3], vec4(1., 2., 0., 1.));
+/// assert_eq!(out[1], locals[1]);
+/// # }
+/// ```
+pub fn multiply_mat4_arrays<F: BaseFloat>(
+    a: &[Matrix4<F>],
+    b: &[Matrix4<F>],
+    out: &mut [Matrix4<F>]
+) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+    for ((l, r), o) in a.iter().zip(b.iter()).zip(out.iter_mut()) {
+        let (l, r): ([[F; 4]; 4], [[F; 4]; 4]) = ((*l).into(), (*r).into());
+        *o = F::mul_mat4(&l, &r).into();
+    }
+}
@@ -1,9 +1,13 @@
 use basenum::BaseFloat;
-use builtin::{ cross, dot, normalize };
+use builtin::{ cross, dot, length, normalize };
+use vec::traits::GenNumVec;
 use traits::GenFloat;
 use num;
 use mat::mat::{ Matrix3, Matrix4 };
-use vec::vec::{ Vector3, Vector4 };
+use quat::Quaternion;
+use builtin::inverse;
+use ext::{ NdcConvention, ndc_to_window, window_to_ndc };
+use vec::vec::{ Vector2, Vector3, Vector4 };
 
 /// Builds a translation 4 * 4 matrix created from a vector of 3 components.
 ///
@@ -35,171 +39,1907 @@ where
         m.c0 * v.x + m.c1 * v.y + m.c2 * v.z + m.c3)
 }
 
-/// Creates a matrix for a symetric perspective-view frustum based on the default handedness.
+macro_rules! def_clip_variant {
+    ($imp: ident, $desc: expr, ($($arg: ident),+), $name: ident, $lh: expr, $zo: expr, $conv: expr) => {
+        #[doc = concat!($desc, "\n\n", $conv)]
+        #[inline]
+        pub fn $name<T: BaseFloat>($($arg: T),+) -> Matrix4<T> {
+            $imp($($arg),+, $lh, $zo)
+        }
+    }
+}
+
+// Defines the variants of a projection for all the combinations of
+// handedness (`_lh` and `_rh`) and depth range of the clip space (`_zo` for
+// `[0, 1]` and `_no` for `[-1, 1]`), named in the order of
+// `_rh_no`, `_rh_zo`, `_lh_no`, `_lh_zo`, `_rh`, `_lh`, `_no` and `_zo`.
+macro_rules! def_clip_variants {
+    (
+        $imp: ident, $desc: expr, ($($arg: ident),+),
+        $rh_no: ident, $rh_zo: ident, $lh_no: ident, $lh_zo: ident,
+        $rh: ident, $lh: ident, $no: ident, $zo: ident
+    ) => {
+        def_clip_variant!($imp, $desc, ($($arg),+), $rh_no, false, false,
+            "Right handed, and the depth range of the clip space is `[-1, 1]` (OpenGL).");
+        def_clip_variant!($imp, $desc, ($($arg),+), $rh_zo, false, true,
+            "Right handed, and the depth range of the clip space is `[0, 1]` (Vulkan).");
+        def_clip_variant!($imp, $desc, ($($arg),+), $lh_no, true, false,
+            "Left handed, and the depth range of the clip space is `[-1, 1]`.");
+        def_clip_variant!($imp, $desc, ($($arg),+), $lh_zo, true, true,
+            "Left handed, and the depth range of the clip space is `[0, 1]` (Direct3D, Metal).");
+        def_clip_variant!($imp, $desc, ($($arg),+), $rh, false, false,
+            "Right handed, and the depth range of the clip space is `[-1, 1]`.");
+        def_clip_variant!($imp, $desc, ($($arg),+), $lh, true, false,
+            "Left handed, and the depth range of the clip space is `[-1, 1]`.");
+        def_clip_variant!($imp, $desc, ($($arg),+), $no, false, false,
+            "Right handed, and the depth range of the clip space is `[-1, 1]`.");
+        def_clip_variant!($imp, $desc, ($($arg),+), $zo, false, true,
+            "Right handed, and the depth range of the clip space is `[0, 1]`.");
+    }
+}
+
+// `z` and `w` components of the 3rd column, and `z` component of the 4th
+// column of perspective projections.
+#[inline]
+fn perspective_depth<T>(
+    z_near: T,
+    z_far: T,
+    lh: bool,
+    zo: bool
+) -> (T, T, T)
+where
+    T : BaseFloat
+{
+    let one = num::one::<T>();
+    let two = one + one;
+    let s = if lh { one } else { -one };
+    let d = z_far - z_near;
+    if zo {
+        (s * z_far / d, s, -(z_far * z_near) / d)
+    } else {
+        (s * (z_far + z_near) / d, s, -(two * z_far * z_near) / d)
+    }
+}
+
+fn perspective_impl<T>(
+    fov_y: T,
+    aspect: T,
+    z_near: T,
+    z_far: T,
+    lh: bool,
+    zo: bool
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let two = one + one;
+    let q = one / (fov_y / two).tan();
+    let a = q / aspect;
+    let (b, w, c) = perspective_depth(z_near, z_far, lh, zo);
+
+    Matrix4::new(
+        Vector4::new(   a, zero, zero, zero),
+        Vector4::new(zero,    q, zero, zero),
+        Vector4::new(zero, zero,    b,    w),
+        Vector4::new(zero, zero,    c, zero)
+    )
+}
+
+/// Creates a matrix for a symetric perspective-view frustum based on the
+/// default conventions, i.e., right handed with depth range `[-1, 1]` of
+/// the clip space (OpenGL).
+///
+/// Variants of other conventions, e.g., `perspective_rh_zo` for Vulkan and
+/// `perspective_lh_zo` for Direct3D and Metal, are also available.
+///
+/// `fov_y` is the field of view angle in the y direction in radians.
+/// The `aspect` ratio determines the field of view in the x direction.
+/// `near_z` is the distance from the viewer to the near clipping plane (always positive) and
+/// `far_z` is the distance from the viewer to the far clipping plane (always positive).
+#[inline]
+pub fn perspective<T>(
+    fov_y: T,
+    aspect: T,
+    z_near: T,
+    z_far: T
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    perspective_impl(fov_y, aspect, z_near, z_far, false, false)
+}
+
+def_clip_variants! {
+    perspective_impl,
+    "Creates a matrix for a symetric perspective-view frustum.\n\nSee `perspective`.",
+    (fov_y, aspect, z_near, z_far),
+    perspective_rh_no, perspective_rh_zo, perspective_lh_no, perspective_lh_zo,
+    perspective_rh, perspective_lh, perspective_no, perspective_zo
+}
+
+/// Creates a matrix for a symmetric perspective-view frustum from the size
+/// of the viewport.
+///
+/// This is the same as `perspective(fov, width / height, z_near, z_far)`,
+/// so the conventions of the clip space are the same as `perspective`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = perspective_fov(1., 800., 600., 0.1, 100.);
+/// assert_eq!(m, perspective(1., 800. / 600., 0.1, 100.));
+/// ```
+#[inline]
+pub fn perspective_fov<T>(
+    fov: T,
+    width: T,
+    height: T,
+    z_near: T,
+    z_far: T
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    perspective(fov, width / height, z_near, z_far)
+}
+
+#[inline]
+fn perspective_fov_impl<T>(
+    fov: T,
+    width: T,
+    height: T,
+    z_near: T,
+    z_far: T,
+    lh: bool,
+    zo: bool
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    perspective_impl(fov, width / height, z_near, z_far, lh, zo)
+}
+
+def_clip_variants! {
+    perspective_fov_impl,
+    "Creates a matrix for a symmetric perspective-view frustum from the size \
+     of the viewport.\n\nSee `perspective_fov`.",
+    (fov, width, height, z_near, z_far),
+    perspective_fov_rh_no, perspective_fov_rh_zo, perspective_fov_lh_no, perspective_fov_lh_zo,
+    perspective_fov_rh, perspective_fov_lh, perspective_fov_no, perspective_fov_zo
+}
+
+/// Creates a matrix for a symmetric perspective-view frustum with the far
+/// clipping plane at infinity, based on the default conventions (right
+/// handed, depth range `[-1, 1]`).
+///
+/// See `perspective` for the conventions.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = infinite_perspective(half_pi(), 1., 0.1);
+/// let p = m * vec4(0., 0., -0.1, 1.);
+/// assert!(is_close_to(&(p.z / p.w), &-1., 1e-6));
+/// // the depth approaches `1` as the distance increases.
+/// let p = m * vec4(0., 0., -1e6, 1.);
+/// assert!(is_close_to(&(p.z / p.w), &1., 1e-6));
+/// ```
+#[inline]
+pub fn infinite_perspective<T>(
+    fov_y: T,
+    aspect: T,
+    z_near: T
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    infinite_perspective_impl(fov_y, aspect, z_near, false, false)
+}
+
+fn infinite_perspective_impl<T>(
+    fov_y: T,
+    aspect: T,
+    z_near: T,
+    lh: bool,
+    zo: bool
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let two = one + one;
+    let q = one / (fov_y / two).tan();
+    let s = if lh { one } else { -one };
+    let c = if zo { -z_near } else { -two * z_near };
+
+    Matrix4::new(
+        Vector4::new(q / aspect, zero, zero, zero),
+        Vector4::new(zero,    q, zero, zero),
+        Vector4::new(zero, zero,    s,    s),
+        Vector4::new(zero, zero,    c, zero)
+    )
+}
+
+def_clip_variants! {
+    infinite_perspective_impl,
+    "Creates a matrix for a symmetric perspective-view frustum with the far \
+     clipping plane at infinity.\n\nSee `infinite_perspective`.",
+    (fov_y, aspect, z_near),
+    infinite_perspective_rh_no, infinite_perspective_rh_zo,
+    infinite_perspective_lh_no, infinite_perspective_lh_zo,
+    infinite_perspective_rh, infinite_perspective_lh,
+    infinite_perspective_no, infinite_perspective_zo
+}
+
+/// Creates a matrix for a symmetric perspective-view frustum with the far
+/// clipping plane at infinity, for graphics hardware that does not support
+/// depth clamping.
+///
+/// `ep` is a small positive number that keeps the depth of points at
+/// infinity slightly less than `1`, so that they are not clipped due to
+/// rounding errors. *GLM* uses the machine epsilon by default.
+///
+/// The result is always right handed with depth range `[-1, 1]`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = tweaked_infinite_perspective(half_pi(), 1., 0.1, 1e-5);
+/// let p = m * vec4(0., 0., -1., 0.);
+/// assert!(p.z / p.w < 1.);
+/// ```
+#[inline]
+pub fn tweaked_infinite_perspective<T>(
+    fov_y: T,
+    aspect: T,
+    z_near: T,
+    ep: T
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let two = one + one;
+    let q = one / (fov_y / two).tan();
+
+    Matrix4::new(
+        Vector4::new(q / aspect, zero, zero, zero),
+        Vector4::new(zero,    q, zero, zero),
+        Vector4::new(zero, zero, ep - one, -one),
+        Vector4::new(zero, zero, (ep - two) * z_near, zero)
+    )
+}
+
+/// Creates a matrix for a perspective-view frustum, which may be
+/// asymmetric (off-center), based on the default conventions (right
+/// handed, depth range `[-1, 1]`).
+///
+/// See `perspective` for the conventions.
+///
+/// `left`, `right`, `bottom` and `top` are the coordinates of the clipping
+/// planes on the near plane. `z_near` and `z_far` are the distances from the
+/// viewer to the near and far clipping planes (always positive).
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// // symmetric frustum is the same as `perspective`.
+/// let m = frustum(-0.1, 0.1, -0.1, 0.1, 0.1, 100.);
+/// assert!(is_close_to(&m, &perspective(half_pi(), 1., 0.1, 100.), 1e-5));
+/// // the corners of the near plane are mapped to the corners of the NDC.
+/// let m = frustum(-1., 3., 2., 4., 1., 10.);
+/// let p = m * vec4(3., 2., -1., 1.);
+/// assert!(is_close_to(&(p / p.w), &vec4(1., -1., -1., 1.), 1e-6));
+/// ```
+#[inline]
+pub fn frustum<T>(
+    left: T,
+    right: T,
+    bottom: T,
+    top: T,
+    z_near: T,
+    z_far: T
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    frustum_impl(left, right, bottom, top, z_near, z_far, false, false)
+}
+
+// The six planes of the public function plus the two convention flags.
+#[allow(clippy::too_many_arguments)]
+fn frustum_impl<T>(
+    left: T,
+    right: T,
+    bottom: T,
+    top: T,
+    z_near: T,
+    z_far: T,
+    lh: bool,
+    zo: bool
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let two = one + one;
+    let (b, w, c) = perspective_depth(z_near, z_far, lh, zo);
+
+    Matrix4::new(
+        Vector4::new(two * z_near / (right - left), zero, zero, zero),
+        Vector4::new(zero, two * z_near / (top - bottom), zero, zero),
+        Vector4::new(
+            -w * (right + left) / (right - left),
+            -w * (top + bottom) / (top - bottom),
+            b,
+            w
+        ),
+        Vector4::new(zero, zero, c, zero)
+    )
+}
+
+def_clip_variants! {
+    frustum_impl,
+    "Creates a matrix for a perspective-view frustum, which may be \
+     asymmetric.\n\nSee `frustum`.",
+    (left, right, bottom, top, z_near, z_far),
+    frustum_rh_no, frustum_rh_zo, frustum_lh_no, frustum_lh_zo,
+    frustum_rh, frustum_lh, frustum_no, frustum_zo
+}
+
+/// Converts a depth value in NDC produced by `perspective` back to the
+/// distance from the viewer.
+///
+/// `d` is in `[-1, 1]`. `z_near` and `z_far` must be the values used to
+/// create the projection matrix.
+///
+/// # Note
+///
+/// Values read back from an OpenGL depth buffer are in `[0, 1]`, and must be
+/// mapped by `d * 2 - 1` first.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = perspective(half_pi(), 1., 0.1, 100.);
+/// let p = m * vec4(0., 0., -10., 1.);
+/// assert!(is_close_to(&linearize_depth(p.z / p.w, 0.1, 100.), &10., 1e-4));
+/// ```
+#[inline]
+pub fn linearize_depth<T>(
+    d: T,
+    z_near: T,
+    z_far: T
+) -> T
+where
+    T : BaseFloat
+{
+    let two = num::one::<T>() + num::one::<T>();
+    two * z_near * z_far / (z_far + z_near - d * (z_far - z_near))
+}
+
+/// Converts the distance from the viewer to a depth value in NDC, i.e., the
+/// inverse of `linearize_depth`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let d = delinearize_depth(10., 0.1, 100.);
+/// assert!(is_close_to(&linearize_depth(d, 0.1, 100.), &10., 1e-4));
+/// assert_eq!(delinearize_depth(0.1, 0.1, 100.), -1.);
+/// ```
+#[inline]
+pub fn delinearize_depth<T>(
+    z: T,
+    z_near: T,
+    z_far: T
+) -> T
+where
+    T : BaseFloat
+{
+    let two = num::one::<T>() + num::one::<T>();
+    (z_far + z_near - two * z_near * z_far / z) / (z_far - z_near)
+}
+
+/// Converts a depth value in `[0, 1]` back to the distance from the viewer.
+///
+/// The depth value is assumed to be produced by a projection matrix mapping
+/// `z_near` to `0` and `z_far` to `1`, as used by Vulkan, Direct3D and
+/// Metal.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// assert_eq!(linearize_depth_zo(0., 0.1, 100.), 0.1);
+/// assert!(is_close_to(&linearize_depth_zo(1., 0.1, 100.), &100., 1e-3));
+/// ```
+#[inline]
+pub fn linearize_depth_zo<T>(
+    d: T,
+    z_near: T,
+    z_far: T
+) -> T
+where
+    T : BaseFloat
+{
+    z_near * z_far / (z_far - d * (z_far - z_near))
+}
+
+/// Converts the distance from the viewer to a depth value in `[0, 1]`, i.e.,
+/// the inverse of `linearize_depth_zo`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let d = delinearize_depth_zo(10., 0.1, 100.);
+/// assert!(is_close_to(&linearize_depth_zo(d, 0.1, 100.), &10., 1e-4));
+/// ```
+#[inline]
+pub fn delinearize_depth_zo<T>(
+    z: T,
+    z_near: T,
+    z_far: T
+) -> T
+where
+    T : BaseFloat
+{
+    (z_far - z_near * z_far / z) / (z_far - z_near)
+}
+
+/// Converts a reversed-Z depth value in `[0, 1]` back to the distance from
+/// the viewer.
+///
+/// The depth value is assumed to be produced by a projection matrix mapping
+/// `z_near` to `1` and `z_far` to `0`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::ext::*;
+///
+/// assert_eq!(linearize_depth_reversed(1., 0.1, 100.), 0.1);
+/// assert_eq!(linearize_depth_reversed(0., 0.1, 100.), 100.);
+/// ```
+#[inline]
+pub fn linearize_depth_reversed<T>(
+    d: T,
+    z_near: T,
+    z_far: T
+) -> T
+where
+    T : BaseFloat
+{
+    z_near * z_far / (z_near + d * (z_far - z_near))
+}
+
+/// Converts the distance from the viewer to a reversed-Z depth value in
+/// `[0, 1]`, i.e., the inverse of `linearize_depth_reversed`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let d = delinearize_depth_reversed(10., 0.1, 100.);
+/// assert!(is_close_to(&linearize_depth_reversed(d, 0.1, 100.), &10., 1e-4));
+/// ```
+#[inline]
+pub fn delinearize_depth_reversed<T>(
+    z: T,
+    z_near: T,
+    z_far: T
+) -> T
+where
+    T : BaseFloat
+{
+    (z_near * z_far / z - z_near) / (z_far - z_near)
+}
+
+/// Creates a matrix for an orthographic parallel viewing volume, based on
+/// the default conventions (right handed, depth range `[-1, 1]`).
+///
+/// See `perspective` for the conventions.
+///
+/// `left`, `right`, `bottom` and `top` are the coordinates of the clipping
+/// planes. `z_near` and `z_far` are the distances from the viewer to the
+/// near and far clipping planes.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = ortho(0., 800., 0., 600., 0.1, 100.);
+/// assert_eq!(m * vec4(400., 600., -0.1, 1.), vec4(0., 1., -1., 1.));
+/// ```
+#[inline]
+pub fn ortho<T>(
+    left: T,
+    right: T,
+    bottom: T,
+    top: T,
+    z_near: T,
+    z_far: T
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    ortho_impl(left, right, bottom, top, z_near, z_far, false, false)
+}
+
+// The six planes of the public function plus the two convention flags.
+#[allow(clippy::too_many_arguments)]
+fn ortho_impl<T>(
+    left: T,
+    right: T,
+    bottom: T,
+    top: T,
+    z_near: T,
+    z_far: T,
+    lh: bool,
+    zo: bool
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let two = one + one;
+    let s = if lh { one } else { -one };
+    let d = z_far - z_near;
+    let (b, c) = if zo {
+        (s / d, -z_near / d)
+    } else {
+        (s * two / d, -(z_far + z_near) / d)
+    };
+
+    Matrix4::new(
+        Vector4::new(two / (right - left), zero, zero, zero),
+        Vector4::new(zero, two / (top - bottom), zero, zero),
+        Vector4::new(zero, zero, b, zero),
+        Vector4::new(
+            -(right + left) / (right - left),
+            -(top + bottom) / (top - bottom),
+            c,
+            one
+        )
+    )
+}
+
+def_clip_variants! {
+    ortho_impl,
+    "Creates a matrix for an orthographic parallel viewing volume.\n\nSee `ortho`.",
+    (left, right, bottom, top, z_near, z_far),
+    ortho_rh_no, ortho_rh_zo, ortho_lh_no, ortho_lh_zo,
+    ortho_rh, ortho_lh, ortho_no, ortho_zo
+}
+
+/// Creates a matrix for projecting two-dimensional coordinates onto the
+/// screen.
+///
+/// This is the same as `ortho_rh_no(left, right, bottom, top, -1, 1)`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = ortho2d(0., 800., 600., 0.);
+/// assert_eq!(m * vec4(0., 0., 0., 1.), vec4(-1., 1., 0., 1.));
+/// assert_eq!(m, ortho_rh_no(0., 800., 600., 0., -1., 1.));
+/// ```
+#[inline]
+pub fn ortho2d<T>(
+    left: T,
+    right: T,
+    bottom: T,
+    top: T
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let one = num::one::<T>();
+    ortho_rh_no(left, right, bottom, top, -one, one)
+}
+
+// Rotation matrix of `angle` radians around `v`.
+#[inline]
+fn axis_angle_mat3<T>(
+    angle: T,
+    v: Vector3<T>
+) -> Matrix3<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    let one = num::one::<T>();
+
+    let (s, c) = angle.sin_cos();
+    let axis = normalize(v);
+    let temp = axis * (one - c);
+
+    Matrix3::new(
+        Vector3::new(
+            c + temp.x * axis.x,
+            temp.x * axis.y + s * axis.z,
+            temp.x * axis.z - s * axis.y),
+        Vector3::new(
+            temp.y * axis.x - s * axis.z,
+            c + temp.y * axis.y,
+            temp.y * axis.z + s * axis.x),
+        Vector3::new(
+            temp.z * axis.x + s * axis.y,
+            temp.z * axis.y - s * axis.x,
+            c + temp.z * axis.z)
+        )
+}
+
+/// Builds a rotation 4 * 4 matrix created from an axis vector and an angle.
+///
+/// `m` as the input matrix multiplied by this rotation matrix.
+/// `angle` is the rotation angle expressed in radians.
+/// Rotation `axis` is recommended to be normalized.
+#[inline]
+pub fn rotate<T>(
+    m: &Matrix4<T>,
+    angle: T,
+    v: Vector3<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    let rotate = axis_angle_mat3(angle, v);
+
+    Matrix4::new(
+		m.c0 * rotate.c0.x + m.c1 * rotate.c0.y + m.c2 * rotate.c0.z,
+		m.c0 * rotate.c1.x + m.c1 * rotate.c1.y + m.c2 * rotate.c1.z,
+		m.c0 * rotate.c2.x + m.c1 * rotate.c2.y + m.c2 * rotate.c2.z,
+		m.c3
+        )
+}
+
+/// Builds a 4 * 4 matrix that translates by `v`.
+///
+/// This is the same as `translate(&Mat4::one(), v)`, but skips the matrix
+/// multiplication.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = from_translation(vec3(1., 2., 3.));
+/// assert_eq!(m * vec4(1., 1., 1., 1.), vec4(2., 3., 4., 1.));
+/// assert_eq!(m * vec4(1., 1., 1., 0.), vec4(1., 1., 1., 0.));
+/// ```
+#[inline]
+pub fn from_translation<T>(
+    v: Vector3<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    Matrix4::new(
+        Vector4::new( one, zero, zero, zero),
+        Vector4::new(zero,  one, zero, zero),
+        Vector4::new(zero, zero,  one, zero),
+        Vector4::new( v.x,  v.y,  v.z,  one)
+    )
+}
+
+/// Builds a 4 * 4 matrix that scales each axis by the corresponding
+/// component of `v`.
+///
+/// This is the same as `scale(&Mat4::one(), v)`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = from_scale(vec3(1., 2., 3.));
+/// assert_eq!(m * vec4(1., 1., 1., 1.), vec4(1., 2., 3., 1.));
+/// ```
+#[inline]
+pub fn from_scale<T>(
+    v: Vector3<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    Matrix4::new(
+        Vector4::new( v.x, zero, zero, zero),
+        Vector4::new(zero,  v.y, zero, zero),
+        Vector4::new(zero, zero,  v.z, zero),
+        Vector4::new(zero, zero, zero,  one)
+    )
+}
+
+/// Builds a 4 * 4 matrix that rotates `angle` radians around `axis`.
+///
+/// This is the same as `rotate(&Mat4::one(), angle, axis)`. `axis` need
+/// not be normalized.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = from_axis_angle(vec3(0., 0., 2.), half_pi());
+/// assert!(is_approx_eq(&(m * vec4(1., 0., 0., 1.)), &vec4(0., 1., 0., 1.)));
+/// ```
+#[inline]
+pub fn from_axis_angle<T>(
+    axis: Vector3<T>,
+    angle: T
+) -> Matrix4<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    let zero = num::zero::<T>();
+    let r = axis_angle_mat3(angle, axis);
+    Matrix4::new(
+        r.c0.extend(zero),
+        r.c1.extend(zero),
+        r.c2.extend(zero),
+        Vector4::new(zero, zero, zero, num::one::<T>())
+    )
+}
+
+/// Matrices whose rotation part can be re-orthonormalized, i.e., `Matrix3`
+/// and `Matrix4`.
+///
+/// See `renormalize_rotation` for details.
+pub trait RenormalizeRotation<T: BaseFloat>: Sized {
+    /// Returns a copy of `self` whose rotation part is re-orthonormalized.
+    fn renormalize_rotation(&self) -> Self;
+}
+
+impl<T: BaseFloat + GenFloat<T>> RenormalizeRotation<T> for Matrix3<T> {
+    #[inline]
+    fn renormalize_rotation(&self) -> Matrix3<T> {
+        // splits the error of orthogonality between the first two columns
+        // evenly, so neither axis is favored.
+        let half = num::one::<T>() / (num::one::<T>() + num::one::<T>());
+        let e = dot(self.c0, self.c1) * half;
+        let x = normalize(self.c0 - self.c1 * e);
+        let y = self.c1 - self.c0 * e;
+        let z = normalize(cross(x, y));
+        Matrix3::new(x, cross(z, x), z)
+    }
+}
+
+impl<T: BaseFloat + GenFloat<T>> RenormalizeRotation<T> for Matrix4<T> {
+    #[inline]
+    fn renormalize_rotation(&self) -> Matrix4<T> {
+        let r = Matrix3::new(
+            self.c0.xyz(),
+            self.c1.xyz(),
+            self.c2.xyz()
+        ).renormalize_rotation();
+        Matrix4::new(
+            r.c0.extend(self.c0.w),
+            r.c1.extend(self.c1.w),
+            r.c2.extend(self.c2.w),
+            self.c3
+        )
+    }
+}
+
+/// Re-orthonormalizes the rotation part of matrix `m`.
+///
+/// Products of many rotation matrices drift away from orthonormality due to
+/// rounding errors. This function pulls such a matrix back to the nearest
+/// rotation, which is useful for long-running simulations that integrate
+/// orientations every frame.
+///
+/// The error of orthogonality between the first two columns is split evenly
+/// between them, then the other columns are rebuilt with cross products. So
+/// the result is always a proper (right-handed) rotation.
+///
+/// For a `Matrix4`, only the upper-left 3 * 3 block is changed.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let drifted = mat3(1.01, 0.02, 0., -0.01, 0.99, 0., 0., 0., 1.03);
+/// let r = renormalize_rotation(&drifted);
+/// let id: Mat3 = num::one();
+/// assert!((r * r.transpose()).is_close_to(&id, 1e-6));
+/// assert!(is_close_to(&r.determinant(), &1., 1e-6));
+/// # }
+/// ```
+#[inline]
+pub fn renormalize_rotation<T, M>(
+    m: &M
+) -> M
+where
+    T : BaseFloat,
+    M : RenormalizeRotation<T>
+{
+    m.renormalize_rotation()
+}
+
+/// Returns the translation part of an affine transformation matrix `m`.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = scale(&translate(&num::one(), vec3(1., 2., 3.)), vec3(2., 2., 2.));
+/// assert_eq!(get_translation(&m), vec3(1., 2., 3.));
+/// # }
+/// ```
+#[inline(always)]
+pub fn get_translation<T>(
+    m: &Matrix4<T>
+) -> Vector3<T>
+where
+    T : BaseFloat
+{
+    m.c3.xyz()
+}
+
+/// Returns the scaling factors of a TRS (translation * rotation * scaling)
+/// matrix `m`.
+///
+/// The factors are the lengths of the first three columns. If the matrix
+/// flips handedness, the `x` factor is negated, so that the rotation returned
+/// by `get_rotation` is always proper.
+///
+/// # Note
+///
+/// Unlike a full decomposition, skew and perspective are not extracted, and
+/// the result is meaningless for matrices that contain them.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = scale(&rotate(&num::one(), 1., vec3(1., 1., 0.)), vec3(2., 3., 4.));
+/// assert!(get_scale(&m).is_close_to(&vec3(2., 3., 4.), 1e-6));
+/// # }
+/// ```
+#[inline]
+pub fn get_scale<T>(
+    m: &Matrix4<T>
+) -> Vector3<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    let (x, y, z) = (m.c0.xyz(), m.c1.xyz(), m.c2.xyz());
+    let sx = length(x);
+    let s = Vector3::new(sx, length(y), length(z));
+    if dot(cross(x, y), z) < num::zero() {
+        Vector3::new(-sx, s.y, s.z)
+    } else {
+        s
+    }
+}
+
+/// Returns the rotation part of a TRS (translation * rotation * scaling)
+/// matrix `m`, i.e., the upper-left 3 * 3 block with scaling removed.
+///
+/// See `get_scale` for the limitations.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let r = rotate(&num::one(), 1., vec3(1., 1., 0.));
+/// let m = scale(&translate(&r, vec3(1., 2., 3.)), vec3(2., 3., 4.));
+/// let q = get_rotation(&m);
+/// assert!(q.c0.is_close_to(&r.c0.xyz(), 1e-6));
+/// assert!(q.c2.is_close_to(&r.c2.xyz(), 1e-6));
+/// # }
+/// ```
+#[inline]
+pub fn get_rotation<T>(
+    m: &Matrix4<T>
+) -> Matrix3<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    let s = get_scale(m);
+    Matrix3::new(
+        m.c0.xyz() / s.x,
+        m.c1.xyz() / s.y,
+        m.c2.xyz() / s.z
+    )
+}
+
+/// Returns the rotation part of a TRS (translation * rotation * scaling)
+/// matrix `m` as a quaternion.
+///
+/// See `get_scale` for the limitations.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let q = Quat::from_axis_angle(normalize(vec3(1., 1., 0.)), 1.);
+/// let m = scale(&world_from_pos_rot(vec3(1., 2., 3.), q), vec3(2., 3., 4.));
+/// assert!(is_close_to(&get_rotation_quat(&m), &q, 1e-6));
+/// # }
+/// ```
+#[inline]
+pub fn get_rotation_quat<T>(
+    m: &Matrix4<T>
+) -> Quaternion<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    Quaternion::from_mat3(&get_rotation(m))
+}
+
+/// Builds a 4 * 4 matrix that rotates `angle` radians around `axis`.
+///
+/// This is the same as `from_axis_angle(axis, angle)`, and is provided with
+/// the name of *GLM*.
+#[inline(always)]
+pub fn axis_angle_matrix<T>(
+    axis: Vector3<T>,
+    angle: T
+) -> Matrix4<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    from_axis_angle(axis, angle)
+}
+
+/// Returns the rotation axis and angle of the rotation matrix `m`.
+///
+/// Only the upper-left 3 * 3 block of `m` is used, and it must be
+/// orthonormal (see `get_rotation` for matrices with scaling). The axis is
+/// normalized and the angle is in the range of `[0, π]`. The `z` axis is
+/// returned for the identity.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let axis = normalize(vec3(1., -2., 3.));
+/// let (v, a) = axis_angle(&axis_angle_matrix(axis, 2.));
+/// assert!(is_close_to(&v, &axis, 1e-5));
+/// assert!(is_close_to(&a, &2., 1e-5));
+/// // angles larger than `π` are returned with the opposite axis.
+/// let (v, a) = axis_angle(&axis_angle_matrix(axis, 4.));
+/// assert!(is_close_to(&v, &-axis, 1e-5));
+/// assert!(is_close_to(&a, &(tau::<f32, f32>() - 4.), 1e-5));
+/// ```
+#[inline]
+pub fn axis_angle<T>(
+    m: &Matrix4<T>
+) -> (Vector3<T>, T)
+where
+    T : BaseFloat
+{
+    let mut q = Quaternion::from_mat3(&Matrix3::new(m.c0.xyz(), m.c1.xyz(), m.c2.xyz()));
+    if q.w < num::zero() {
+        q = -q;
+    }
+    (q.axis(), q.angle())
+}
+
+/// Returns the rotation part of `m`, i.e., the upper-left 3 * 3 block
+/// extended to a 4 * 4 matrix.
+///
+/// Only the translation and perspective are removed. Unlike `get_rotation`,
+/// scaling is kept.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let r = rotate(&num::one(), 1., vec3(1., 1., 0.));
+/// let m = translate(&num::one(), vec3(1., 2., 3.)) * r;
+/// assert_eq!(extract_matrix_rotation(&m), r);
+/// # }
+/// ```
+#[inline]
+pub fn extract_matrix_rotation<T>(
+    m: &Matrix4<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    Matrix4::new(
+        m.c0.xyz().extend(zero),
+        m.c1.xyz().extend(zero),
+        m.c2.xyz().extend(zero),
+        Vector4::new(zero, zero, zero, num::one())
+    )
+}
+
+/// Returns the inverse of the affine transformation `m`, i.e., a matrix whose
+/// last row is `(0, 0, 0, 1)`.
+///
+/// Only the upper-left 3 * 3 block is inverted, and the translation is
+/// transformed back by it, which is much cheaper than the general `inverse`.
+/// The result is meaningless if `m` contains perspective.
+///
+/// # Panic
+///
+/// It is a panic if the upper-left 3 * 3 block of `m` is singular.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = scale(&rotate(&translate(&num::one(), vec3(1., 2., 3.)), 1., vec3(1., 1., 0.)), vec3(2., 3., 4.));
+/// assert!(is_close_to(&affine_inverse(&m), &inverse(&m), 1e-6));
+/// # }
+/// ```
+#[inline]
+pub fn affine_inverse<T>(
+    m: &Matrix4<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let r = inverse(&Matrix3::new(m.c0.xyz(), m.c1.xyz(), m.c2.xyz()));
+    let t = -(r * m.c3.xyz());
+    Matrix4::new(
+        r.c0.extend(zero),
+        r.c1.extend(zero),
+        r.c2.extend(zero),
+        t.extend(num::one())
+    )
+}
+
+/// Builds a rotation 4 * 4 matrix that rotates around the point `pivot`.
+///
+/// `m` as the input matrix multiplied by this rotation matrix.
+/// `angle` is the rotation angle expressed in radians.
+/// Rotation `axis` is recommended to be normalized.
+///
+/// The result is the same as
+/// `translate(&rotate(&translate(m, pivot), angle, axis), -pivot)`, i.e.,
+/// a point is translated by `-pivot`, rotated, and then translated back.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let pivot = vec3(1., 2., 3.);
+/// let m = rotate_around(&num::one(), half_pi(), vec3(0., 0., 1.), pivot);
+/// assert!(is_approx_eq(&(m * vec4(1., 2., 3., 1.)), &vec4(1., 2., 3., 1.)));
+/// assert!(is_approx_eq(&(m * vec4(2., 2., 3., 1.)), &vec4(1., 3., 3., 1.)));
+/// # }
+/// ```
+#[inline]
+pub fn rotate_around<T>(
+    m: &Matrix4<T>,
+    angle: T,
+    axis: Vector3<T>,
+    pivot: Vector3<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    let r = rotate(&translate(m, pivot), angle, axis);
+    translate(&r, -pivot)
+}
+
+/// Builds a scale 4 * 4 matrix created from 3 scalars.
+///
+/// `m` is the input matrix multiplied by this scale matrix.
+/// `v` is the ratio of scaling for each axis.
+#[inline]
+pub fn scale<T>(
+    m: &Matrix4<T>,
+    v: Vector3<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    Matrix4::new(
+        m.c0 * v.x,
+        m.c1 * v.y,
+        m.c2 * v.z,
+        m.c3)
+}
+
+/// Builds a 4 * 4 matrix that scales all coordinates by `scale` and then
+/// adds `bias` to them.
+///
+/// `scale_bias(0.5, 0.5)` maps the normalized device coordinates in range
+/// [-1, 1] to texture coordinates in range [0, 1], which is often used to
+/// build the texture matrix of shadow maps.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = scale_bias(0.5, 0.5);
+/// assert_eq!(m * vec4(-1., 1., 0., 1.), vec4(0., 1., 0.5, 1.));
+/// ```
+#[inline]
+pub fn scale_bias<T>(
+    scale: T,
+    bias: T
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    Matrix4::new(
+        Vector4::new(scale, zero, zero, zero),
+        Vector4::new(zero, scale, zero, zero),
+        Vector4::new(zero, zero, scale, zero),
+        Vector4::new(bias, bias, bias, one)
+    )
+}
+
+/// Builds a 3 * 3 matrix that orthogonally projects 2D homogeneous
+/// coordinates onto the line that passes the origin and is perpendicular to
+/// `normal`.
+///
+/// `m` is the input matrix multiplied by this projection matrix.
+/// `normal` must be normalized.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = proj2d(&num::one(), vec2(0., 1.));
+/// assert_eq!(m * vec3(3., 4., 1.), vec3(3., 0., 1.));
+/// # }
+/// ```
+#[inline]
+pub fn proj2d<T>(
+    m: &Matrix3<T>,
+    normal: Vector2<T>
+) -> Matrix3<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let n = normal;
+    let r = Matrix3::new(
+        Vector3::new(one - n.x * n.x, -n.x * n.y, zero),
+        Vector3::new(-n.x * n.y, one - n.y * n.y, zero),
+        Vector3::new(zero, zero, one)
+    );
+    m.mul_m(&r)
+}
+
+/// Builds a 4 * 4 matrix that orthogonally projects 3D homogeneous
+/// coordinates onto the plane that passes the origin and is perpendicular to
+/// `normal`.
+///
+/// `m` is the input matrix multiplied by this projection matrix.
+/// `normal` must be normalized.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = proj3d(&num::one(), vec3(0., 1., 0.));
+/// assert_eq!(m * vec4(3., 4., 5., 1.), vec4(3., 0., 5., 1.));
+/// # }
+/// ```
+#[inline]
+pub fn proj3d<T>(
+    m: &Matrix4<T>,
+    normal: Vector3<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let n = normal;
+    let r = Matrix4::new(
+        Vector4::new(one - n.x * n.x, -n.x * n.y, -n.x * n.z, zero),
+        Vector4::new(-n.x * n.y, one - n.y * n.y, -n.y * n.z, zero),
+        Vector4::new(-n.x * n.z, -n.y * n.z, one - n.z * n.z, zero),
+        Vector4::new(zero, zero, zero, one)
+    );
+    m.mul_m(&r)
+}
+
+/// Builds a 4 * 4 matrix that projects geometry onto a plane along the rays
+/// from a light source, i.e., flattens the geometry to its planar shadow.
+///
+/// `plane` is `(a, b, c, d)` of the plane equation `ax + by + cz + d = 0`.
+/// `light` is the homogeneous position of the light, `w` is `0` for a
+/// directional light and `1` for a point light.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// // the ground plane `y = 0` and a point light above the origin.
+/// let m = planar_shadow(vec4(0., 1., 0., 0.), vec4(0., 10., 0., 1.));
+/// let p = m * vec4(1., 5., 1., 1.);
+/// assert_eq!(vec3(p.x, p.y, p.z) / p.w, vec3(2., 0., 2.));
+/// ```
+#[inline]
+pub fn planar_shadow<T>(
+    plane: Vector4<T>,
+    light: Vector4<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat
+{
+    let d = (plane * light).sum();
+    let mut r = Matrix4::new(
+        light * -plane.x,
+        light * -plane.y,
+        light * -plane.z,
+        light * -plane.w
+    );
+    for i in 0..4 {
+        r[i][i] = r[i][i] + d;
+    }
+    r
+}
+
+/// Build a look at view matrix based on the default handedness, i.e., right
+/// handed. See `look_at_lh` for the left handed one.
+///
+/// View matrix is based on the `eye` position of the camera, `center` position where the camera is
+/// looking at and a normalized `up` vector, how the camera is oriented. Typically (0, 0, 1)
+#[inline]
+pub fn look_at<T>(
+    eye: Vector3<T>,
+    center: Vector3<T>,
+    up: Vector3<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    look_at_rh::<T>(eye, center, up)
+}
+
+/// Build a right handed look at view matrix.
+///
+/// View matrix is based on the `eye` position of the camera, `center` position where the camera is
+/// looking at and a normalized `up` vector, how the camera is oriented. Typically (0, 0, 1)
+#[inline]
+pub fn look_at_rh<T>(
+    eye: Vector3<T>,
+    center: Vector3<T>,
+    up: Vector3<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let f = normalize(center - eye);
+    let s = normalize(cross(f, up));
+    let u = cross(s, f);
+    Matrix4::new(
+        Vector4::new(s.x, u.x,-f.x, zero),
+        Vector4::new(s.y, u.y,-f.y, zero),
+        Vector4::new(s.z, u.z,-f.z, zero),
+        Vector4::new(-dot(s, eye), -dot(u, eye), dot(f, eye), one)
+    )
+}
+
+/// Build a left handed look at view matrix.
+///
+/// View matrix is based on the `eye` position of the camera, `center` position where the camera is
+/// looking at and a normalized `up` vector, how the camera is oriented. Typically (0, 0, 1)
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let v = look_at_lh(vec3(0., 0., 5.), vec3(0., 0., 0.), vec3(0., 1., 0.));
+/// // the camera looks at `+z` of the view space.
+/// assert!(is_approx_eq(&(v * vec4(0., 0., 0., 1.)), &vec4(0., 0., 5., 1.)));
+/// ```
+#[inline]
+pub fn look_at_lh<T>(
+    eye: Vector3<T>,
+    center: Vector3<T>,
+    up: Vector3<T>
+) -> Matrix4<T>
+where
+    T : BaseFloat + GenFloat<T>
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let f = normalize(center - eye);
+    let s = normalize(cross(up, f));
+    let u = cross(f, s);
+    Matrix4::new(
+        Vector4::new(s.x, u.x, f.x, zero),
+        Vector4::new(s.y, u.y, f.y, zero),
+        Vector4::new(s.z, u.z, f.z, zero),
+        Vector4::new(-dot(s, eye), -dot(u, eye), -dot(f, eye), one)
+    )
+}
+
+/// Builds the world matrix of a camera located at `position` and oriented
+/// by the rotation `orientation`, i.e., the inverse of
+/// [`view_from_pos_rot`](fn.view_from_pos_rot.html).
+///
+/// `orientation` is any rotation that converts to a `Matrix3`, e.g., a
+/// `Quaternion`.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let r: Mat3 = num::one();
+/// let m = world_from_pos_rot(vec3(1., 2., 3.), r);
+/// assert_eq!(m * vec4(0., 0., 0., 1.), vec4(1., 2., 3., 1.));
+/// # }
+/// ```
+#[inline]
+pub fn world_from_pos_rot<T, R>(
+    position: Vector3<T>,
+    orientation: R
+) -> Matrix4<T>
+where
+    T : BaseFloat,
+    R : Into<Matrix3<T>>
+{
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let r = orientation.into();
+    Matrix4::new(
+        r.c0.extend(zero),
+        r.c1.extend(zero),
+        r.c2.extend(zero),
+        position.extend(one)
+    )
+}
+
+/// Builds the view matrix of a camera located at `position` and oriented by
+/// the rotation `orientation`.
+///
+/// `orientation` is any rotation that converts to a `Matrix3`. Unlike
+/// `look_at`, no target point is needed, which is convenient for cameras
+/// that store their orientations directly (e.g., FPS cameras).
 ///
-/// `fov_y` is the field of view angle in the y direction in radians.
-/// The `aspect` ratio determines the field of view in the x direction.
-/// `near_z` is the distance from the viewer to the near clipping plane (always positive) and
-/// `far_z` is the distance from the viewer to the far clipping plane (always positive).
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// // a camera at (0, 0, 5) that turns left by 90 degrees.
+/// let r = mat3(0., 0., -1., 0., 1., 0., 1., 0., 0.);
+/// let v = view_from_pos_rot(vec3(0., 0., 5.), r);
+/// let p = v * vec4(-1., 0., 5., 1.);
+/// assert!(is_approx_eq(&p, &vec4(0., 0., -1., 1.)));
+/// ```
 #[inline]
-pub fn perspective<T>(
-    fov_y: T,
-    aspect: T,
-    z_near: T,
-    z_far: T
+pub fn view_from_pos_rot<T, R>(
+    position: Vector3<T>,
+    orientation: R
 ) -> Matrix4<T>
 where
-    T : BaseFloat
+    T : BaseFloat,
+    R : Into<Matrix3<T>>
 {
-    // TODO: make this a compile option
-    perspective_rh(fov_y, aspect, z_near, z_far)
+    let zero = num::zero::<T>();
+    let one = num::one::<T>();
+    let r = orientation.into();
+    let t = -position;
+    Matrix4::new(
+        Vector4::new(r.c0.x, r.c1.x, r.c2.x, zero),
+        Vector4::new(r.c0.y, r.c1.y, r.c2.y, zero),
+        Vector4::new(r.c0.z, r.c1.z, r.c2.z, zero),
+        Vector4::new(
+            (r.c0 * t).sum(),
+            (r.c1 * t).sum(),
+            (r.c2 * t).sum(),
+            one
+        )
+    )
 }
 
-/// Creates a matrix for a right handed, symetric perspective-view frustum.
+/// Builds the model matrix of a spherical billboard.
 ///
-/// `fov_y` is the field of view angle in the y direction in radians.
-/// The `aspect` ratio determines the field of view in the x direction.
-/// `near_z` is the distance from the viewer to the near clipping plane (always positive) and
-/// `far_z` is the distance from the viewer to the far clipping plane (always positive).
+/// The billboard is placed at `center`, and its local `+z` axis points to
+/// the camera at `camera_pos`. `camera_up` is the up direction of the camera,
+/// which becomes the local `+y` axis after being orthogonalized.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = billboard(vec3(1., 0., 0.), vec3(1., 0., 5.), vec3(0., 1., 0.));
+/// assert_eq!(m * vec4(0., 0., 1., 0.), vec4(0., 0., 1., 0.));
+/// assert_eq!(m * vec4(0., 0., 0., 1.), vec4(1., 0., 0., 1.));
+/// ```
 #[inline]
-pub fn perspective_rh<T>(
-    fov_y: T,
-    aspect: T,
-    z_near: T,
-    z_far: T
+pub fn billboard<T>(
+    center: Vector3<T>,
+    camera_pos: Vector3<T>,
+    camera_up: Vector3<T>
 ) -> Matrix4<T>
 where
-    T : BaseFloat
+    T : BaseFloat + GenFloat<T>
 {
     let zero = num::zero::<T>();
     let one = num::one::<T>();
-    let two = one + one;
-    let q = one / (fov_y / two).tan();
-    let a = q / aspect;
-    let b = (z_near + z_far) / (z_near - z_far);
-    let c = (two * z_near * z_far) / (z_near - z_far);
-
+    let look = normalize(camera_pos - center);
+    let right = normalize(cross(camera_up, look));
+    let up = cross(look, right);
     Matrix4::new(
-        Vector4::new(   a, zero, zero, zero),
-        Vector4::new(zero,    q, zero, zero),
-        Vector4::new(zero, zero,    b, zero - one),
-        Vector4::new(zero, zero,    c, zero)
+        right.extend(zero),
+        up.extend(zero),
+        look.extend(zero),
+        center.extend(one)
     )
 }
 
-/// Builds a rotation 4 * 4 matrix created from an axis vector and an angle.
+/// Builds the model matrix of a cylindrical billboard, which can only rotate
+/// around the fixed axis `up`.
 ///
-/// `m` as the input matrix multiplied by this rotation matrix.
-/// `angle` is the rotation angle expressed in radians.
-/// Rotation `axis` is recommended to be normalized.
+/// The billboard is placed at `center`, its local `+y` axis is `up`, and its
+/// local `+z` axis points to the camera at `camera_pos` as close as possible.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let m = billboard_cylindrical(vec3(0., 0., 0.), vec3(0., 5., 5.), vec3(0., 1., 0.));
+/// assert!(is_close_to(&(m * vec4(0., 1., 0., 0.)), &vec4(0., 1., 0., 0.), 1e-6));
+/// assert!(is_close_to(&(m * vec4(0., 0., 1., 0.)), &vec4(0., 0., 1., 0.), 1e-6));
+/// ```
 #[inline]
-pub fn rotate<T>(
-    m: &Matrix4<T>,
-    angle: T,
-    v: Vector3<T>
+pub fn billboard_cylindrical<T>(
+    center: Vector3<T>,
+    camera_pos: Vector3<T>,
+    up: Vector3<T>
 ) -> Matrix4<T>
 where
     T : BaseFloat + GenFloat<T>
 {
     let zero = num::zero::<T>();
     let one = num::one::<T>();
+    let up = normalize(up);
+    let to_camera = camera_pos - center;
+    let look = normalize(to_camera - up * dot(to_camera, up));
+    let right = cross(up, look);
+    Matrix4::new(
+        right.extend(zero),
+        up.extend(zero),
+        look.extend(zero),
+        center.extend(one)
+    )
+}
 
-    let a = angle;
-    let (s, c) = a.sin_cos();
-    let axis = normalize(v);
-    let temp = axis * (one - c);
+/// Returns the corners of the view frustum in world space.
+///
+/// `inv_view_proj` is the inverse of the product of the projection matrix
+/// and the view matrix, i.e., `inverse(&(proj * view))`. The depth range of
+/// the clip space is assumed to be `[-1, 1]` (OpenGL).
+///
+/// The first four corners are on the near plane and the last four are on the
+/// far plane. On each plane, the corners are in the order of bottom-left,
+/// bottom-right, top-right and top-left, so corner `i + 4` is on the same
+/// edge of the frustum with corner `i`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let proj = ortho(-1., 1., -1., 1., 1., 10.);
+/// let corners = frustum_corners(&inverse(&proj));
+/// assert!(is_approx_eq(&corners[0], &vec3(-1., -1., -1.)));
+/// assert!(is_approx_eq(&corners[6], &vec3(1., 1., -10.)));
+/// ```
+pub fn frustum_corners<T>(
+    inv_view_proj: &Matrix4<T>
+) -> [Vector3<T>; 8]
+where
+    T : BaseFloat
+{
+    frustum_corners_impl(inv_view_proj, -num::one::<T>())
+}
 
-    let rotate = Matrix3::new(
-        Vector3::new(
-            c + temp.x * axis.x,
-            temp.x * axis.y + s * axis.z,
-            temp.x * axis.z - s * axis.y),
-        Vector3::new(
-            temp.y * axis.x - s * axis.z,
-            c + temp.y * axis.y,
-            temp.y * axis.z + s * axis.x),
-        Vector3::new(
-            temp.z * axis.x + s * axis.y,
-            temp.z * axis.y - s * axis.x,
-            c + temp.z * axis.z)
-        );
+/// Returns the corners of the view frustum in world space, assuming the
+/// depth range of the clip space is `[0, 1]` (Vulkan, Direct3D and Metal).
+///
+/// See `frustum_corners` for the order of the corners.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let proj = ortho_rh_zo(-1., 1., -1., 1., 1., 10.);
+/// let corners = frustum_corners_zo(&inverse(&proj));
+/// assert!(is_approx_eq(&corners[0], &vec3(-1., -1., -1.)));
+/// assert!(is_approx_eq(&corners[6], &vec3(1., 1., -10.)));
+/// ```
+pub fn frustum_corners_zo<T>(
+    inv_view_proj: &Matrix4<T>
+) -> [Vector3<T>; 8]
+where
+    T : BaseFloat
+{
+    frustum_corners_impl(inv_view_proj, num::zero::<T>())
+}
 
-    Matrix4::new(
-		m.c0 * rotate.c0.x + m.c1 * rotate.c0.y + m.c2 * rotate.c0.z,
-		m.c0 * rotate.c1.x + m.c1 * rotate.c1.y + m.c2 * rotate.c1.z,
-		m.c0 * rotate.c2.x + m.c1 * rotate.c2.y + m.c2 * rotate.c2.z,
-		m.c3
-        )
+// `n` is the depth of the near plane in the clip space.
+fn frustum_corners_impl<T>(
+    inv_view_proj: &Matrix4<T>,
+    n: T
+) -> [Vector3<T>; 8]
+where
+    T : BaseFloat
+{
+    let one = num::one::<T>();
+    let ndc = [
+        Vector4::new(-one, -one,    n, one),
+        Vector4::new( one, -one,    n, one),
+        Vector4::new( one,  one,    n, one),
+        Vector4::new(-one,  one,    n, one),
+        Vector4::new(-one, -one,  one, one),
+        Vector4::new( one, -one,  one, one),
+        Vector4::new( one,  one,  one, one),
+        Vector4::new(-one,  one,  one, one),
+    ];
+    let mut corners = [Vector3::new(one, one, one); 8];
+    for i in 0..8 {
+        let p = inv_view_proj.mul_v(&ndc[i]);
+        corners[i] = p.xyz() / p.w;
+    }
+    corners
 }
 
-/// Builds a scale 4 * 4 matrix created from 3 scalars.
+/// Returns the corners of a slice of the view frustum, e.g., a cascade of
+/// cascaded shadow maps.
 ///
-/// `m` is the input matrix multiplied by this scale matrix.
-/// `v` is the ratio of scaling for each axis.
+/// `corners` are the corners returned by
+/// [`frustum_corners`](fn.frustum_corners.html). `t_near` and `t_far` are the
+/// relative positions of the near and far planes of the slice, where `0` is
+/// the near plane and `1` is the far plane of the frustum. For a perspective
+/// projection, the relative position of view depth `d` is
+/// `(d - z_near) / (z_far - z_near)`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let proj = ortho(-1., 1., -1., 1., 1., 11.);
+/// let corners = frustum_corners(&inverse(&proj));
+/// let slice = frustum_slice_corners(&corners, 0.5, 1.);
+/// assert!(is_close_to(&slice[0], &vec3(-1., -1., -6.), 1e-5));
+/// assert!(is_close_to(&slice[4], &corners[4], 1e-5));
+/// ```
+pub fn frustum_slice_corners<T>(
+    corners: &[Vector3<T>; 8],
+    t_near: T,
+    t_far: T
+) -> [Vector3<T>; 8]
+where
+    T : BaseFloat
+{
+    let mut slice = *corners;
+    for i in 0..4 {
+        let edge = corners[i + 4] - corners[i];
+        slice[i] = corners[i] + edge * t_near;
+        slice[i + 4] = corners[i] + edge * t_far;
+    }
+    slice
+}
+
 #[inline]
-pub fn scale<T>(
-    m: &Matrix4<T>,
-    v: Vector3<T>
-) -> Matrix4<T>
+fn project_impl<T>(
+    obj: Vector3<T>,
+    model: &Matrix4<T>,
+    proj: &Matrix4<T>,
+    viewport: Vector4<T>,
+    zo: bool
+) -> Vector3<T>
 where
-    T : BaseFloat + GenFloat<T>
+    T : BaseFloat
 {
-    Matrix4::new(
-        m.c0 * v.x,
-        m.c1 * v.y,
-        m.c2 * v.z,
-        m.c3)
+    let clip = *proj * (*model * obj.extend(num::one()));
+    let conv = NdcConvention { flip_y: false, zero_to_one: zo };
+    ndc_to_window(clip.xyz() / clip.w, viewport, conv)
+}
+
+#[inline]
+fn unproject_impl<T>(
+    win: Vector3<T>,
+    model: &Matrix4<T>,
+    proj: &Matrix4<T>,
+    viewport: Vector4<T>,
+    zo: bool
+) -> Vector3<T>
+where
+    T : BaseFloat
+{
+    let conv = NdcConvention { flip_y: false, zero_to_one: zo };
+    let ndc = window_to_ndc(win, viewport, conv);
+    let obj = inverse(&(*proj * *model)) * ndc.extend(num::one());
+    obj.xyz() / obj.w
 }
 
-/// Build a look at view matrix based on the default handedness.
+/// Maps object coordinates `obj` to window coordinates, as `gluProject`
+/// does.
 ///
-/// View matrix is based on the `eye` position of the camera, `center` position where the camera is
-/// looking at and a normalized `up` vector, how the camera is oriented. Typically (0, 0, 1)
+/// `model` is the model-view matrix, `proj` is the projection matrix, and
+/// `viewport` is `(x, y, width, height)` of the viewport. The `z` component
+/// of the result is the depth in `[0, 1]`, and the `y` axis of the window
+/// points up (OpenGL).
+///
+/// The depth range of the clip space is assumed to be `[-1, 1]`, like
+/// `project_no`. See `project_zo` for `[0, 1]`.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let (model, proj): (Mat4, Mat4) = (num::one(), perspective(half_pi(), 1., 1., 10.));
+/// let vp = vec4(0., 0., 800., 600.);
+/// let win = project(vec3(0., 0., -1.), &model, &proj, vp);
+/// assert!(is_close_to(&win, &vec3(400., 300., 0.), 1e-4));
+/// # }
+/// ```
 #[inline]
-pub fn look_at<T>(
-    eye: Vector3<T>,
-    center: Vector3<T>,
-    up: Vector3<T>
-) -> Matrix4<T>
+pub fn project<T>(
+    obj: Vector3<T>,
+    model: &Matrix4<T>,
+    proj: &Matrix4<T>,
+    viewport: Vector4<T>
+) -> Vector3<T>
 where
-    T : BaseFloat + GenFloat<T>
+    T : BaseFloat
 {
-    // TODO: make handedness configurable
-    look_at_rh::<T>(eye, center, up)
+    project_impl(obj, model, proj, viewport, false)
 }
 
-/// Build a right handed look at view matrix.
+/// Maps object coordinates to window coordinates, assuming the depth range
+/// of the clip space is `[-1, 1]` (OpenGL).
 ///
-/// View matrix is based on the `eye` position of the camera, `center` position where the camera is
-/// looking at and a normalized `up` vector, how the camera is oriented. Typically (0, 0, 1)
+/// See `project`.
 #[inline]
-pub fn look_at_rh<T>(
-    eye: Vector3<T>,
-    center: Vector3<T>,
-    up: Vector3<T>
-) -> Matrix4<T>
+pub fn project_no<T>(
+    obj: Vector3<T>,
+    model: &Matrix4<T>,
+    proj: &Matrix4<T>,
+    viewport: Vector4<T>
+) -> Vector3<T>
 where
-    T : BaseFloat + GenFloat<T>
+    T : BaseFloat
 {
-    let zero = num::zero::<T>();
-    let one = num::one::<T>();
-    let f = normalize(center - eye);
-    let s = normalize(cross(f, up));
-    let u = cross(s, f);
-    Matrix4::new(
-        Vector4::new(s.x, u.x,-f.x, zero),
-        Vector4::new(s.y, u.y,-f.y, zero),
-        Vector4::new(s.z, u.z,-f.z, zero),
-        Vector4::new(-dot(s, eye), -dot(u, eye), dot(f, eye), one)
-    )
+    project_impl(obj, model, proj, viewport, false)
+}
+
+/// Maps object coordinates to window coordinates, assuming the depth range
+/// of the clip space is `[0, 1]` (Vulkan, Direct3D, Metal).
+///
+/// See `project`.
+#[inline]
+pub fn project_zo<T>(
+    obj: Vector3<T>,
+    model: &Matrix4<T>,
+    proj: &Matrix4<T>,
+    viewport: Vector4<T>
+) -> Vector3<T>
+where
+    T : BaseFloat
+{
+    project_impl(obj, model, proj, viewport, true)
+}
+
+/// Maps window coordinates `win` to object coordinates, i.e., the inverse
+/// of `project`, as `gluUnProject` does.
+///
+/// This is useful for picking, e.g., `win.z` of `0` and `1` give the points
+/// on the near and far planes under the mouse cursor.
+///
+/// # Panic
+///
+/// It is a panic if `proj * model` is not invertible.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate glm;
+/// # fn main() {
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let model = look_at(vec3(1., 2., 3.), vec3(0., 0., 0.), vec3(0., 1., 0.));
+/// let proj = perspective(1., 4. / 3., 0.1, 100.);
+/// let vp = vec4(0., 0., 800., 600.);
+/// let obj = vec3(0.5, -0.3, 0.2);
+/// let win = project(obj, &model, &proj, vp);
+/// assert!(is_close_to(&unproject(win, &model, &proj, vp), &obj, 1e-3));
+/// # }
+/// ```
+#[inline]
+pub fn unproject<T>(
+    win: Vector3<T>,
+    model: &Matrix4<T>,
+    proj: &Matrix4<T>,
+    viewport: Vector4<T>
+) -> Vector3<T>
+where
+    T : BaseFloat
+{
+    unproject_impl(win, model, proj, viewport, false)
+}
+
+/// Maps window coordinates to object coordinates, assuming the depth range
+/// of the clip space is `[-1, 1]` (OpenGL).
+///
+/// See `unproject`.
+#[inline]
+pub fn unproject_no<T>(
+    win: Vector3<T>,
+    model: &Matrix4<T>,
+    proj: &Matrix4<T>,
+    viewport: Vector4<T>
+) -> Vector3<T>
+where
+    T : BaseFloat
+{
+    unproject_impl(win, model, proj, viewport, false)
+}
+
+/// Maps window coordinates to object coordinates, assuming the depth range
+/// of the clip space is `[0, 1]` (Vulkan, Direct3D, Metal).
+///
+/// See `unproject`.
+#[inline]
+pub fn unproject_zo<T>(
+    win: Vector3<T>,
+    model: &Matrix4<T>,
+    proj: &Matrix4<T>,
+    viewport: Vector4<T>
+) -> Vector3<T>
+where
+    T : BaseFloat
+{
+    unproject_impl(win, model, proj, viewport, true)
 }
 
 #[cfg(test)]
 mod test {
     use num;
     use std::f32;
+    use basenum::*;
     use vec::vec::{ vec3, vec4 };
-    use ext::{ perspective, translate };
+    use mat::mat::{ Mat4, Matrix3 };
+    use mat::traits::{ GenMat, GenSquareMat };
+    use quat::Quat;
+    use builtin::normalize;
+    use ext::{
+        perspective_rh_no, perspective_rh_zo, perspective_lh_no, perspective_lh_zo,
+        frustum_rh_no, frustum_rh_zo, frustum_lh_no, frustum_lh_zo,
+        ortho_rh_no, ortho_rh_zo, ortho_lh_no, ortho_lh_zo,
+        infinite_perspective_rh_zo, infinite_perspective_lh_zo, look_at_lh, look_at_rh,
+        project_zo, unproject_zo,
+        perspective, ortho, look_at, frustum_corners, frustum_corners_zo,
+        translate, rotate, scale, renormalize_rotation, view_from_pos_rot,
+        world_from_pos_rot, get_translation, get_scale, get_rotation
+    };
 
     #[test]
     fn test_translate() {
@@ -216,4 +1956,95 @@ mod test {
     fn test_perspective() {
         let p = perspective(f32::consts::PI * 2.0 * 45.0 / 360.0, 1920.0 / 1080.0, 0.1, 100.0);
     }
+
+    #[test]
+    fn test_clip_conventions() {
+        let (n, f) = (0.5, 20.);
+        let depth = |m: Mat4, z: f32| { let p = m * vec4(0.3, -0.2, z, 1.); p.z / p.w };
+        let ms: [(Mat4, bool, bool); 12] = [
+            (perspective_rh_no(1., 1.5, n, f), false, false),
+            (perspective_rh_zo(1., 1.5, n, f), false, true),
+            (perspective_lh_no(1., 1.5, n, f), true, false),
+            (perspective_lh_zo(1., 1.5, n, f), true, true),
+            (frustum_rh_no(-1., 2., -1., 0.5, n, f), false, false),
+            (frustum_rh_zo(-1., 2., -1., 0.5, n, f), false, true),
+            (frustum_lh_no(-1., 2., -1., 0.5, n, f), true, false),
+            (frustum_lh_zo(-1., 2., -1., 0.5, n, f), true, true),
+            (ortho_rh_no(-1., 2., -1., 0.5, n, f), false, false),
+            (ortho_rh_zo(-1., 2., -1., 0.5, n, f), false, true),
+            (ortho_lh_no(-1., 2., -1., 0.5, n, f), true, false),
+            (ortho_lh_zo(-1., 2., -1., 0.5, n, f), true, true),
+        ];
+        for &(m, lh, zo) in ms.iter() {
+            let s = if lh { 1. } else { -1. };
+            let lo = if zo { 0. } else { -1. };
+            assert!(is_close_to(&depth(m, s * n), &lo, 1e-5));
+            assert!(is_close_to(&depth(m, s * f), &1., 1e-5));
+        }
+        // left handed is right handed with `z` mirrored.
+        let flip = scale(&num::one(), vec3(1., 1., -1.));
+        assert_close_to!(frustum_lh_zo(-1., 2., -1., 0.5, n, f), frustum_rh_zo(-1., 2., -1., 0.5, n, f) * flip, 1e-6);
+        assert_close_to!(infinite_perspective_lh_zo(1., 1.5, n), infinite_perspective_rh_zo(1., 1.5, n) * flip, 1e-6);
+        assert!(is_close_to(&depth(infinite_perspective_rh_zo(1., 1.5, n), -n), &0., 1e-6));
+        let eye = vec3(1., 2., 3.);
+        let (c, up) = (vec3(-2., 0., 1.), vec3(0., 1., 0.));
+        // the view space is turned around the `y` axis.
+        let turn = scale(&num::one(), vec3(-1., 1., -1.));
+        assert_close_to!(look_at_lh(eye, c, up), turn * look_at_rh(eye, c, up), 1e-6);
+        assert_eq!(perspective(1., 1.5, n, f), perspective_rh_no(1., 1.5, n, f));
+        assert_eq!(ortho(-1., 2., -1., 0.5, n, f), ortho_rh_no(-1., 2., -1., 0.5, n, f));
+        assert_eq!(look_at(eye, c, up), look_at_rh(eye, c, up));
+        // both depth ranges give the same corners for their projections.
+        let (no, zo) = (perspective_rh_no(1., 1.5, n, f), perspective_rh_zo(1., 1.5, n, f));
+        let (a, b) = (frustum_corners(&no.inverse().unwrap()), frustum_corners_zo(&zo.inverse().unwrap()));
+        for (p, q) in a.iter().zip(b.iter()) {
+            assert_close_to!(*p, *q, 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_project_zo() {
+        let model: Mat4 = translate(&num::one(), vec3(0., 0., -5.));
+        let proj = perspective_rh_zo(1., 1., 1., 10.);
+        let vp = vec4(10., 20., 100., 100.);
+        let win = project_zo(vec3(0., 0., 4.), &model, &proj, vp);
+        assert_close_to!(win, vec3(60., 70., 0.), 1e-5);
+        let obj = unproject_zo(vec3(30., 40., 1.), &model, &proj, vp);
+        assert_close_to!(project_zo(obj, &model, &proj, vp), vec3(30., 40., 1.), 1e-4);
+    }
+
+    #[test]
+    fn test_view_from_pos_rot() {
+        let m: Mat4 = rotate(&num::one(), 0.7, vec3(1., 2., 3.));
+        let r = Matrix3::new(m.c0.xyz(), m.c1.xyz(), m.c2.xyz());
+        let p = vec3(-3., 2., 8.);
+        let id: Mat4 = num::one();
+        assert_close_to!(view_from_pos_rot(p, r) * world_from_pos_rot(p, r), id, 1e-6);
+        let q = Quat::from_axis_angle(normalize(vec3(1., 2., 3.)), 0.7);
+        assert_close_to!(world_from_pos_rot(p, q), world_from_pos_rot(p, r), 1e-6);
+    }
+
+    #[test]
+    fn test_renormalize_rotation() {
+        let mut m: Mat4 = translate(&num::one(), vec3(1., 2., 3.));
+        let step: Mat4 = rotate(&num::one(), 0.01, vec3(1., 2., 3.));
+        for _ in 0..10000 {
+            m = renormalize_rotation(&(m * step));
+        }
+        let r = Matrix3::new(m.c0.xyz(), m.c1.xyz(), m.c2.xyz());
+        let id: Matrix3<f32> = num::one();
+        assert_close_to!(r * r.transpose(), id, 1e-6);
+        assert_eq!(m.c3, vec4(1., 2., 3., 1.));
+        assert_eq!(vec4(m.c0.w, m.c1.w, m.c2.w, m.c3.w), vec4(0., 0., 0., 1.));
+    }
+
+    #[test]
+    fn test_get_scale_rotation() {
+        let r: Mat4 = rotate(&num::one(), 0.7, vec3(1., 2., 3.));
+        let m = scale(&translate(&r, vec3(1., 2., 3.)), vec3(-2., 3., 0.5));
+        let (s, q) = (get_scale(&m), get_rotation(&m));
+        assert_close_to!(q.determinant(), 1., 1e-6);
+        let back = world_from_pos_rot(get_translation(&m), q);
+        assert_close_to!(scale(&back, s), m, 1e-5);
+    }
 }
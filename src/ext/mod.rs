@@ -37,6 +37,17 @@ pub use self::exp::*;
 pub use self::common::*;
 pub use self::geom::*;
 pub use self::matrix::*;
+pub use self::viewport::*;
+pub use self::sample::*;
+pub use self::color::*;
+pub use self::noise::*;
+pub use self::isometry::*;
+pub use self::affine::*;
+pub use self::wrap::*;
+pub use self::integer::*;
+pub use self::euler::*;
+pub use self::dual::Dual;
+pub use self::dual_quat::DualQuaternion;
 pub use self::consts::{
     Consts,
     epsilon,
@@ -73,4 +84,15 @@ mod exp;
 mod common;
 mod geom;
 mod matrix;
+mod viewport;
+mod sample;
+mod color;
+mod noise;
+mod isometry;
+mod affine;
+mod wrap;
+mod integer;
+mod euler;
+mod dual;
+mod dual_quat;
 pub mod consts;
@@ -0,0 +1,222 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Functions that map uniformly distributed numbers to directions.
+//!
+//! The parameters `u1` and `u2` of the sampling functions are independent
+//! random numbers uniformly distributed in `[0, 1)`. The returned directions
+//! are unit vectors around the `+z` axis. Use `orient_around` to rotate them
+//! to an arbitrary normal.
+//!
+//! The `*_with_rng` variants draw the random numbers from a caller supplied
+//! `Rng`. Together with `seeded_rng`, they make procedural generation
+//! reproducible across runs.
+
+use basenum::BaseFloat;
+use vec::vec::Vector3;
+use super::consts::{ Consts, tau };
+use rand::{ Rand, Rng, SeedableRng, XorShiftRng };
+
+/// Returns a fast pseudo random number generator whose sequence is fully
+/// determined by `seed`.
+///
+/// Unlike `rand::thread_rng`, the same seed always produces the same
+/// sequence, on every platform.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let a = sample_hemisphere_cosine_with_rng::<f32, _>(&mut seeded_rng(42));
+/// let b = sample_hemisphere_cosine_with_rng::<f32, _>(&mut seeded_rng(42));
+/// assert_eq!(a, b);
+/// ```
+pub fn seeded_rng(seed: u64) -> XorShiftRng {
+    // SplitMix64, so that similar seeds give unrelated states, and the state
+    // is never all zeros.
+    let mut z = seed;
+    let mut next = || -> u64 {
+        z = z.wrapping_add(0x9E3779B97F4A7C15);
+        let mut x = z;
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+        x ^ (x >> 31)
+    };
+    let (a, b) = (next(), next());
+    let mut words = [a as u32, (a >> 32) as u32, b as u32, (b >> 32) as u32];
+    if words == [0; 4] {
+        words[0] = 1;
+    }
+    XorShiftRng::from_seed(words)
+}
+
+/// Samples a direction from the `+z` hemisphere with a cosine-weighted
+/// distribution, i.e., the probability density is `cos(θ) / π`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let d = sample_hemisphere_cosine(0.3, 0.7);
+/// assert!(is_approx_eq(&length(d), &1.));
+/// assert!(d.z >= 0.);
+/// assert_eq!(sample_hemisphere_cosine(0., 0.), vec3(0., 0., 1.));
+/// ```
+#[inline]
+pub fn sample_hemisphere_cosine<F>(u1: F, u2: F) -> Vector3<F>
+where
+    F : BaseFloat + Consts<F>
+{
+    let r = u1.sqrt();
+    let phi = tau::<F, F>() * u2;
+    let z = (F::one() - u1).sqrt();
+    Vector3::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+/// Samples a direction from the `+z` hemisphere with a uniform distribution,
+/// i.e., the probability density is `1 / (2π)`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let d = sample_hemisphere_uniform(0.3, 0.7);
+/// assert!(is_approx_eq(&length(d), &1.));
+/// assert!(is_approx_eq(&d.z, &0.7));
+/// ```
+#[inline]
+pub fn sample_hemisphere_uniform<F>(u1: F, u2: F) -> Vector3<F>
+where
+    F : BaseFloat + Consts<F>
+{
+    sample_cone_uniform(F::zero(), u1, u2)
+}
+
+/// Samples a direction from the cone around the `+z` axis with a uniform
+/// distribution.
+///
+/// `cos_max` is the cosine of the half apex angle of the cone. The
+/// probability density is `1 / (2π (1 - cos_max))`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let d = sample_cone_uniform(0.9, 0.5, 0.25);
+/// assert!(is_approx_eq(&length(d), &1.));
+/// assert!(d.z >= 0.9);
+/// ```
+#[inline]
+pub fn sample_cone_uniform<F>(cos_max: F, u1: F, u2: F) -> Vector3<F>
+where
+    F : BaseFloat + Consts<F>
+{
+    let z = F::one() - u1 * (F::one() - cos_max);
+    let r = (F::one() - z * z).sqrt();
+    let phi = tau::<F, F>() * u2;
+    Vector3::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+/// Rotates direction `v`, which is defined around the `+z` axis, to the
+/// space around unit normal `n`.
+///
+/// The orthonormal basis is built without branches on the normal's
+/// direction (Duff et al., 2017), so any unit `n` is accepted.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let n = normalize(vec3(1., 1., 1.));
+/// assert!(is_close_to(&orient_around(vec3(0., 0., 1.), n), &n, 1e-6));
+/// let d = orient_around(sample_hemisphere_cosine(0.3, 0.7), n);
+/// assert!(dot(d, n) >= 0.);
+/// ```
+#[inline]
+pub fn orient_around<F>(v: Vector3<F>, n: Vector3<F>) -> Vector3<F>
+where
+    F : BaseFloat
+{
+    let sign = F::one().copysign(n.z);
+    let a = -F::one() / (sign + n.z);
+    let b = n.x * n.y * a;
+    let b1 = Vector3::new(F::one() + sign * n.x * n.x * a, sign * b, -sign * n.x);
+    let b2 = Vector3::new(b, sign + n.y * n.y * a, -n.y);
+    b1 * v.x + b2 * v.y + n * v.z
+}
+
+/// Samples a direction with `sample_hemisphere_cosine`, using random numbers
+/// generated by `rng`.
+#[inline]
+pub fn sample_hemisphere_cosine_with_rng<F, R: Rng>(rng: &mut R) -> Vector3<F>
+where
+    F : BaseFloat + Consts<F> + Rand
+{
+    let (u1, u2) = (rng.gen(), rng.gen());
+    sample_hemisphere_cosine(u1, u2)
+}
+
+/// Samples a direction with `sample_hemisphere_uniform`, using random
+/// numbers generated by `rng`.
+#[inline]
+pub fn sample_hemisphere_uniform_with_rng<F, R: Rng>(rng: &mut R) -> Vector3<F>
+where
+    F : BaseFloat + Consts<F> + Rand
+{
+    let (u1, u2) = (rng.gen(), rng.gen());
+    sample_hemisphere_uniform(u1, u2)
+}
+
+/// Samples a direction with `sample_cone_uniform`, using random numbers
+/// generated by `rng`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let mut rng = seeded_rng(7);
+/// for _ in 0..16 {
+///     let d: DVec3 = sample_cone_uniform_with_rng(0.9, &mut rng);
+///     assert!(d.z >= 0.9);
+/// }
+/// ```
+#[inline]
+pub fn sample_cone_uniform_with_rng<F, R: Rng>(cos_max: F, rng: &mut R) -> Vector3<F>
+where
+    F : BaseFloat + Consts<F> + Rand
+{
+    let (u1, u2) = (rng.gen(), rng.gen());
+    sample_cone_uniform(cos_max, u1, u2)
+}
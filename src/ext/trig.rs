@@ -24,6 +24,7 @@
 use basenum::BaseFloat;
 use traits::GenFloat;
 use num::Float;
+use std::f64::consts::PI;
 
 /// Simultaneously computes the sine and cosine of `x`, returns
 /// `(sin(x), cos(x))`.
@@ -43,3 +44,33 @@ use num::Float;
 pub fn sin_cos<F: BaseFloat, T: GenFloat<F>>(x: T) -> (T, T) {
     x.split(Float::sin_cos)
 }
+
+/// Interpolates between angles `a` and `b` (in radians) along the shortest
+/// path around the circle, component-wise.
+///
+/// The result is `a + d * t`, where `d` is the difference `b - a` wrapped
+/// into `[-π, π)`. It is not wrapped itself, so it can lie outside of
+/// `[-π, π]`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// // Halfway from 170° to -170° is 180°, not 0°.
+/// let (a, b) = (radians(170_f32), radians(-170.));
+/// assert!(is_approx_eq(&lerp_angle(a, b, 0.5), &pi()));
+/// let v = lerp_angle(vec2(0., 1.), vec2(1., 2.), 0.5);
+/// assert!(is_approx_eq(&v, &vec2(0.5, 1.5)));
+/// ```
+#[inline]
+pub fn lerp_angle<F: BaseFloat, T: GenFloat<F>>(a: T, b: T, t: F) -> T {
+    let pi = F::from(PI).unwrap();
+    let tau = pi + pi;
+    let d = b.zip(a, |y, x| -> F {
+        let d = y - x;
+        d - ((d + pi) / tau).floor() * tau
+    });
+    a + d * t
+}
@@ -0,0 +1,188 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Conversions between normalized device coordinates (NDC), texture
+//! coordinates (UV) and window (pixel) coordinates.
+//!
+//! Graphics APIs disagree on the direction of the `y` axis and on the depth
+//! range of the NDC. These conventions are described by `NdcConvention`,
+//! which is passed to all the functions of this module.
+
+use basenum::BaseFloat;
+use vec::vec::{ Vector2, Vector3, Vector4 };
+use num;
+
+/// Describes how NDC maps to window and texture coordinates.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct NdcConvention {
+    /// `true` if the `y` axis of window and texture coordinates points to
+    /// the opposite direction of the `y` axis of the NDC.
+    pub flip_y: bool,
+    /// `true` if the depth range of the NDC is `[0, 1]`, `false` if it is
+    /// `[-1, 1]`.
+    pub zero_to_one: bool,
+}
+
+impl NdcConvention {
+    /// OpenGL: window origin at the bottom-left corner, NDC depth in
+    /// `[-1, 1]`.
+    ///
+    /// # Note
+    ///
+    /// Use `NdcConvention { flip_y: true, ..NdcConvention::OPENGL }` for
+    /// pixel coordinates with the origin at the top-left corner, e.g., the
+    /// positions of mouse events.
+    pub const OPENGL: NdcConvention =
+        NdcConvention { flip_y: false, zero_to_one: false };
+    /// Vulkan: NDC `y` points down, NDC depth in `[0, 1]`.
+    pub const VULKAN: NdcConvention =
+        NdcConvention { flip_y: false, zero_to_one: true };
+    /// Direct3D and Metal: NDC `y` points up but window `y` points down,
+    /// NDC depth in `[0, 1]`.
+    pub const DIRECTX: NdcConvention =
+        NdcConvention { flip_y: true, zero_to_one: true };
+}
+
+/// Converts the `x` and `y` components of a point in NDC to texture
+/// coordinates in `[0, 1]`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let ndc = vec2(-1., 0.5);
+/// assert_eq!(ndc_to_uv(ndc, NdcConvention::OPENGL), vec2(0., 0.75));
+/// assert_eq!(ndc_to_uv(ndc, NdcConvention::DIRECTX), vec2(0., 0.25));
+/// ```
+#[inline]
+pub fn ndc_to_uv<T>(
+    ndc: Vector2<T>,
+    conv: NdcConvention
+) -> Vector2<T>
+where
+    T : BaseFloat
+{
+    let half = num::cast::<f32, T>(0.5).unwrap();
+    let uv = ndc * half + half;
+    if conv.flip_y {
+        Vector2::new(uv.x, num::one::<T>() - uv.y)
+    } else {
+        uv
+    }
+}
+
+/// Converts texture coordinates in `[0, 1]` to the `x` and `y` components
+/// of a point in NDC.
+///
+/// This is the inverse of `ndc_to_uv`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let uv = vec2(0., 0.25);
+/// assert_eq!(uv_to_ndc(uv, NdcConvention::DIRECTX), vec2(-1., 0.5));
+/// ```
+#[inline]
+pub fn uv_to_ndc<T>(
+    uv: Vector2<T>,
+    conv: NdcConvention
+) -> Vector2<T>
+where
+    T : BaseFloat
+{
+    let one = num::one::<T>();
+    let uv = if conv.flip_y { Vector2::new(uv.x, one - uv.y) } else { uv };
+    uv * (one + one) - one
+}
+
+/// Converts a point in NDC to window coordinates.
+///
+/// `viewport` is `(x, y, width, height)` of the viewport, in pixels. The
+/// depth of the returned point is always in `[0, 1]`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let vp = vec4(0., 0., 800., 600.);
+/// let ndc = vec3(0., 1., 0.);
+/// assert_eq!(ndc_to_window(ndc, vp, NdcConvention::OPENGL), vec3(400., 600., 0.5));
+/// assert_eq!(ndc_to_window(ndc, vp, NdcConvention::DIRECTX), vec3(400., 0., 0.));
+/// ```
+#[inline]
+pub fn ndc_to_window<T>(
+    ndc: Vector3<T>,
+    viewport: Vector4<T>,
+    conv: NdcConvention
+) -> Vector3<T>
+where
+    T : BaseFloat
+{
+    let uv = ndc_to_uv(ndc.xy(), conv);
+    let half = num::cast::<f32, T>(0.5).unwrap();
+    let z = if conv.zero_to_one { ndc.z } else { ndc.z * half + half };
+    Vector3::new(
+        viewport.x + uv.x * viewport.z,
+        viewport.y + uv.y * viewport.w,
+        z)
+}
+
+/// Converts a point in window coordinates to NDC.
+///
+/// This is the inverse of `ndc_to_window`.
+///
+/// # Example
+///
+/// ```rust
+/// use glm::*;
+/// use glm::ext::*;
+///
+/// let vp = vec4(0., 0., 800., 600.);
+/// let win = vec3(200., 150., 0.5);
+/// assert_eq!(window_to_ndc(win, vp, NdcConvention::OPENGL), vec3(-0.5, -0.5, 0.));
+/// assert_eq!(window_to_ndc(win, vp, NdcConvention::VULKAN), vec3(-0.5, -0.5, 0.5));
+/// ```
+#[inline]
+pub fn window_to_ndc<T>(
+    win: Vector3<T>,
+    viewport: Vector4<T>,
+    conv: NdcConvention
+) -> Vector3<T>
+where
+    T : BaseFloat
+{
+    let uv = Vector2::new(
+        (win.x - viewport.x) / viewport.z,
+        (win.y - viewport.y) / viewport.w);
+    let xy = uv_to_ndc(uv, conv);
+    let one = num::one::<T>();
+    let z = if conv.zero_to_one { win.z } else { win.z * (one + one) - one };
+    Vector3::new(xy.x, xy.y, z)
+}
@@ -0,0 +1,110 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+// Texture coordinate wrapping, i.e., the `GLM_GTX_wrap` extension.
+
+use basenum::{ BaseNum, BaseFloat };
+use traits::GenFloat;
+
+/// Clamps texture coordinate `x` to `[0, 1]`, i.e., the `CLAMP_TO_EDGE`
+/// address mode.
+///
+/// # Note
+///
+/// This is `clamp` of `GLM_GTX_wrap`, renamed to avoid a collision with the
+/// built-in function `clamp`.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec3;
+/// use glm::ext::clamp_to_edge;
+///
+/// assert_eq!(clamp_to_edge(vec3(-0.5, 0.5, 1.5)), vec3(0., 0.5, 1.));
+/// ```
+#[inline(always)]
+pub fn clamp_to_edge<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
+    x.map(|f| -> F {
+        BaseNum::min(BaseNum::max(f, F::zero()), F::one())
+    })
+}
+
+/// Wraps texture coordinate `x` into `[0, 1)`, i.e., the `REPEAT` address
+/// mode.
+///
+/// Negative coordinates wrap around as well, e.g., `-0.25` becomes `0.75`.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec3;
+/// use glm::ext::repeat;
+///
+/// assert_eq!(repeat(vec3(-0.25, 0.5, 1.25)), vec3(0.75, 0.5, 0.25));
+/// ```
+#[inline(always)]
+pub fn repeat<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
+    x.map(|f| -> F {
+        f - f.floor()
+    })
+}
+
+/// Mirrors texture coordinate `x` once around `0`, and then clamps it to
+/// `[0, 1]`, i.e., the `MIRROR_CLAMP_TO_EDGE` address mode.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec3;
+/// use glm::ext::mirror_clamp;
+///
+/// assert_eq!(mirror_clamp(vec3(-0.25, 0.5, 1.25)), vec3(0.25, 0.5, 1.));
+/// ```
+#[inline(always)]
+pub fn mirror_clamp<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
+    x.map(|f| -> F {
+        BaseNum::min(f.abs(), F::one())
+    })
+}
+
+/// Wraps texture coordinate `x` into `[0, 1]`, mirroring every other
+/// repetition, i.e., the `MIRRORED_REPEAT` address mode.
+///
+/// # Example
+///
+/// ```
+/// use glm::vec4;
+/// use glm::ext::mirror_repeat;
+///
+/// let x = vec4(-0.25, 0.5, 1.25, 2.25);
+/// assert_eq!(mirror_repeat(x), vec4(0.25, 0.5, 0.75, 0.25));
+/// ```
+#[inline(always)]
+pub fn mirror_repeat<F: BaseFloat, T: GenFloat<F>>(x: T) -> T {
+    x.map(|f| -> F {
+        let one = F::one();
+        let two = one + one;
+        let t = f - (f / two).floor() * two;
+        one - (t - one).abs()
+    })
+}
@@ -41,7 +41,7 @@
 //!
 //! - Precision qualifiers is not supported,
 //! - Half float type is not available, yet,
-//! - There is no vector swizzle operators. For example, you can't do this,
+//! - Swizzle operators are methods. For example, you can't do this,
 //!
 //!   ~~~ignore
 //!   # use glm::*;
@@ -49,10 +49,18 @@
 //!   // and,
 //!   my_vec2.yx = my_vec4.xx;
 //!   ~~~
-//!   Part of swizzle operators can be done but must be in a very tedious way
-//!   at the moment.
-//!   The plan is to implemente accessing swizzle operators *after* Rust macro
-//!   supports concatenating identifiers.
+//!   but you can do this,
+//!
+//!   ~~~
+//!   # use glm::*;
+//!   # let my_vec4 = vec4(1., 2., 3., 4.);
+//!   let mut my_vec2 = my_vec4.wz();
+//!   // and,
+//!   my_vec2.set_yx(my_vec4.xx());
+//!   ~~~
+//!   Swizzle operators and setters are defined in traits `Swizzle2`,
+//!   `Swizzle3` and `Swizzle4`, which are implemented for vectors of
+//!   corresponding dimensions.
 //! - Because Rust does not support function name overloading, loads of
 //!   convenient constructor functions can't be implemented. For example,
 //!   you can't do this,
@@ -121,11 +129,67 @@
 //!   ~~~
 //! - Built-in function `mod` is renamed to `fmod`, because **mod** is a Rust
 //!   keyword.
+//! - Some functions panic (e.g., `inverse` of a singular matrix) or return
+//!   `NaN`s (e.g., `normalize` of a zero vector) on invalid input. Fallible
+//!   versions prefixed with `try_` (e.g., `ext::try_inverse`, `try_uint`)
+//!   return `Result<_, GlmError>` instead.
+//!
+//! ## Optional features
+//!
+//! - `cgmath` and `nalgebra`: conversions (`From`/`Into`) between `glm` types
+//!   and the vectors and matrices of these crates.
+//! - `zerocopy` and `rkyv`: zero-copy (de)serialization of vectors and
+//!   matrices, e.g., for memory-mapped asset files. With `zerocopy`,
+//!   matrices are not `IntoBytes`, view their columns with
+//!   `m.as_array().as_bytes()` instead.
+//! - `serde`: implements `Serialize` and `Deserialize` for vectors,
+//!   matrices and quaternions. They are (de)serialized as structs, e.g.,
+//!   `{"x":1.0,"y":2.0}`, and matrices as structs of column vectors.
+//! - `bytemuck`: implements `Pod` and `Zeroable` for vectors, matrices and
+//!   quaternions of `Pod` scalars, so that they can be cast to bytes, e.g.,
+//!   for `wgpu::Queue::write_buffer`, without `unsafe` code.
+//! - `encase`: implements `ShaderType`, so that vectors and matrices can be
+//!   written to uniform and storage buffers with correct std140/std430
+//!   layouts.
+//! - `bevy_reflect`: derives `Reflect` (and thus `TypePath`) for vectors and
+//!   matrices, so that they can be inspected and serialized by Bevy based
+//!   editors.
+//! - `fast-rsqrt`: computes `inversesqrt` (and thus `normalize`) of `f32`
+//!   with the SSE `rsqrtss` instruction plus one Newton-Raphson iteration.
+//!   Faster, but less accurate.
+//! - `fma-dispatch`: computes `fma` of `f32` and `f64` with the FMA
+//!   instruction if the running x86_64 CPU supports it, even if the target
+//!   does not enable it at compile time. Without it, `fma` of such targets
+//!   falls back to a correct but slow software implementation.
+//! - `simd`: computes `f32` 4-component dot products (and thus `length`
+//!   and `normalize` of `Vec4`), `Mat4 * Vec4`, `Mat4 * Mat4` and the
+//!   inverse of `Mat4` with SSE instructions on x86 and x86_64 targets.
+//!   The memory layouts of vectors and matrices are unchanged, i.e., `Vec4`
+//!   and `Mat4` are not stored as 128-bit SSE values. Operands are loaded
+//!   into registers by each operation.
 //!
 
 extern crate rand;
 extern crate num;
 extern crate quickcheck;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "zerocopy")]
+extern crate zerocopy;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
+#[cfg(feature = "encase")]
+extern crate encase;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(feature = "bevy_reflect")]
+extern crate bevy_reflect;
 
 pub use builtin::*;
 
@@ -135,11 +199,11 @@ pub use basenum::{
 };
 
 pub use traits::{
-    GenNum, GenInt, GenFloat
+    GenNum, GenInt, GenFloat, NumType, FloatType
 };
 
 pub use vec::traits::{
-    GenVec, GenNumVec, GenFloatVec, GenBVec,
+    GenVec, GenNumVec, GenFloatVec, GenBVec, NumVecType, FloatVecType,
 };
 
 pub use vec::vec::{
@@ -151,11 +215,11 @@ pub use vec::vec::{
     DVec2, DVec3, DVec4, dvec2, dvec3, dvec4,
 };
 
-// pub use vec::swizzle::{
-//     Swizzle2, Swizzle3, Swizzle4,
-// };
+pub use vec::swizzle::{
+    Swizzle2, Swizzle3, Swizzle4,
+};
 
-pub use mat::traits::{ GenMat, GenSquareMat };
+pub use mat::traits::{ GenMat, GenSquareMat, MatType, SquareMatType };
 
 pub use mat::mat::{
     Matrix2, Matrix3, Matrix4,
@@ -173,9 +237,12 @@ pub use mat::ctor::{
     dmat3x2, dmat2x3, dmat4x2, dmat2x4, dmat4x3, dmat3x4,
 };
 
+pub use quat::{ Quaternion, Quat, DQuat, quat, dquat };
+
 pub use cast::{
     PrimCast,
     int, uint, float, double, boolean,
+    try_int, try_uint, try_float, try_double,
     to_ivec2, to_ivec3, to_ivec4,
     to_uvec2, to_uvec3, to_uvec4,
     to_vec2, to_vec3, to_vec4,
@@ -183,12 +250,15 @@ pub use cast::{
     to_bvec2, to_bvec3, to_bvec4
 };
 
+pub use error::GlmError;
+
 #[macro_use]
 mod basenum;
 mod traits;
 mod vec {
     pub mod traits;
     pub mod vec;
+    pub mod swizzle;
 }
 mod mat {
     pub mod traits;
@@ -196,7 +266,11 @@ mod mat {
     pub mod ctor;
     pub mod sqmat;
 }
+mod quat;
+mod simd;
 mod cast;
+mod error;
+mod compat;
 
 pub mod builtin;
 pub mod ext;
@@ -23,10 +23,18 @@
 
 use basenum::{ BaseFloat, ApproxEq };
 use vec::vec::{ Vector2, Vector3, Vector4 };
-use super::traits::GenMat;
-use std::mem;
-use std::ops::{ Add, Mul, Sub, Neg, Div, Rem, Index, IndexMut };
+use traits::GenNum;
+use vec::traits::GenVec;
+use super::traits::{ GenMat, MatType };
+use error::GlmError;
+use std::{ cmp, mem, slice };
+use std::convert::TryFrom;
+use std::ops::{
+    Add, Mul, Sub, Neg, Div, Rem, Index, IndexMut,
+    AddAssign, SubAssign, MulAssign, DivAssign, RemAssign,
+};
 use rand::{ Rand, Rng };
+use builtin as bif;
 use num::Zero;
 #[cfg(test)]
 use quickcheck::{ Arbitrary, Gen };
@@ -87,14 +95,11 @@ macro_rules! mul_v_unrolled {
             $m[0].w * $v.x + $m[1].w * $v.y + $m[2].w * $v.z
         )
     };
-    ($m: ident, $v: ident, Vector4, Vector4) => {
-        Vector4::new(
-            $m[0].x * $v.x + $m[1].x * $v.y + $m[2].x * $v.z + $m[3].x * $v.w,
-            $m[0].y * $v.x + $m[1].y * $v.y + $m[2].y * $v.z + $m[3].y * $v.w,
-            $m[0].z * $v.x + $m[1].z * $v.y + $m[2].z * $v.z + $m[3].z * $v.w,
-            $m[0].w * $v.x + $m[1].w * $v.y + $m[2].w * $v.z + $m[3].w * $v.w
-        )
-    };
+    ($m: ident, $v: ident, Vector4, Vector4) => ({
+        let m: [[T; 4]; 4] = (*$m).into();
+        let c = T::mul_mat4_vec4(&m, $v.as_array());
+        Vector4::new(c[0], c[1], c[2], c[3])
+    });
 }
 
 macro_rules! mul_m_unrolled {
@@ -196,6 +201,17 @@ macro_rules! def_matrix {
         $(
             #[repr(C)]
             #[derive(Copy, Clone, PartialEq, Debug)]
+            // NOTE: the derive of `IntoBytes` can not prove that columns of
+            //       a generic type have no padding. Use `as_array().as_bytes()`.
+            #[cfg_attr(feature = "zerocopy", derive(
+                ::zerocopy::FromBytes, ::zerocopy::Immutable,
+                ::zerocopy::KnownLayout
+            ))]
+            #[cfg_attr(feature = "rkyv", derive(
+                ::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize
+            ))]
+            #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+            #[cfg_attr(feature = "bevy_reflect", derive(::bevy_reflect::Reflect))]
             pub struct $t<T: BaseFloat> {
                 $(pub $field: $ct<T>), +
             }
@@ -225,6 +241,7 @@ macro_rules! impl_matrix {
         $tr: ident,         // type of transpose matrix,
         $om: ident,         // the product of multiplying transpose matrix,
         $cn: expr,          // number of columns, i.e., the dimension of $rt,
+        $rn: expr,          // number of rows, i.e., the dimension of $ct,
         $($field: ident), + // fields for repeating reference columns,
     }), +) => {
         $(
@@ -233,6 +250,27 @@ macro_rules! impl_matrix {
                 pub fn new($($field: $ct<T>), +) -> $t<T> {
                     $t { $($field: $field), + }
                 }
+                /// Constructs a matrix with every element set to `x`.
+                ///
+                /// # Note
+                ///
+                /// This is different from the GLSL constructor `matN(x)`,
+                /// see `from_diag_scalar`.
+                #[inline(always)]
+                pub fn from_s(x: T) -> $t<T> {
+                    $t { $($field: $ct::from_s(x)), + }
+                }
+                /// Constructs a matrix with `x` on the main diagonal and
+                /// zero elsewhere, i.e., the GLSL constructor `matN(x)`.
+                #[inline]
+                pub fn from_diag_scalar(x: T) -> $t<T> {
+                    let mut m = $t::<T>::zero();
+                    let n = cmp::min($cn, $ct::<T>::DIM);
+                    for i in 0..n {
+                        m[i][i] = x;
+                    }
+                    m
+                }
                 #[inline(always)]
                 pub fn from_array(ary: &[$ct<T>; $cn]) -> &$t<T> {
                     let m: &Self = unsafe { mem::transmute(ary) };
@@ -253,10 +291,83 @@ macro_rules! impl_matrix {
                     let ary: &mut[$ct<T>; $cn] = unsafe { mem::transmute(self) };
                     ary
                 }
+                /// Returns the elements of _self_ as a flat array in column
+                /// major order, e.g., for `glUniformMatrix4fv`.
+                #[inline(always)]
+                pub fn as_flat_array(&self) -> &[T; $cn * $rn] {
+                    let ary: &[T; $cn * $rn] = unsafe { mem::transmute(self) };
+                    ary
+                }
+                #[inline(always)]
+                pub fn as_flat_array_mut(&mut self) -> &mut [T; $cn * $rn] {
+                    let ary: &mut [T; $cn * $rn] = unsafe { mem::transmute(self) };
+                    ary
+                }
+                /// Returns the columns of _self_ as arrays, i.e., `[[T; R]; C]`
+                /// for a matrix of `C` columns and `R` rows.
+                #[inline(always)]
+                pub fn as_column_arrays(&self) -> &[[T; $rn]; $cn] {
+                    let ary: &[[T; $rn]; $cn] = unsafe { mem::transmute(self) };
+                    ary
+                }
+                #[inline(always)]
+                pub fn as_column_arrays_mut(&mut self) -> &mut [[T; $rn]; $cn] {
+                    let ary: &mut [[T; $rn]; $cn] = unsafe { mem::transmute(self) };
+                    ary
+                }
+                /// Returns a copy of the elements of _self_ in column major
+                /// order.
+                #[inline(always)]
+                pub fn to_array(&self) -> [T; $cn * $rn] {
+                    *self.as_flat_array()
+                }
+                /// Returns a raw pointer to the first element. Elements are
+                /// contiguous and in column major order.
+                #[inline(always)]
+                pub fn as_ptr(&self) -> *const T {
+                    self.as_flat_array().as_ptr()
+                }
+                #[inline(always)]
+                pub fn as_mut_ptr(&mut self) -> *mut T {
+                    self.as_flat_array_mut().as_mut_ptr()
+                }
+                /// Returns an iterator over the column vectors.
+                #[inline(always)]
+                pub fn columns(&self) -> slice::Iter<'_, $ct<T>> {
+                    self.as_array().iter()
+                }
+                /// Returns an iterator over mutable references to the
+                /// column vectors.
+                #[inline(always)]
+                pub fn columns_mut(&mut self) -> slice::IterMut<'_, $ct<T>> {
+                    self.as_array_mut().iter_mut()
+                }
+                /// Returns an iterator over the row vectors.
+                #[inline]
+                pub fn rows(&self) -> impl Iterator<Item = $rt<T>> + '_ {
+                    (0..$ct::<T>::DIM).map(move |i| $rt::new($(self.$field[i]), +))
+                }
                 #[inline(always)]
                 pub fn add_s(&self, rhs: T) -> $t<T> {
                     $t::new($(self.$field + rhs), +)
                 }
+                /// Returns the column vectors of booleans that indicate which
+                /// elements are NaN.
+                #[inline]
+                pub fn isnan(&self) -> [$ct<bool>; $cn] {
+                    [$(bif::isnan(self.$field)), +]
+                }
+                /// Returns the column vectors of booleans that indicate which
+                /// elements are positive or negative infinity.
+                #[inline]
+                pub fn isinf(&self) -> [$ct<bool>; $cn] {
+                    [$(bif::isinf(self.$field)), +]
+                }
+                /// Returns `true` if no element is NaN or infinity.
+                #[inline]
+                pub fn is_finite(&self) -> bool {
+                    $(self.$field.as_array().iter().all(|x| x.is_finite())) && +
+                }
                 #[inline(always)]
                 pub fn add_m(&self, rhs: &$t<T>) -> $t<T> {
                     $t::new($(self.$field + rhs.$field), +)
@@ -315,6 +426,23 @@ macro_rules! impl_matrix {
                     self.as_array_mut().index_mut(i)
                 }
             }
+            impl<T: BaseFloat> Index<(usize, usize)> for $t<T> {
+                type Output = T;
+                // NOTE: the index is (row, column), while `m[i][j]` is
+                //       (column, row) because matrices are column major.
+                #[inline(always)]
+                fn index(&self, i: (usize, usize)) -> &T {
+                    let (row, col) = i;
+                    self.as_array().index(col).index(row)
+                }
+            }
+            impl<T: BaseFloat> IndexMut<(usize, usize)> for $t<T> {
+                #[inline(always)]
+                fn index_mut(&mut self, i: (usize, usize)) -> &mut T {
+                    let (row, col) = i;
+                    self.as_array_mut().index_mut(col).index_mut(row)
+                }
+            }
             impl<T: BaseFloat> Rand for $t<T> {
                 #[inline]
                 fn rand<R: Rng>(rng: &mut R) -> $t<T> {
@@ -336,6 +464,34 @@ macro_rules! impl_matrix {
                     self.add_s(rhs)
                 }
             }
+            impl<T: BaseFloat> From<[[T; $rn]; $cn]> for $t<T> {
+                /// Constructs a matrix from an array of columns.
+                #[inline(always)]
+                fn from(ary: [[T; $rn]; $cn]) -> $t<T> {
+                    let [$($field),+] = ary;
+                    $t { $($field: $ct::from($field)), + }
+                }
+            }
+            impl<T: BaseFloat> From<$t<T>> for [[T; $rn]; $cn] {
+                #[inline(always)]
+                fn from(m: $t<T>) -> [[T; $rn]; $cn] {
+                    [$(m.$field.into()), +]
+                }
+            }
+            impl<'a, T: BaseFloat> TryFrom<&'a [T]> for $t<T> {
+                type Error = GlmError;
+                /// Constructs a matrix from its elements in column major
+                /// order, or returns `Err(GlmError::LengthMismatch)` if the
+                /// length of `s` is not the number of elements.
+                fn try_from(s: &'a [T]) -> Result<$t<T>, GlmError> {
+                    let n = $ct::<T>::DIM;
+                    if s.len() != n * $cn {
+                        return Err(GlmError::LengthMismatch);
+                    }
+                    let mut cols = s.chunks(n);
+                    Ok($t { $($field: $ct::try_from(cols.next().unwrap())?), + })
+                }
+            }
             impl<T: BaseFloat> ApproxEq for $t<T> {
                 type BaseType = T;
                 #[inline]
@@ -420,6 +576,88 @@ macro_rules! impl_matrix {
                     self.mul_m(&rhs)
                 }
             }
+            impl<'a, 'b, T: BaseFloat> Add<&'b $t<T>> for &'a $t<T> {
+                type Output = $t<T>;
+                #[inline(always)]
+                fn add(self, rhs: &'b $t<T>) -> $t<T> {
+                    self.add_m(rhs)
+                }
+            }
+            impl<'a, 'b, T: BaseFloat> Sub<&'b $t<T>> for &'a $t<T> {
+                type Output = $t<T>;
+                #[inline(always)]
+                fn sub(self, rhs: &'b $t<T>) -> $t<T> {
+                    self.sub_m(rhs)
+                }
+            }
+            impl<'a, 'b, T: BaseFloat> Mul<&'b $rt<T>> for &'a $t<T> {
+                type Output = $ct<T>;
+                #[inline(always)]
+                fn mul(self, rhs: &'b $rt<T>) -> $ct<T> {
+                    self.mul_v(rhs)
+                }
+            }
+            impl<'a, 'b, T: BaseFloat> Mul<&'b $tr<T>> for &'a $t<T> {
+                type Output = $om<T>;
+                #[inline(always)]
+                fn mul(self, rhs: &'b $tr<T>) -> $om<T> {
+                    self.mul_m(rhs)
+                }
+            }
+            impl<T: BaseFloat> AddAssign<T> for $t<T> {
+                #[inline(always)]
+                fn add_assign(&mut self, rhs: T) {
+                    $(self.$field = self.$field + rhs;)+
+                }
+            }
+            impl<T: BaseFloat> AddAssign<$t<T>> for $t<T> {
+                #[inline(always)]
+                fn add_assign(&mut self, rhs: $t<T>) {
+                    $(self.$field = self.$field + rhs.$field;)+
+                }
+            }
+            impl<T: BaseFloat> SubAssign<T> for $t<T> {
+                #[inline(always)]
+                fn sub_assign(&mut self, rhs: T) {
+                    $(self.$field = self.$field - rhs;)+
+                }
+            }
+            impl<T: BaseFloat> SubAssign<$t<T>> for $t<T> {
+                #[inline(always)]
+                fn sub_assign(&mut self, rhs: $t<T>) {
+                    $(self.$field = self.$field - rhs.$field;)+
+                }
+            }
+            impl<T: BaseFloat> MulAssign<T> for $t<T> {
+                #[inline(always)]
+                fn mul_assign(&mut self, rhs: T) {
+                    $(self.$field = self.$field * rhs;)+
+                }
+            }
+            impl<T: BaseFloat> DivAssign<T> for $t<T> {
+                #[inline(always)]
+                fn div_assign(&mut self, rhs: T) {
+                    $(self.$field = self.$field / rhs;)+
+                }
+            }
+            impl<T: BaseFloat> DivAssign<$t<T>> for $t<T> {
+                #[inline(always)]
+                fn div_assign(&mut self, rhs: $t<T>) {
+                    $(self.$field = self.$field / rhs.$field;)+
+                }
+            }
+            impl<T: BaseFloat> RemAssign<T> for $t<T> {
+                #[inline(always)]
+                fn rem_assign(&mut self, rhs: T) {
+                    $(self.$field = self.$field % rhs;)+
+                }
+            }
+            impl<T: BaseFloat> RemAssign<$t<T>> for $t<T> {
+                #[inline(always)]
+                fn rem_assign(&mut self, rhs: $t<T>) {
+                    $(self.$field = self.$field % rhs.$field;)+
+                }
+            }
             impl<T: BaseFloat> Zero for $t<T> {
                 #[inline(always)]
                 fn zero() -> $t<T> {
@@ -430,6 +668,10 @@ macro_rules! impl_matrix {
                     $(self.$field.is_zero()) && +
                 }
             }
+            impl<T: BaseFloat> MatType for $t<T> {
+                type Scalar = T;
+                type Column = $ct<T>;
+            }
             impl<T: BaseFloat> GenMat<T, $ct<T>> for $t<T> {
                 type R = $rt<T>;
                 type Transpose = $tr<T>;
@@ -447,17 +689,17 @@ macro_rules! impl_matrix {
 }
 
 impl_matrix! {
-    { Matrix2,   Vector2, Vector2, Matrix2,   Matrix2, 2, c0, c1 },
-    { Matrix3x2, Vector2, Vector3, Matrix2x3, Matrix2, 3, c0, c1, c2 },
-    { Matrix4x2, Vector2, Vector4, Matrix2x4, Matrix2, 4, c0, c1, c2, c3 },
+    { Matrix2,   Vector2, Vector2, Matrix2,   Matrix2, 2, 2, c0, c1 },
+    { Matrix3x2, Vector2, Vector3, Matrix2x3, Matrix2, 3, 2, c0, c1, c2 },
+    { Matrix4x2, Vector2, Vector4, Matrix2x4, Matrix2, 4, 2, c0, c1, c2, c3 },
 
-    { Matrix2x3, Vector3, Vector2, Matrix3x2, Matrix3, 2, c0, c1 },
-    { Matrix3,   Vector3, Vector3, Matrix3,   Matrix3, 3, c0, c1, c2 },
-    { Matrix4x3, Vector3, Vector4, Matrix3x4, Matrix3, 4, c0, c1, c2, c3 },
+    { Matrix2x3, Vector3, Vector2, Matrix3x2, Matrix3, 2, 3, c0, c1 },
+    { Matrix3,   Vector3, Vector3, Matrix3,   Matrix3, 3, 3, c0, c1, c2 },
+    { Matrix4x3, Vector3, Vector4, Matrix3x4, Matrix3, 4, 3, c0, c1, c2, c3 },
 
-    { Matrix2x4, Vector4, Vector2, Matrix4x2, Matrix4, 2, c0, c1 },
-    { Matrix3x4, Vector4, Vector3, Matrix4x3, Matrix4, 3, c0, c1, c2 },
-    { Matrix4,   Vector4, Vector4, Matrix4,   Matrix4, 4, c0, c1, c2, c3 }
+    { Matrix2x4, Vector4, Vector2, Matrix4x2, Matrix4, 2, 4, c0, c1 },
+    { Matrix3x4, Vector4, Vector3, Matrix4x3, Matrix4, 3, 4, c0, c1, c2 },
+    { Matrix4,   Vector4, Vector4, Matrix4,   Matrix4, 4, 4, c0, c1, c2, c3 }
 }
 
 macro_rules! impl_mul(
@@ -474,10 +716,68 @@ macro_rules! impl_mul(
                     )
                 }
             }
+            impl<'a, 'b, T: BaseFloat> Mul<&'b $rhs<T>> for &'a $t<T> {
+                type Output = $output<T>;
+                #[inline(always)]
+                fn mul(self, rhs: &'b $rhs<T>) -> $output<T> {
+                    $output::new(
+                        $(self.mul_v(&rhs.$field)), +
+                    )
+                }
+            }
         )+
     };
 );
 
+// See `def_consts` in `vec.rs`.
+macro_rules! def_consts(
+    ($({ $t: ident, $ct: ident, $($field: ident),+ }),+) => {
+        $(
+            impl $t<f32> {
+                /// The matrix whose elements are all `0`.
+                pub const ZERO: $t<f32> = $t { $($field: $ct::<f32>::ZERO),+ };
+            }
+            impl $t<f64> {
+                /// The matrix whose elements are all `0`.
+                pub const ZERO: $t<f64> = $t { $($field: $ct::<f64>::ZERO),+ };
+            }
+        )+
+    }
+);
+
+def_consts! {
+    { Matrix2,   Vector2, c0, c1 },
+    { Matrix3x2, Vector2, c0, c1, c2 },
+    { Matrix4x2, Vector2, c0, c1, c2, c3 },
+    { Matrix2x3, Vector3, c0, c1 },
+    { Matrix3,   Vector3, c0, c1, c2 },
+    { Matrix4x3, Vector3, c0, c1, c2, c3 },
+    { Matrix2x4, Vector4, c0, c1 },
+    { Matrix3x4, Vector4, c0, c1, c2 },
+    { Matrix4,   Vector4, c0, c1, c2, c3 }
+}
+
+macro_rules! def_identity(
+    ($({ $t: ident, $ct: ident, $({ $field: ident, $axis: ident }),+ }),+) => {
+        $(
+            impl $t<f32> {
+                /// The identity matrix.
+                pub const IDENTITY: $t<f32> = $t { $($field: $ct::<f32>::$axis),+ };
+            }
+            impl $t<f64> {
+                /// The identity matrix.
+                pub const IDENTITY: $t<f64> = $t { $($field: $ct::<f64>::$axis),+ };
+            }
+        )+
+    }
+);
+
+def_identity! {
+    { Matrix2, Vector2, { c0, X }, { c1, Y } },
+    { Matrix3, Vector3, { c0, X }, { c1, Y }, { c2, Z } },
+    { Matrix4, Vector4, { c0, X }, { c1, Y }, { c2, Z }, { c3, W } }
+}
+
 impl_mul! {
     { Matrix2x3, Matrix2,   Matrix2x3, c0, c1 },
     { Matrix2x4, Matrix2,   Matrix2x4, c0, c1 },
@@ -507,6 +807,23 @@ impl_mul! {
     { Matrix4x3, Matrix4,   Matrix4x3, c0, c1, c2, c3 }
 }
 
+// `m *= rhs` is only meaningful when the product has the same type as `m`,
+// i.e., for square matrices.
+macro_rules! impl_mul_assign(
+    ($($t: ident),+) => {
+        $(
+            impl<T: BaseFloat> MulAssign<$t<T>> for $t<T> {
+                #[inline(always)]
+                fn mul_assign(&mut self, rhs: $t<T>) {
+                    *self = self.mul_m(&rhs);
+                }
+            }
+        )+
+    };
+);
+
+impl_mul_assign! { Matrix2, Matrix3, Matrix4 }
+
 macro_rules! def_alias(
     (
         $({
@@ -578,6 +895,42 @@ mod test {
 
     use mat::ctor::*;
     use vec::vec::*;
+    use num::Float;
+    use error::GlmError;
+    use std::convert::TryFrom;
+    use super::{ Mat3, Mat3x2, Mat2x3, Mat2x4, Mat4, DMat2, DMat4x3 };
+    use num::{ One, Zero };
+
+    #[test]
+    fn test_consts() {
+        const ID: Mat4 = Mat4::IDENTITY;
+        assert_eq!(ID, Mat4::one());
+        assert_eq!(DMat2::IDENTITY, DMat2::one());
+        assert_eq!(DMat4x3::ZERO, DMat4x3::zero());
+        assert_eq!(Mat3::IDENTITY * Mat3::ZERO, Mat3::ZERO);
+    }
+
+    #[test]
+    fn test_flat_array() {
+        let mut m = mat3x2(1., 2., 3., 4., 5., 6.);
+        assert_eq!(m.as_flat_array(), &[1., 2., 3., 4., 5., 6.]);
+        assert_eq!(m.to_array(), [1., 2., 3., 4., 5., 6.]);
+        m.as_flat_array_mut()[3] = 0.;
+        assert_eq!(m[1], vec2(3., 0.));
+        assert_eq!(unsafe { *m.as_ptr().offset(4) }, 5.);
+        assert_eq!(Mat4::IDENTITY.to_array()[15], 1.);
+        assert_eq!(m.as_column_arrays(), &[[1., 2.], [3., 0.], [5., 6.]]);
+        m.as_column_arrays_mut()[2][0] = 0.;
+        assert_eq!(m[2], vec2(0., 6.));
+    }
+
+    #[test]
+    fn test_from_array() {
+        let m: Mat3x2 = [[1., 2.], [3., 4.], [5., 6.]].into();
+        assert_eq!(m, mat3x2(1., 2., 3., 4., 5., 6.));
+        let a: [[f32; 2]; 3] = m.into();
+        assert_eq!(a, [[1., 2.], [3., 4.], [5., 6.]]);
+    }
 
     #[test]
     fn test_index() {
@@ -588,6 +941,74 @@ mod test {
         assert_eq!(m[0][1], 2.)
     }
 
+    #[test]
+    fn test_try_from() {
+        let a = [1., 2., 3., 4., 5., 6.];
+        assert_eq!(Mat3x2::try_from(&a[..]), Ok(mat3x2(1., 2., 3., 4., 5., 6.)));
+        assert_eq!(Mat2x3::try_from(&a[..]), Ok(mat2x3(1., 2., 3., 4., 5., 6.)));
+        assert_eq!(Mat3::try_from(&a[..]), Err(GlmError::LengthMismatch));
+    }
+
+    #[test]
+    fn test_ref_ops() {
+        let m = mat3x2(1., 2., 3., 4., 5., 6.);
+        let n = mat2x3(1., 0., 2., 0., 1., 3.);
+        let v = vec3(1., 2., 3.);
+        assert_eq!(&m + &m, m + m);
+        assert_eq!(&m - &m, m - m);
+        assert_eq!(&m * &v, m * v);
+        assert_eq!(&m * &n, m * n);
+        assert_eq!(&n * &m, n * m);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let mut m = mat3x2(1., 2., 3., 4., 5., 6.);
+        let cs: Vec<Vec2> = m.columns().cloned().collect();
+        assert_eq!(cs, vec![vec2(1., 2.), vec2(3., 4.), vec2(5., 6.)]);
+        let rs: Vec<Vec3> = m.rows().collect();
+        assert_eq!(rs, vec![vec3(1., 3., 5.), vec3(2., 4., 6.)]);
+        for c in m.columns_mut() {
+            c.x = 0.;
+        }
+        assert_eq!(m, mat3x2(0., 2., 0., 4., 0., 6.));
+    }
+
+    #[test]
+    fn test_from_s() {
+        assert_eq!(Mat3x2::from_s(2.), mat3x2(2., 2., 2., 2., 2., 2.));
+        assert_eq!(Mat3::from_diag_scalar(2.), mat3(2., 0., 0., 0., 2., 0., 0., 0., 2.));
+        assert_eq!(Mat3x2::from_diag_scalar(2.), mat3x2(2., 0., 0., 2., 0., 0.));
+        assert_eq!(Mat2x4::from_diag_scalar(1.), mat2x4(1., 0., 0., 0., 0., 1., 0., 0.));
+    }
+
+    #[test]
+    fn test_isnan_isinf() {
+        let nan: f32 = Float::nan();
+        let inf: f32 = Float::infinity();
+        let m = mat3x2(1., nan, inf, 4., -inf, 6.);
+        assert_eq!(m.isnan(), [bvec2(false, true), bvec2(false, false), bvec2(false, false)]);
+        assert_eq!(m.isinf(), [bvec2(false, false), bvec2(true, false), bvec2(true, false)]);
+        assert!(!m.is_finite());
+        assert!(mat2(1., 2., 3., 4.).is_finite());
+    }
+
+    #[test]
+    fn test_index_row_col() {
+        let mut m = mat3x2(1., 2., 3., 4., 5., 6.);
+        assert_eq!(m[(0, 2)], 5.);
+        assert_eq!(m[(1, 0)], m[0][1]);
+        m[(1, 2)] = 0.;
+        assert_eq!(m.c2, vec2(5., 0.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_row_col_bound_check() {
+        let m = mat3x2(1., 2., 3., 4., 5., 6.);
+        m[(2, 0)];
+    }
+
     #[test]
     #[should_panic]
     fn test_index_bound_check() {
@@ -595,6 +1016,24 @@ mod test {
         m2[2];
     }
 
+    #[test]
+    fn test_assign_ops() {
+        let m = mat2(1., 2., 3., 4.);
+        let mut n = m;
+        n += 1.;
+        assert_eq!(n, m + 1.);
+        n -= m;
+        assert_eq!(n, mat2(1., 1., 1., 1.));
+        n *= 2.;
+        n /= mat2(1., 2., 4., 8.);
+        assert_eq!(n, mat2(2., 1., 0.5, 0.25));
+        n %= 0.5;
+        assert_eq!(n, mat2(0., 0., 0., 0.25));
+        let mut p = m;
+        p *= m;
+        assert_eq!(p, m * m);
+    }
+
     #[test]
     fn test_mul_v() {
         let m = mat3x2(1., 2., 3., 4., 5., 6.);
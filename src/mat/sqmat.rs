@@ -23,7 +23,7 @@
 
 use basenum::BaseFloat;
 use vec::vec::{ Vector2, Vector3, Vector4 };
-use super::traits::{ GenMat, GenSquareMat };
+use super::traits::GenSquareMat;
 use super::mat::*;
 use num::One;
 
@@ -59,6 +59,39 @@ impl<T: BaseFloat> GenSquareMat<T, Vector2<T>> for Matrix2<T> {
             Some(m)
         }
     }
+    #[inline]
+    fn minor(&self, i: usize, j: usize) -> T {
+        if i > 1 || j > 1 {
+            panic!("index out of range [({:?}, {:?}) > (1, 1)].", i, j)
+        }
+        self[1 - j][1 - i]
+    }
+}
+
+impl<T: BaseFloat> Matrix3<T> {
+    /// Returns the `Matrix2` obtained by removing row `i` and column `j`.
+    ///
+    /// # Panic
+    ///
+    /// It is a panic if `i` or `j` is larger than `2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    ///
+    /// let m = mat3(1., 2., 3., 4., 5., 6., 7., 8., 9.);
+    /// assert_eq!(m.submatrix(0, 1), mat2(2., 3., 8., 9.));
+    /// ```
+    #[inline]
+    pub fn submatrix(&self, i: usize, j: usize) -> Matrix2<T> {
+        match j {
+            0 => Matrix2::new(self.c1.without(i), self.c2.without(i)),
+            1 => Matrix2::new(self.c0.without(i), self.c2.without(i)),
+            2 => Matrix2::new(self.c0.without(i), self.c1.without(i)),
+            _ => panic!("parameter j is out of range [{:?} > 2].", j)
+        }
+    }
 }
 
 impl<T: BaseFloat> One for Matrix3<T> {
@@ -106,6 +139,42 @@ impl<T: BaseFloat> GenSquareMat<T, Vector3<T>> for Matrix3<T> {
             Some(m)
         }
     }
+    #[inline]
+    fn minor(&self, i: usize, j: usize) -> T {
+        self.submatrix(i, j).determinant()
+    }
+}
+
+impl<T: BaseFloat> Matrix4<T> {
+    /// Returns the `Matrix3` obtained by removing row `i` and column `j`.
+    ///
+    /// # Panic
+    ///
+    /// It is a panic if `i` or `j` is larger than `3`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    ///
+    /// let m = mat4(
+    ///     1., 2., 3., 4.,
+    ///     5., 6., 7., 8.,
+    ///     9., 10., 11., 12.,
+    ///     13., 14., 15., 16.
+    /// );
+    /// assert_eq!(m.submatrix(3, 0), mat3(5., 6., 7., 9., 10., 11., 13., 14., 15.));
+    /// ```
+    #[inline]
+    pub fn submatrix(&self, i: usize, j: usize) -> Matrix3<T> {
+        match j {
+            0 => Matrix3::new(self.c1.without(i), self.c2.without(i), self.c3.without(i)),
+            1 => Matrix3::new(self.c0.without(i), self.c2.without(i), self.c3.without(i)),
+            2 => Matrix3::new(self.c0.without(i), self.c1.without(i), self.c3.without(i)),
+            3 => Matrix3::new(self.c0.without(i), self.c1.without(i), self.c2.without(i)),
+            _ => panic!("parameter j is out of range [{:?} > 3].", j)
+        }
+    }
 }
 
 impl<T: BaseFloat> One for Matrix4<T> {
@@ -160,52 +229,12 @@ impl<T: BaseFloat> GenSquareMat<T, Vector4<T>> for Matrix4<T> {
     }
     #[inline]
     fn inverse(&self) -> Option<Matrix4<T>> {
-        let det = self.determinant();
-        let ling = T::zero();
-        if det.is_approx_eq(&ling) {
-            None
-        } else {
-            let inv_det = det.recip();
-            let tr = self.transpose();
-            let cf = |i, j| -> T {
-                let mat = match i {
-                    0 => Matrix3::new(
-                        tr.c1.truncate(j),
-                        tr.c2.truncate(j),
-                        tr.c3.truncate(j)
-                    ),
-                    1 => Matrix3::new(
-                        tr.c0.truncate(j),
-                        tr.c2.truncate(j),
-                        tr.c3.truncate(j)
-                    ),
-                    2 => Matrix3::new(
-                        tr.c0.truncate(j),
-                        tr.c1.truncate(j),
-                        tr.c3.truncate(j)
-                    ),
-                    3 => Matrix3::new(
-                        tr.c0.truncate(j),
-                        tr.c1.truncate(j),
-                        tr.c2.truncate(j)
-                    ),
-                    _ => unreachable!(),
-                };
-                let d = mat.determinant() * inv_det;
-                if (i + j) & 1 == 1 {
-                    -d
-                } else {
-                    d
-                }
-            };
-            let m = Matrix4::new(
-                Vector4::new(cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3)),
-                Vector4::new(cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3)),
-                Vector4::new(cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3)),
-                Vector4::new(cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3))
-            );
-            Some(m)
-        }
+        let m: [[T; 4]; 4] = (*self).into();
+        T::inverse_mat4(&m).map(Matrix4::from)
+    }
+    #[inline]
+    fn minor(&self, i: usize, j: usize) -> T {
+        self.submatrix(i, j).determinant()
     }
 }
 
@@ -234,6 +263,59 @@ mod test {
         assert_eq!(Mat4::one().determinant(), 1.);
     }
 
+    #[test]
+    fn test_cofactor() {
+        let m4 = mat4(
+            1., 0., 4., 0.,
+            2., 1., 2., 1.,
+            3., 2., 3., 1.,
+            4., 3., 0., 0.
+        );
+        // Laplace expansion along the first row.
+        let det = (0..4).fold(0., |d, j| d + m4[j][0] * m4.cofactor(0, j));
+        assert_eq!(det, m4.determinant());
+        let m2 = mat2(4., 5., 6., 7.);
+        assert_eq!(m2.cofactor(0, 0), 7.);
+        assert_eq!(m2.cofactor(1, 0), -6.);
+    }
+
+    #[test]
+    fn test_minor_default() {
+        use mat::traits::minor_by_expansion;
+
+        let m4 = mat4(
+            1., 0., 4., 0.,
+            2., 1., 2., 1.,
+            3., 2., 3., 1.,
+            4., 3., 0., 0.
+        );
+        let m3 = mat3(1., 4., 7., 2., 5., 8., 3., 6., 10.);
+        let m2 = mat2(4., 5., 6., 7.);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(minor_by_expansion(&m4, i, j), m4.minor(i, j));
+                if i < 3 && j < 3 {
+                    assert_eq!(minor_by_expansion(&m3, i, j), m3.minor(i, j));
+                }
+                if i < 2 && j < 2 {
+                    assert_eq!(minor_by_expansion(&m2, i, j), m2.minor(i, j));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_minor_default_bound_check() {
+        ::mat::traits::minor_by_expansion(&Mat3::one(), 3, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_minor_bound_check() {
+        Mat3::one().minor(0, 3);
+    }
+
     #[test]
     fn test_inverse_mat2() {
         let yi = Mat2::one();
@@ -22,7 +22,7 @@
 // THE SOFTWARE.
 
 use basenum::{ BaseFloat, ApproxEq };
-use vec::traits::GenFloatVec;
+use vec::traits::{ GenVec, GenFloatVec };
 use std::ops::{ Add, Sub, Neg, Mul, Div, Rem, Index, IndexMut };
 use num::{ One, Zero };
 
@@ -75,6 +75,46 @@ C: GenFloatVec<T>
     /// assert_eq!(m1.mul_c(&m2), glm::mat2(0., 0., -21., 2.));
     /// ```
     fn mul_c(&self, rhs: &Self) -> Self;
+
+    /// Applies `f` to each element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenMat;
+    ///
+    /// let m = glm::mat2(1., -2., 3., -4.);
+    /// assert_eq!(m.map(|x| x * 2.), glm::mat2(2., -4., 6., -8.));
+    /// ```
+    #[inline]
+    fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
+        let mut m = Self::zero();
+        for i in 0..Self::R::DIM {
+            m[i] = self[i].map(&f);
+        }
+        m
+    }
+
+    /// Applies `f` to each pair of corresponding elements of `self` and
+    /// `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenMat;
+    ///
+    /// let m1 = glm::mat2(1., 2., 3., 4.);
+    /// let m2 = glm::mat2(4., 3., 2., 1.);
+    /// assert_eq!(m1.zip(&m2, f32::max), glm::mat2(4., 3., 3., 4.));
+    /// ```
+    #[inline]
+    fn zip<F: Fn(T, T) -> T>(&self, rhs: &Self, f: F) -> Self {
+        let mut m = Self::zero();
+        for i in 0..Self::R::DIM {
+            m[i] = self[i].zip(rhs[i], &f);
+        }
+        m
+    }
 }
 
 /// Generic type of square matrix.
@@ -101,4 +141,121 @@ C: GenFloatVec<T>,
     /// Returns the inverse matrix of a square matrix, or `None` if the
     /// matrix is not invertible.
     fn inverse(&self) -> Option<Self>;
+
+    /// Returns the minor of element at row `i` and column `j`, i.e., the
+    /// determinant of the sub-matrix that row `i` and column `j` are removed.
+    ///
+    /// # Panic
+    ///
+    /// It is a panic if `i` or `j` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenSquareMat;
+    ///
+    /// let m = glm::mat3(1., 4., 7., 2., 5., 8., 3., 6., 10.);
+    /// assert_eq!(m.minor(0, 0), 2.);
+    /// assert_eq!(m.minor(1, 2), -6.);
+    /// ```
+    #[inline]
+    fn minor(&self, i: usize, j: usize) -> T {
+        minor_by_expansion(self, i, j)
+    }
+
+    /// Returns the cofactor of element at row `i` and column `j`, i.e.,
+    /// *(-1)<sup>i + j</sup>* times the minor.
+    ///
+    /// # Panic
+    ///
+    /// It is a panic if `i` or `j` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenSquareMat;
+    ///
+    /// let m = glm::mat3(1., 4., 7., 2., 5., 8., 3., 6., 10.);
+    /// assert_eq!(m.cofactor(1, 2), 6.);
+    /// ```
+    #[inline]
+    fn cofactor(&self, i: usize, j: usize) -> T {
+        let m = self.minor(i, j);
+        if (i + j) & 1 == 1 {
+            -m
+        } else {
+            m
+        }
+    }
 }
+
+// Minor of the element at row `i` and column `j` of `m`, by the Laplace
+// expansion of the sub-matrix. This is the default of `GenSquareMat::minor`.
+pub(crate) fn minor_by_expansion<T, C, M>(m: &M, i: usize, j: usize) -> T
+where
+    T: BaseFloat,
+    C: GenFloatVec<T>,
+    M: GenSquareMat<T, C>,
+{
+    let n = C::DIM;
+    if i >= n || j >= n {
+        panic!("index out of range [({:?}, {:?}) > ({:?}, {:?})].", i, j, n - 1, n - 1)
+    }
+    let mut a = [[T::zero(); 4]; 4];
+    for (c, k) in (0..n).filter(|&k| k != j).enumerate() {
+        for (r, l) in (0..n).filter(|&l| l != i).enumerate() {
+            a[c][r] = m[k][l];
+        }
+    }
+    det_by_expansion(&a, n - 1)
+}
+
+// Determinant of the leading `n * n` block of `a`, expanded along the first
+// column.
+fn det_by_expansion<T: BaseFloat>(a: &[[T; 4]; 4], n: usize) -> T {
+    if n == 1 {
+        return a[0][0];
+    }
+    let mut d = T::zero();
+    for r in 0..n {
+        let mut s = [[T::zero(); 4]; 4];
+        for (sc, col) in a[1..n].iter().enumerate() {
+            for (sr, l) in (0..n).filter(|&l| l != r).enumerate() {
+                s[sc][sr] = col[l];
+            }
+        }
+        let t = a[0][r] * det_by_expansion(&s, n - 1);
+        d = if r & 1 == 1 { d - t } else { d + t };
+    }
+    d
+}
+
+/// Matrix type whose scalar and column types are associated types.
+///
+/// It is implemented for the matrix types of this crate with `Scalar = T`
+/// and `Column = C` of their `GenMat<T, C>` impl. The type of row vectors is
+/// `GenMat::R`. It lets generic functions take one type parameter instead of
+/// three, e.g., `fn f<M: MatType>(c: M::Column) -> M`. See `NumType`.
+pub trait MatType
+: GenMat<<Self as MatType>::Scalar, <Self as MatType>::Column>
+{
+    /// Type of the elements.
+    type Scalar: BaseFloat;
+
+    /// Type of column vectors.
+    type Column: GenFloatVec<Self::Scalar>;
+}
+
+/// Square matrix type whose scalar and column types are associated types.
+///
+/// It is implemented for every `MatType` that is a `GenSquareMat`. See
+/// `MatType`.
+pub trait SquareMatType
+: MatType
++ GenSquareMat<<Self as MatType>::Scalar, <Self as MatType>::Column>
+{}
+
+impl<M> SquareMatType for M
+where
+    M: MatType + GenSquareMat<<M as MatType>::Scalar, <M as MatType>::Column>
+{}
@@ -0,0 +1,584 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Quaternions, which represent rotations in 3D space.
+//!
+//! This is not a GLSL type. The API follows the quaternion extensions of
+//! *GLM* (`gtc_quaternion`).
+
+use basenum::{ BaseFloat, ApproxEq };
+use builtin::{ cross, dot };
+use vec::vec::{ Vector3, Vector4 };
+use mat::mat::{ Matrix3, Matrix4 };
+use std::ops::{ Add, Sub, Mul, Div, Neg, MulAssign };
+use rand::{ Rand, Rng };
+use num::{ One, Zero };
+
+/// Quaternion `w + xi + yj + zk`.
+///
+/// The elements are stored in the order of `x`, `y`, `z`, `w`, which is the
+/// same as a `Vector4`. However, the constructors take the real part `w`
+/// first, as *GLM* does.
+///
+/// Rotations are represented by unit quaternions, and the product `p * q` is
+/// the rotation that applies `q` first and then `p`, like matrices.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "zerocopy", derive(
+    ::zerocopy::FromBytes, ::zerocopy::IntoBytes,
+    ::zerocopy::Immutable, ::zerocopy::KnownLayout
+))]
+#[cfg_attr(feature = "rkyv", derive(
+    ::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize
+))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(::bevy_reflect::Reflect))]
+pub struct Quaternion<T: BaseFloat> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: BaseFloat> Quaternion<T> {
+
+    /// Creates a quaternion from the real part `w` and the imaginary parts
+    /// `x`, `y` and `z`.
+    #[inline(always)]
+    pub fn new(w: T, x: T, y: T, z: T) -> Quaternion<T> {
+        Quaternion { x: x, y: y, z: z, w: w }
+    }
+
+    /// Creates a quaternion from the scalar (real) part `s` and the vector
+    /// (imaginary) part `v`.
+    #[inline(always)]
+    pub fn from_scalar_vector(s: T, v: Vector3<T>) -> Quaternion<T> {
+        Quaternion::new(s, v.x, v.y, v.z)
+    }
+
+    /// Returns the identity quaternion, i.e., no rotation.
+    #[inline(always)]
+    pub fn identity() -> Quaternion<T> {
+        let ling = T::zero();
+        Quaternion::new(T::one(), ling, ling, ling)
+    }
+
+    /// Creates the rotation of `angle` radians around `axis`.
+    ///
+    /// `axis` must be normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    /// use glm::ext::half_pi;
+    ///
+    /// let q = Quat::from_axis_angle(vec3(0., 0., 1.), half_pi());
+    /// assert!(is_approx_eq(&(q * vec3(1., 0., 0.)), &vec3(0., 1., 0.)));
+    /// ```
+    #[inline]
+    pub fn from_axis_angle(axis: Vector3<T>, angle: T) -> Quaternion<T> {
+        let half = angle / (T::one() + T::one());
+        Quaternion::from_scalar_vector(half.cos(), axis * half.sin())
+    }
+
+    /// Creates a rotation from Euler angles in radians, i.e., pitch
+    /// (`angles.x`), yaw (`angles.y`) and roll (`angles.z`).
+    ///
+    /// The rotations are applied in the order of `x`, `y` and `z` axes, i.e.,
+    /// the result is `Rz * Ry * Rx`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    ///
+    /// let (x, y, z) = (vec3(1., 0., 0.), vec3(0., 1., 0.), vec3(0., 0., 1.));
+    /// let q = Quat::from_euler(vec3(0.1, 0.2, 0.3));
+    /// let r =
+    ///     Quat::from_axis_angle(z, 0.3) *
+    ///     Quat::from_axis_angle(y, 0.2) *
+    ///     Quat::from_axis_angle(x, 0.1);
+    /// assert!(is_close_to(&q, &r, 1e-6));
+    /// ```
+    pub fn from_euler(angles: Vector3<T>) -> Quaternion<T> {
+        let half = angles / (T::one() + T::one());
+        let (sx, cx) = (half.x.sin(), half.x.cos());
+        let (sy, cy) = (half.y.sin(), half.y.cos());
+        let (sz, cz) = (half.z.sin(), half.z.cos());
+        Quaternion::new(
+            cx * cy * cz + sx * sy * sz,
+            sx * cy * cz - cx * sy * sz,
+            cx * sy * cz + sx * cy * sz,
+            cx * cy * sz - sx * sy * cz
+        )
+    }
+
+    /// Converts the rotation matrix `m` to a quaternion.
+    ///
+    /// `m` must be orthonormal, otherwise the result is meaningless.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    ///
+    /// let q = Quat::from_axis_angle(normalize(vec3(1., 2., 3.)), 2.);
+    /// let m: Mat3 = q.into();
+    /// assert!(is_close_to(&Quat::from_mat3(&m), &q, 1e-6));
+    /// ```
+    pub fn from_mat3(m: &Matrix3<T>) -> Quaternion<T> {
+        let (yi, two) = (T::one(), T::one() + T::one());
+        let quarter = yi / (two * two);
+        let four_w = m.c0.x + m.c1.y + m.c2.z;
+        let four_x = m.c0.x - m.c1.y - m.c2.z;
+        let four_y = m.c1.y - m.c0.x - m.c2.z;
+        let four_z = m.c2.z - m.c0.x - m.c1.y;
+        // picks the largest element to avoid dividing by small numbers.
+        let mut biggest = four_w;
+        let mut index = 0;
+        if four_x > biggest { biggest = four_x; index = 1; }
+        if four_y > biggest { biggest = four_y; index = 2; }
+        if four_z > biggest { biggest = four_z; index = 3; }
+        let val = (biggest + yi).sqrt() / two;
+        let mult = quarter / val;
+        match index {
+            0 => Quaternion::new(
+                val,
+                (m.c1.z - m.c2.y) * mult,
+                (m.c2.x - m.c0.z) * mult,
+                (m.c0.y - m.c1.x) * mult
+            ),
+            1 => Quaternion::new(
+                (m.c1.z - m.c2.y) * mult,
+                val,
+                (m.c0.y + m.c1.x) * mult,
+                (m.c2.x + m.c0.z) * mult
+            ),
+            2 => Quaternion::new(
+                (m.c2.x - m.c0.z) * mult,
+                (m.c0.y + m.c1.x) * mult,
+                val,
+                (m.c1.z + m.c2.y) * mult
+            ),
+            _ => Quaternion::new(
+                (m.c0.y - m.c1.x) * mult,
+                (m.c2.x + m.c0.z) * mult,
+                (m.c1.z + m.c2.y) * mult,
+                val
+            ),
+        }
+    }
+
+    /// Returns the scalar (real) part.
+    #[inline(always)]
+    pub fn scalar(&self) -> T {
+        self.w
+    }
+
+    /// Returns the vector (imaginary) part.
+    #[inline(always)]
+    pub fn vector(&self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// Returns the dot product of `self` and `rhs` as 4D vectors.
+    #[inline]
+    pub fn dot(&self, rhs: &Quaternion<T>) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// Returns the length (norm) of `self`.
+    #[inline]
+    pub fn length(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns `self` scaled to unit length.
+    ///
+    /// The identity quaternion is returned if `self` is zero.
+    #[inline]
+    pub fn normalize(&self) -> Quaternion<T> {
+        let len = self.length();
+        if len <= T::zero() {
+            Quaternion::identity()
+        } else {
+            *self / len
+        }
+    }
+
+    /// Returns the conjugate of `self`, i.e., `w - xi - yj - zk`.
+    ///
+    /// For unit quaternions, this is the same as the inverse.
+    #[inline]
+    pub fn conjugate(&self) -> Quaternion<T> {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Returns the multiplicative inverse of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    ///
+    /// let q = quat(1., 2., 3., 4.);
+    /// assert!(is_close_to(&(q * q.inverse()), &Quat::identity(), 1e-6));
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Quaternion<T> {
+        self.conjugate() / self.dot(self)
+    }
+
+    /// Rotates vector `v` by `self`, which must be a unit quaternion.
+    ///
+    /// This is also available as `self * v`.
+    #[inline]
+    pub fn rotate(&self, v: Vector3<T>) -> Vector3<T> {
+        let two = T::one() + T::one();
+        let u = self.vector();
+        let uv = cross(u, v);
+        let uuv = cross(u, uv);
+        v + (uv * self.w + uuv) * two
+    }
+
+    /// Returns the rotation angle in radians, in the range of `[0, 2π]`.
+    #[inline]
+    pub fn angle(&self) -> T {
+        let l = dot(self.vector(), self.vector()).sqrt();
+        l.atan2(self.w) * (T::one() + T::one())
+    }
+
+    /// Returns the normalized rotation axis.
+    ///
+    /// The `z` axis is returned if `self` is the identity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    ///
+    /// let axis = normalize(vec3(1., 2., 3.));
+    /// let q = Quat::from_axis_angle(axis, 1.5);
+    /// assert!(is_close_to(&q.axis(), &axis, 1e-6));
+    /// assert!(is_close_to(&q.angle(), &1.5, 1e-6));
+    /// ```
+    #[inline]
+    pub fn axis(&self) -> Vector3<T> {
+        let v = self.vector();
+        let l = dot(v, v).sqrt();
+        if l <= T::zero() {
+            let ling = T::zero();
+            Vector3::new(ling, ling, T::one())
+        } else {
+            v / l
+        }
+    }
+
+    /// Spherical linear interpolation between `self` and `rhs`, which must
+    /// be unit quaternions.
+    ///
+    /// The shortest path is taken, i.e., `rhs` is negated if the angle
+    /// between `self` and `rhs` is larger than `π / 2` in 4D.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::*;
+    ///
+    /// let z = vec3(0., 0., 1.);
+    /// let (a, b) = (Quat::from_axis_angle(z, 0.2), Quat::from_axis_angle(z, 1.));
+    /// assert!(is_close_to(&a.slerp(&b, 0.5), &Quat::from_axis_angle(z, 0.6), 1e-6));
+    /// ```
+    pub fn slerp(&self, rhs: &Quaternion<T>, t: T) -> Quaternion<T> {
+        let yi = T::one();
+        let mut c = self.dot(rhs);
+        let mut b = *rhs;
+        if c < T::zero() {
+            b = -b;
+            c = -c;
+        }
+        if c > yi - T::epsilon() {
+            // `sin(angle)` is close to zero, falls back to linear
+            // interpolation.
+            return self.nlerp(&b, t);
+        }
+        let angle = c.acos();
+        (*self * ((yi - t) * angle).sin() + b * (t * angle).sin()) / angle.sin()
+    }
+
+    /// Normalized linear interpolation between `self` and `rhs`.
+    ///
+    /// Faster than `slerp`, but the angular velocity is not constant.
+    #[inline]
+    pub fn nlerp(&self, rhs: &Quaternion<T>, t: T) -> Quaternion<T> {
+        (*self * (T::one() - t) + *rhs * t).normalize()
+    }
+
+    /// Converts `self`, which must be a unit quaternion, to a rotation
+    /// matrix.
+    pub fn to_mat3(&self) -> Matrix3<T> {
+        let (yi, two) = (T::one(), T::one() + T::one());
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+        Matrix3::new(
+            Vector3::new(yi - two * (yy + zz), two * (xy + wz), two * (xz - wy)),
+            Vector3::new(two * (xy - wz), yi - two * (xx + zz), two * (yz + wx)),
+            Vector3::new(two * (xz + wy), two * (yz - wx), yi - two * (xx + yy))
+        )
+    }
+
+    /// Converts `self`, which must be a unit quaternion, to a homogeneous
+    /// rotation matrix.
+    #[inline]
+    pub fn to_mat4(&self) -> Matrix4<T> {
+        let (ling, yi) = (T::zero(), T::one());
+        let m = self.to_mat3();
+        Matrix4::new(
+            m.c0.extend(ling),
+            m.c1.extend(ling),
+            m.c2.extend(ling),
+            Vector4::new(ling, ling, ling, yi)
+        )
+    }
+}
+
+impl<T: BaseFloat> From<Quaternion<T>> for Matrix3<T> {
+    #[inline(always)]
+    fn from(q: Quaternion<T>) -> Matrix3<T> {
+        q.to_mat3()
+    }
+}
+
+impl<T: BaseFloat> From<Quaternion<T>> for Matrix4<T> {
+    #[inline(always)]
+    fn from(q: Quaternion<T>) -> Matrix4<T> {
+        q.to_mat4()
+    }
+}
+
+impl<T: BaseFloat> From<Matrix3<T>> for Quaternion<T> {
+    #[inline(always)]
+    fn from(m: Matrix3<T>) -> Quaternion<T> {
+        Quaternion::from_mat3(&m)
+    }
+}
+
+impl<T: BaseFloat> Rand for Quaternion<T> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Quaternion<T> {
+        Quaternion { x: rng.gen(), y: rng.gen(), z: rng.gen(), w: rng.gen() }
+    }
+}
+
+impl<T: BaseFloat> ApproxEq for Quaternion<T> {
+    type BaseType = T;
+    #[inline]
+    fn is_close_to(&self, rhs: &Quaternion<T>, max_diff: T) -> bool {
+        self.x.is_close_to(&rhs.x, max_diff) &&
+        self.y.is_close_to(&rhs.y, max_diff) &&
+        self.z.is_close_to(&rhs.z, max_diff) &&
+        self.w.is_close_to(&rhs.w, max_diff)
+    }
+}
+
+impl<T: BaseFloat> Zero for Quaternion<T> {
+    #[inline(always)]
+    fn zero() -> Quaternion<T> {
+        let ling = T::zero();
+        Quaternion::new(ling, ling, ling, ling)
+    }
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero() && self.z.is_zero() && self.w.is_zero()
+    }
+}
+
+impl<T: BaseFloat> One for Quaternion<T> {
+    #[inline(always)]
+    fn one() -> Quaternion<T> {
+        Quaternion::identity()
+    }
+}
+
+impl<T: BaseFloat> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+    #[inline(always)]
+    fn neg(self) -> Quaternion<T> {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: BaseFloat> Add<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    #[inline(always)]
+    fn add(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(
+            self.w + rhs.w, self.x + rhs.x, self.y + rhs.y, self.z + rhs.z
+        )
+    }
+}
+
+impl<T: BaseFloat> Sub<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    #[inline(always)]
+    fn sub(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(
+            self.w - rhs.w, self.x - rhs.x, self.y - rhs.y, self.z - rhs.z
+        )
+    }
+}
+
+impl<T: BaseFloat> Mul<T> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    #[inline(always)]
+    fn mul(self, rhs: T) -> Quaternion<T> {
+        Quaternion::new(self.w * rhs, self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: BaseFloat> Div<T> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    #[inline(always)]
+    fn div(self, rhs: T) -> Quaternion<T> {
+        Quaternion::new(self.w / rhs, self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+/// Hamilton product.
+impl<T: BaseFloat> Mul<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    #[inline]
+    fn mul(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        let (p, q) = (self, rhs);
+        Quaternion::new(
+            p.w * q.w - p.x * q.x - p.y * q.y - p.z * q.z,
+            p.w * q.x + p.x * q.w + p.y * q.z - p.z * q.y,
+            p.w * q.y + p.y * q.w + p.z * q.x - p.x * q.z,
+            p.w * q.z + p.z * q.w + p.x * q.y - p.y * q.x
+        )
+    }
+}
+
+impl<T: BaseFloat> MulAssign<Quaternion<T>> for Quaternion<T> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Quaternion<T>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: BaseFloat> Mul<Vector3<T>> for Quaternion<T> {
+    type Output = Vector3<T>;
+    #[inline(always)]
+    fn mul(self, rhs: Vector3<T>) -> Vector3<T> {
+        self.rotate(rhs)
+    }
+}
+
+pub type Quat = Quaternion<f32>;
+pub type DQuat = Quaternion<f64>;
+
+/// Creates a `Quat` from the real part `w` and the imaginary parts `x`, `y`
+/// and `z`.
+#[inline(always)]
+pub fn quat(w: f32, x: f32, y: f32, z: f32) -> Quat {
+    Quaternion::new(w, x, y, z)
+}
+
+/// Creates a `DQuat` from the real part `w` and the imaginary parts `x`, `y`
+/// and `z`.
+#[inline(always)]
+pub fn dquat(w: f64, x: f64, y: f64, z: f64) -> DQuat {
+    Quaternion::new(w, x, y, z)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use basenum::{ is_close_to, ApproxEq };
+    use builtin::normalize;
+    use vec::vec::{ vec3, dvec3 };
+    use mat::ctor::mat3;
+    use mat::mat::Mat3;
+
+    #[test]
+    fn test_hamilton_product() {
+        let (i, j, k) = (quat(0., 1., 0., 0.), quat(0., 0., 1., 0.), quat(0., 0., 0., 1.));
+        let minus_one = quat(-1., 0., 0., 0.);
+        assert_eq!(i * i, minus_one);
+        assert_eq!(j * j, minus_one);
+        assert_eq!(k * k, minus_one);
+        assert_eq!(i * j * k, minus_one);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let q = Quat::from_axis_angle(vec3(0., 1., 0.), ::std::f32::consts::FRAC_PI_2);
+        assert_close_to!(q * vec3(1., 0., 0.), vec3(0., 0., -1.), 1e-6);
+        assert_close_to!(q.to_mat3() * vec3(1., 2., 3.), q * vec3(1., 2., 3.), 1e-6);
+        let m: Mat3 = q.into();
+        assert_close_to!(m, mat3(0., 0., -1., 0., 1., 0., 1., 0., 0.), 1e-6);
+    }
+
+    #[test]
+    fn test_compose() {
+        let a = DQuat::from_axis_angle(normalize(dvec3(1., 2., 3.)), 0.7);
+        let b = DQuat::from_axis_angle(normalize(dvec3(-3., 1., 0.)), 2.1);
+        let v = dvec3(4., -5., 6.);
+        assert_close_to!((a * b) * v, a * (b * v), 1e-12);
+        assert_close_to!((a * b).to_mat3(), a.to_mat3() * b.to_mat3(), 1e-12);
+        assert_close_to!(a.inverse() * (a * v), v, 1e-12);
+    }
+
+    #[test]
+    fn test_from_mat3() {
+        // covers all branches of picking the largest element.
+        for &(axis, angle) in [
+            (vec3(0., 0., 1.), 0.3),
+            (vec3(1., 0., 0.), 3.),
+            (vec3(0., 1., 0.), 3.),
+            (vec3(0., 0., 1.), 3.),
+        ].iter() {
+            let q = Quat::from_axis_angle(axis, angle);
+            let r = Quat::from_mat3(&q.to_mat3());
+            assert!(r.is_close_to(&q, 1e-6) || r.is_close_to(&-q, 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_slerp() {
+        let z = vec3(0., 0., 1.);
+        let a = Quat::from_axis_angle(z, 0.1);
+        let b = Quat::from_axis_angle(z, 0.5);
+        assert_close_to!(a.slerp(&b, 0.), a, 1e-6);
+        assert_close_to!(a.slerp(&b, 1.), b, 1e-6);
+        // shortest path.
+        assert_close_to!(a.slerp(&-b, 0.25), Quat::from_axis_angle(z, 0.2), 1e-6);
+        assert_close_to!(a.slerp(&a, 0.5), a, 1e-6);
+    }
+}
@@ -0,0 +1,274 @@
+//
+// GLSL Mathematics for Rust.
+//
+// Copyright (c) 2015 The glm-rs authors.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+
+// Kernels of 4-component vectors and 4x4 matrices, on arrays of scalars.
+//
+// `scalar` is the default implementation for all float types. With feature
+// `simd`, `f32` uses `sse` instead. Both evaluate the same expressions in
+// the same order wherever the instruction set allows it, so that results
+// only differ in rounding of horizontal sums.
+//
+// The SSE kernels load and store with the unaligned `loadu`/`storeu`. Vector
+// and matrix types keep the layout of plain arrays, so they are only 4 or 8
+// byte aligned, and on current CPUs the unaligned instructions cost the same
+// as the aligned ones on aligned data. Each operand is loaded once per call
+// and all intermediate values stay in registers, e.g., `mul_mat4` keeps the
+// four columns of `l` in registers for all columns of the product.
+
+pub mod scalar {
+
+    use basenum::BaseFloat;
+
+    #[inline(always)]
+    pub fn dot4<T: BaseFloat>(x: &[T; 4], y: &[T; 4]) -> T {
+        x[0] * y[0] + x[1] * y[1] + x[2] * y[2] + x[3] * y[3]
+    }
+
+    #[inline(always)]
+    pub fn mul_mat4_vec4<T: BaseFloat>(m: &[[T; 4]; 4], v: &[T; 4]) -> [T; 4] {
+        let row = |i: usize| {
+            m[0][i] * v[0] + m[1][i] * v[1] + m[2][i] * v[2] + m[3][i] * v[3]
+        };
+        [row(0), row(1), row(2), row(3)]
+    }
+
+    #[inline(always)]
+    pub fn mul_mat4<T: BaseFloat>(l: &[[T; 4]; 4], r: &[[T; 4]; 4]) -> [[T; 4]; 4] {
+        [
+            mul_mat4_vec4(l, &r[0]),
+            mul_mat4_vec4(l, &r[1]),
+            mul_mat4_vec4(l, &r[2]),
+            mul_mat4_vec4(l, &r[3]),
+        ]
+    }
+
+    // NOTE: this is the algorithm of GLM, which computes the adjugate from
+    //       2x2 sub-determinants, four at a time.
+    pub fn inverse_mat4<T: BaseFloat>(m: &[[T; 4]; 4]) -> Option<[[T; 4]; 4]> {
+        // sub-determinants of rows `i` and `j`.
+        let fac = |i: usize, j: usize| {
+            let d = |a: usize, b: usize| m[a][i] * m[b][j] - m[b][i] * m[a][j];
+            [d(2, 3), d(2, 3), d(1, 3), d(1, 2)]
+        };
+        let vec = |i: usize| [m[1][i], m[0][i], m[0][i], m[0][i]];
+        // `(a * fa - b * fb + c * fc) * (s, -s, s, -s)`.
+        let col = |a: [T; 4], fa: [T; 4], b: [T; 4], fb: [T; 4], c: [T; 4], fc: [T; 4], s: T| {
+            let sign = [s, -s, s, -s];
+            let e = |k: usize| (a[k] * fa[k] - b[k] * fb[k] + c[k] * fc[k]) * sign[k];
+            [e(0), e(1), e(2), e(3)]
+        };
+        let (f0, f1, f2) = (fac(2, 3), fac(1, 3), fac(1, 2));
+        let (f3, f4, f5) = (fac(0, 3), fac(0, 2), fac(0, 1));
+        let (v0, v1, v2, v3) = (vec(0), vec(1), vec(2), vec(3));
+        let yi = T::one();
+        let adj = [
+            col(v1, f0, v2, f1, v3, f2, yi),
+            col(v0, f0, v2, f3, v3, f4, -yi),
+            col(v0, f1, v1, f3, v3, f5, yi),
+            col(v0, f2, v1, f4, v2, f5, -yi),
+        ];
+        let det =
+            (m[0][0] * adj[0][0] + m[1][0] * adj[0][1]) +
+            (m[2][0] * adj[0][2] + m[3][0] * adj[0][3]);
+        if det.is_approx_eq(&T::zero()) {
+            None
+        } else {
+            let inv_det = det.recip();
+            let scale = |c: [T; 4]| {
+                [c[0] * inv_det, c[1] * inv_det, c[2] * inv_det, c[3] * inv_det]
+            };
+            Some([scale(adj[0]), scale(adj[1]), scale(adj[2]), scale(adj[3])])
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "simd",
+    any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse"))
+))]
+pub mod sse {
+
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+    use basenum::ApproxEq;
+
+    // `_MM_SHUFFLE`, which is not a stable const fn.
+    const fn shuffle(z: i32, y: i32, x: i32, w: i32) -> i32 {
+        (z << 6) | (y << 4) | (x << 2) | w
+    }
+
+    #[inline(always)]
+    fn load(a: &[f32; 4]) -> __m128 {
+        unsafe { _mm_loadu_ps(a.as_ptr()) }
+    }
+
+    #[inline(always)]
+    fn store(v: __m128) -> [f32; 4] {
+        let mut a = [0.; 4];
+        unsafe { _mm_storeu_ps(a.as_mut_ptr(), v) };
+        a
+    }
+
+    #[inline(always)]
+    pub fn dot4(x: &[f32; 4], y: &[f32; 4]) -> f32 {
+        unsafe {
+            let p = _mm_mul_ps(load(x), load(y));
+            // (p0 + p1, p1 + p0, p2 + p3, p3 + p2)
+            let s = _mm_add_ps(p, _mm_shuffle_ps::<{ shuffle(2, 3, 0, 1) }>(p, p));
+            _mm_cvtss_f32(_mm_add_ss(s, _mm_movehl_ps(s, s)))
+        }
+    }
+
+    #[inline(always)]
+    pub fn mul_mat4_vec4(m: &[[f32; 4]; 4], v: &[f32; 4]) -> [f32; 4] {
+        unsafe {
+            let r = _mm_mul_ps(load(&m[0]), _mm_set1_ps(v[0]));
+            let r = _mm_add_ps(r, _mm_mul_ps(load(&m[1]), _mm_set1_ps(v[1])));
+            let r = _mm_add_ps(r, _mm_mul_ps(load(&m[2]), _mm_set1_ps(v[2])));
+            let r = _mm_add_ps(r, _mm_mul_ps(load(&m[3]), _mm_set1_ps(v[3])));
+            store(r)
+        }
+    }
+
+    // Same as `mul_mat4_vec4` for each column of `r`, but the columns of `l`
+    // are loaded only once for all four.
+    #[inline(always)]
+    pub fn mul_mat4(l: &[[f32; 4]; 4], r: &[[f32; 4]; 4]) -> [[f32; 4]; 4] {
+        unsafe {
+            let (c0, c1, c2, c3) = (load(&l[0]), load(&l[1]), load(&l[2]), load(&l[3]));
+            let col = |v: &[f32; 4]| {
+                let p = _mm_mul_ps(c0, _mm_set1_ps(v[0]));
+                let p = _mm_add_ps(p, _mm_mul_ps(c1, _mm_set1_ps(v[1])));
+                let p = _mm_add_ps(p, _mm_mul_ps(c2, _mm_set1_ps(v[2])));
+                let p = _mm_add_ps(p, _mm_mul_ps(c3, _mm_set1_ps(v[3])));
+                store(p)
+            };
+            [col(&r[0]), col(&r[1]), col(&r[2]), col(&r[3])]
+        }
+    }
+
+    // sub-determinants of rows `i` and `j`, see `scalar::inverse_mat4`.
+    macro_rules! fac {
+        ($c1: ident, $c2: ident, $c3: ident, $i: expr, $j: expr) => ({
+            let a = _mm_shuffle_ps::<{ shuffle($j, $j, $j, $j) }>($c3, $c2);
+            let b = _mm_shuffle_ps::<{ shuffle($i, $i, $i, $i) }>($c3, $c2);
+            let s0 = _mm_shuffle_ps::<{ shuffle($i, $i, $i, $i) }>($c2, $c1);
+            let s1 = _mm_shuffle_ps::<{ shuffle(2, 0, 0, 0) }>(a, a);
+            let s2 = _mm_shuffle_ps::<{ shuffle(2, 0, 0, 0) }>(b, b);
+            let s3 = _mm_shuffle_ps::<{ shuffle($j, $j, $j, $j) }>($c2, $c1);
+            _mm_sub_ps(_mm_mul_ps(s0, s1), _mm_mul_ps(s2, s3))
+        })
+    }
+
+    // `(c1[i], c0[i], c0[i], c0[i])`.
+    macro_rules! spread {
+        ($c0: ident, $c1: ident, $i: expr) => ({
+            let t = _mm_shuffle_ps::<{ shuffle($i, $i, $i, $i) }>($c1, $c0);
+            _mm_shuffle_ps::<{ shuffle(2, 2, 2, 0) }>(t, t)
+        })
+    }
+
+    pub fn inverse_mat4(m: &[[f32; 4]; 4]) -> Option<[[f32; 4]; 4]> {
+        unsafe {
+            let (c0, c1, c2, c3) = (load(&m[0]), load(&m[1]), load(&m[2]), load(&m[3]));
+            let (f0, f1, f2) = (fac!(c1, c2, c3, 2, 3), fac!(c1, c2, c3, 1, 3), fac!(c1, c2, c3, 1, 2));
+            let (f3, f4, f5) = (fac!(c1, c2, c3, 0, 3), fac!(c1, c2, c3, 0, 2), fac!(c1, c2, c3, 0, 1));
+            let (v0, v1) = (spread!(c0, c1, 0), spread!(c0, c1, 1));
+            let (v2, v3) = (spread!(c0, c1, 2), spread!(c0, c1, 3));
+            let col = |a, fa, b, fb, c, fc, sign| {
+                let r = _mm_sub_ps(_mm_mul_ps(a, fa), _mm_mul_ps(b, fb));
+                _mm_mul_ps(_mm_add_ps(r, _mm_mul_ps(c, fc)), sign)
+            };
+            let sign_a = _mm_set_ps(-1., 1., -1., 1.);
+            let sign_b = _mm_set_ps(1., -1., 1., -1.);
+            let a0 = col(v1, f0, v2, f1, v3, f2, sign_a);
+            let a1 = col(v0, f0, v2, f3, v3, f4, sign_b);
+            let a2 = col(v0, f1, v1, f3, v3, f5, sign_a);
+            let a3 = col(v0, f2, v1, f4, v2, f5, sign_b);
+            // first row of `m`.
+            let r0 = _mm_shuffle_ps::<{ shuffle(0, 0, 0, 0) }>(c0, c1);
+            let r1 = _mm_shuffle_ps::<{ shuffle(0, 0, 0, 0) }>(c2, c3);
+            let row = _mm_shuffle_ps::<{ shuffle(2, 0, 2, 0) }>(r0, r1);
+            let d = store(_mm_mul_ps(row, a0));
+            let det = (d[0] + d[1]) + (d[2] + d[3]);
+            if det.is_approx_eq(&0.) {
+                None
+            } else {
+                let inv_det = _mm_set1_ps(det.recip());
+                Some([
+                    store(_mm_mul_ps(a0, inv_det)),
+                    store(_mm_mul_ps(a1, inv_det)),
+                    store(_mm_mul_ps(a2, inv_det)),
+                    store(_mm_mul_ps(a3, inv_det)),
+                ])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::scalar;
+    use basenum::*;
+
+    const M: [[f32; 4]; 4] = [
+        [1., 0., 4., 0.],
+        [2., 1., 2., 1.],
+        [3., 2., 3., 1.],
+        [4., 3., 0., 0.],
+    ];
+
+    #[test]
+    fn test_scalar() {
+        let v = [1., -1., 2., 0.5];
+        assert_eq!(scalar::dot4(&v, &v), 6.25);
+        assert_eq!(scalar::mul_mat4_vec4(&M, &[1., 0., 0., 0.]), M[0]);
+        assert_eq!(scalar::mul_mat4_vec4(&M, &v), [7., 4.5, 8., 1.]);
+        let id = [[1., 0., 0., 0.], [0., 1., 0., 0.], [0., 0., 1., 0.], [0., 0., 0., 1.]];
+        assert_eq!(scalar::mul_mat4(&M, &id), M);
+        assert_eq!(scalar::mul_mat4(&M, &[v; 4]), [[7., 4.5, 8., 1.]; 4]);
+        let inv = scalar::inverse_mat4(&M).unwrap();
+        assert_close_to!(inv[0][1], 12. / 7., 1e-6);
+        assert!(scalar::inverse_mat4(&[[1., 2., 3., 4.]; 4]).is_none());
+    }
+
+    #[cfg(all(
+        feature = "simd",
+        any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse"))
+    ))]
+    #[test]
+    fn test_sse() {
+        use super::sse;
+
+        let v = [1., -1., 2., 0.5];
+        assert_eq!(sse::dot4(&v, &M[1]), scalar::dot4(&v, &M[1]));
+        assert_eq!(sse::mul_mat4_vec4(&M, &v), scalar::mul_mat4_vec4(&M, &v));
+        assert_eq!(sse::mul_mat4(&M, &M), scalar::mul_mat4(&M, &M));
+        assert_eq!(sse::inverse_mat4(&M), scalar::inverse_mat4(&M));
+        assert!(sse::inverse_mat4(&[[1., 2., 3., 4.]; 4]).is_none());
+    }
+}
@@ -24,9 +24,7 @@
 use basenum::{ BaseNum, BaseInt, BaseFloat, SignedNum, ApproxEq };
 use std::ops::{ Add, Mul, Sub, Div, Rem, Not, BitAnd, BitOr, BitXor, Shl, Shr };
 use rand::Rand;
-use num::{ Float, One, Zero };
-
-// TODO: use associated types to reduce redundant type parameters.
+use num::{ One, Zero };
 
 /// Generic numeric type.
 pub trait GenNum<E: BaseNum>
@@ -53,8 +51,40 @@ pub trait GenNum<E: BaseNum>
     fn map2<F>(self, y: Self, f: F) -> (Self, Self) where F: Fn(E, E) -> (E, E);
 }
 
+/// Generic numeric type whose scalar type is an associated type.
+///
+/// It lets generic functions take one type parameter instead of two, e.g.,
+/// `fn f<T: NumType>(x: T, s: T::Scalar)` instead of
+/// `fn f<S: BaseNum, T: GenNum<S>>(x: T, s: S)`.
+///
+/// # Note
+///
+/// `GenNum`, `GenFloat` and friends keep their type parameters, so bounds
+/// on them in user code still compile. But the built-in functions that take
+/// a vector and a scalar (e.g., `min_s`, `clamp_s` and `mix_s`) and the
+/// matrix functions (e.g., `outerProduct` and `inverse`) are now generic
+/// over these traits, with fewer type parameters. Calls that name the type
+/// parameters explicitly, e.g., `min_s::<f32, Vec3>(v, 0.)`, must drop the
+/// scalar type, i.e., `min_s::<Vec3>(v, 0.)`.
+///
+/// The associated-type traits are views of the same types, not a second
+/// set of implementations:
+///
+/// - `NumType` and `MatType` are the roots. They are implemented explicitly
+///   next to `GenNum` and `GenMat`, since a blanket impl over `GenNum<E>`
+///   would leave `E` unconstrained.
+/// - `FloatType`, `NumVecType`, `FloatVecType` and `SquareMatType` have
+///   blanket impls derived from the roots, so they never need implementing.
+pub trait NumType: GenNum<<Self as NumType>::Scalar> {
+    /// Type of the components.
+    type Scalar: BaseNum;
+}
+
 macro_rules! impl_GenNum_for_scalar(
     ($t: ty) => {
+        impl NumType for $t {
+            type Scalar = $t;
+        }
         impl GenNum<$t> for $t {
             #[inline(always)]
             fn from_s(x: $t) -> Self {
@@ -125,6 +155,23 @@ pub trait GenFloat<F: BaseFloat>
     fn fma(&self, b: &Self, c: &Self) -> Self;
 }
 
+/// Generic float number type whose scalar type is an associated type.
+///
+/// It is implemented for every `NumType` that is a `GenFloat` of its scalar
+/// type. See `NumType`.
+pub trait FloatType: GenFloat<<Self as FloatType>::Scalar> {
+    /// Type of the components.
+    type Scalar: BaseFloat;
+}
+
+impl<T> FloatType for T
+where
+    T: NumType + GenFloat<<T as NumType>::Scalar>,
+    <T as NumType>::Scalar: BaseFloat,
+{
+    type Scalar = <T as NumType>::Scalar;
+}
+
 pub trait GenType: GenFloat<f32> {}
 pub trait GenDType: GenFloat<f64> {}
 
@@ -133,7 +180,7 @@ macro_rules! impl_GenFloat_for_scalar(
         impl_GenNum_for_scalar! { $t }
         impl GenFloat<$t> for $t {
             fn fma(&self, b: &$t, c: &$t) -> $t {
-                Float::mul_add(*self, *b, *c)
+                BaseFloat::fused_mul_add(*self, *b, *c)
             }
         }
         impl $gt for $t {}
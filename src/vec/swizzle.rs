@@ -24,77 +24,1326 @@
 use basenum::Primitive;
 use super::vec::{ Vector2, Vector3, Vector4 };
 
-macro_rules! def_swizzle2 {
-    (
-        {$($f1: ident, $field1: ident),+},
-        {$($f2: ident, {$($field2: ident),+}),+}
-    ) => {
-        pub trait Swizzle2<T: Primitive> {
-            fn x(&self) -> T;
-            fn y(&self) -> T;
-            $(
-                #[inline(always)]
-                fn $f1(&self) -> T { self.$field1() }
-            )+
-            $(
-                #[inline(always)]
-                fn $f2(&self) -> Vector2<T> {
-                    Vector2::new($(self.$field2()),+)
-                }
-            )+
-        }
+// Defines single component accessors that are aliases of `x`, `y`, `z` or `w`.
+macro_rules! swizzle_aliases {
+    ($($f: ident: $field: ident),+) => {
+        $(
+            #[inline(always)]
+            fn $f(&self) -> T { self.$field() }
+        )+
     }
 }
 
-macro_rules! gen_swizzle2 {
-    (
-        {$($fx: ident),+},
-        {$($fy: ident),+},
-        {
-            $({$($f2x: ident),+}, {$($f2y: ident),+}),+
-        }
-    ) => {
-        def_swizzle2! {
-            { $($fx, x),+, $($fy, y),+ },
-            {
-                $(
-                    $(
-                        concat_idents!($f2x, $f2y), { $f2x, $f2y }
-                    ),+
-                )+
+// Defines swizzle operators that return new vectors, e.g.,
+// `zyx: Vector3(z, y, x)` defines `fn zyx(&self) -> Vector3<T>`.
+macro_rules! swizzle_fns {
+    ($($f: ident: $v: ident($($field: ident),+)),+) => {
+        $(
+            #[inline(always)]
+            fn $f(&self) -> $v<T> {
+                $v::new($(self.$field()),+)
             }
-        }
+        )+
     }
 }
 
-gen_swizzle2! {
-    { r, u },
-    { g, v },
-    {
-        { x, y }, { x, y },
-        { r, g }, { r, g },
-        { u, v }, { u, v }
+// Defines swizzle setters that assign components from a vector, e.g.,
+// `set_zx: Vector2(set_z: x, set_x: y)` defines
+// `fn set_zx(&mut self, v: Vector2<T>)`, which sets `z` to `v.x` and
+// `x` to `v.y`.
+macro_rules! swizzle_setters {
+    ($($f: ident: $v: ident($($set: ident: $field: ident),+)),+) => {
+        $(
+            #[inline(always)]
+            fn $f(&mut self, v: $v<T>) {
+                $(self.$set(v.$field);)+
+            }
+        )+
     }
 }
 
-macro_rules! def_swizzle3 {
-    () => {
-        pub trait Swizzle3<T: Primitive>: Swizzle2<T> {
-            fn z(&self) -> T;
-        }
+/// Swizzle operators of vectors that have at least two components.
+///
+/// Besides `x` and `y`, components can also be named `r` and `g` (colors),
+/// `s` and `t` (texture coordinates), or `u` and `v`. Names from different
+/// sets can not be mixed in one swizzle operator, as in GLSL.
+///
+/// Setters, e.g., `set_yx`, assign distinct components at once and are the
+/// counterparts of GLSL expressions like `v.yx = u.xx`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+///
+/// let v = vec2(1., 2.);
+/// assert_eq!(v.yx(), vec2(2., 1.));
+/// assert_eq!(v.ggr(), vec3(2., 2., 1.));
+/// assert_eq!(v.stst(), vec4(1., 2., 1., 2.));
+///
+/// let mut u = v;
+/// u.set_yx(v.xx());
+/// assert_eq!(u, vec2(1., 1.));
+/// ```
+pub trait Swizzle2<T: Primitive> {
+    fn x(&self) -> T;
+    fn y(&self) -> T;
+    fn set_x(&mut self, x: T);
+    fn set_y(&mut self, y: T);
+    swizzle_aliases! { r: x, g: y, s: x, t: y, u: x, v: y }
+    swizzle_fns! {
+        uu: Vector2(x, x),
+        uv: Vector2(x, y),
+        vu: Vector2(y, x),
+        vv: Vector2(y, y),
+        xx: Vector2(x, x),
+        xy: Vector2(x, y),
+        yx: Vector2(y, x),
+        yy: Vector2(y, y),
+        rr: Vector2(x, x),
+        rg: Vector2(x, y),
+        gr: Vector2(y, x),
+        gg: Vector2(y, y),
+        ss: Vector2(x, x),
+        st: Vector2(x, y),
+        ts: Vector2(y, x),
+        tt: Vector2(y, y),
+        xxx: Vector3(x, x, x),
+        xxy: Vector3(x, x, y),
+        xyx: Vector3(x, y, x),
+        xyy: Vector3(x, y, y),
+        yxx: Vector3(y, x, x),
+        yxy: Vector3(y, x, y),
+        yyx: Vector3(y, y, x),
+        yyy: Vector3(y, y, y),
+        rrr: Vector3(x, x, x),
+        rrg: Vector3(x, x, y),
+        rgr: Vector3(x, y, x),
+        rgg: Vector3(x, y, y),
+        grr: Vector3(y, x, x),
+        grg: Vector3(y, x, y),
+        ggr: Vector3(y, y, x),
+        ggg: Vector3(y, y, y),
+        sss: Vector3(x, x, x),
+        sst: Vector3(x, x, y),
+        sts: Vector3(x, y, x),
+        stt: Vector3(x, y, y),
+        tss: Vector3(y, x, x),
+        tst: Vector3(y, x, y),
+        tts: Vector3(y, y, x),
+        ttt: Vector3(y, y, y),
+        xxxx: Vector4(x, x, x, x),
+        xxxy: Vector4(x, x, x, y),
+        xxyx: Vector4(x, x, y, x),
+        xxyy: Vector4(x, x, y, y),
+        xyxx: Vector4(x, y, x, x),
+        xyxy: Vector4(x, y, x, y),
+        xyyx: Vector4(x, y, y, x),
+        xyyy: Vector4(x, y, y, y),
+        yxxx: Vector4(y, x, x, x),
+        yxxy: Vector4(y, x, x, y),
+        yxyx: Vector4(y, x, y, x),
+        yxyy: Vector4(y, x, y, y),
+        yyxx: Vector4(y, y, x, x),
+        yyxy: Vector4(y, y, x, y),
+        yyyx: Vector4(y, y, y, x),
+        yyyy: Vector4(y, y, y, y),
+        rrrr: Vector4(x, x, x, x),
+        rrrg: Vector4(x, x, x, y),
+        rrgr: Vector4(x, x, y, x),
+        rrgg: Vector4(x, x, y, y),
+        rgrr: Vector4(x, y, x, x),
+        rgrg: Vector4(x, y, x, y),
+        rggr: Vector4(x, y, y, x),
+        rggg: Vector4(x, y, y, y),
+        grrr: Vector4(y, x, x, x),
+        grrg: Vector4(y, x, x, y),
+        grgr: Vector4(y, x, y, x),
+        grgg: Vector4(y, x, y, y),
+        ggrr: Vector4(y, y, x, x),
+        ggrg: Vector4(y, y, x, y),
+        gggr: Vector4(y, y, y, x),
+        gggg: Vector4(y, y, y, y),
+        ssss: Vector4(x, x, x, x),
+        ssst: Vector4(x, x, x, y),
+        ssts: Vector4(x, x, y, x),
+        sstt: Vector4(x, x, y, y),
+        stss: Vector4(x, y, x, x),
+        stst: Vector4(x, y, x, y),
+        stts: Vector4(x, y, y, x),
+        sttt: Vector4(x, y, y, y),
+        tsss: Vector4(y, x, x, x),
+        tsst: Vector4(y, x, x, y),
+        tsts: Vector4(y, x, y, x),
+        tstt: Vector4(y, x, y, y),
+        ttss: Vector4(y, y, x, x),
+        ttst: Vector4(y, y, x, y),
+        ttts: Vector4(y, y, y, x),
+        tttt: Vector4(y, y, y, y)
+    }
+    swizzle_setters! {
+        set_xy: Vector2(set_x: x, set_y: y),
+        set_yx: Vector2(set_y: x, set_x: y),
+        set_rg: Vector2(set_x: x, set_y: y),
+        set_gr: Vector2(set_y: x, set_x: y),
+        set_st: Vector2(set_x: x, set_y: y),
+        set_ts: Vector2(set_y: x, set_x: y)
     }
 }
 
-macro_rules! def_swizzle4 {
-    () => {
-        pub trait Swizzle4<T: Primitive>: Swizzle3<T> {
-            fn w(&self) -> T;
-        }
+/// Swizzle operators of vectors that have at least three components.
+///
+/// The third component is also named `b` or `p`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+///
+/// let v = ivec3(1, 2, 3);
+/// assert_eq!(v.zyx(), ivec3(3, 2, 1));
+/// assert_eq!(v.bgr(), v.zyx());
+/// assert_eq!(v.xzzy(), ivec4(1, 3, 3, 2));
+///
+/// let mut u = v;
+/// u.set_xz(ivec2(7, 8));
+/// assert_eq!(u, ivec3(7, 2, 8));
+/// ```
+pub trait Swizzle3<T: Primitive>: Swizzle2<T> {
+    fn z(&self) -> T;
+    fn set_z(&mut self, z: T);
+    swizzle_aliases! { b: z, p: z }
+    swizzle_fns! {
+        xz: Vector2(x, z),
+        yz: Vector2(y, z),
+        zx: Vector2(z, x),
+        zy: Vector2(z, y),
+        zz: Vector2(z, z),
+        rb: Vector2(x, z),
+        gb: Vector2(y, z),
+        br: Vector2(z, x),
+        bg: Vector2(z, y),
+        bb: Vector2(z, z),
+        sp: Vector2(x, z),
+        tp: Vector2(y, z),
+        ps: Vector2(z, x),
+        pt: Vector2(z, y),
+        pp: Vector2(z, z),
+        xxz: Vector3(x, x, z),
+        xyz: Vector3(x, y, z),
+        xzx: Vector3(x, z, x),
+        xzy: Vector3(x, z, y),
+        xzz: Vector3(x, z, z),
+        yxz: Vector3(y, x, z),
+        yyz: Vector3(y, y, z),
+        yzx: Vector3(y, z, x),
+        yzy: Vector3(y, z, y),
+        yzz: Vector3(y, z, z),
+        zxx: Vector3(z, x, x),
+        zxy: Vector3(z, x, y),
+        zxz: Vector3(z, x, z),
+        zyx: Vector3(z, y, x),
+        zyy: Vector3(z, y, y),
+        zyz: Vector3(z, y, z),
+        zzx: Vector3(z, z, x),
+        zzy: Vector3(z, z, y),
+        zzz: Vector3(z, z, z),
+        rrb: Vector3(x, x, z),
+        rgb: Vector3(x, y, z),
+        rbr: Vector3(x, z, x),
+        rbg: Vector3(x, z, y),
+        rbb: Vector3(x, z, z),
+        grb: Vector3(y, x, z),
+        ggb: Vector3(y, y, z),
+        gbr: Vector3(y, z, x),
+        gbg: Vector3(y, z, y),
+        gbb: Vector3(y, z, z),
+        brr: Vector3(z, x, x),
+        brg: Vector3(z, x, y),
+        brb: Vector3(z, x, z),
+        bgr: Vector3(z, y, x),
+        bgg: Vector3(z, y, y),
+        bgb: Vector3(z, y, z),
+        bbr: Vector3(z, z, x),
+        bbg: Vector3(z, z, y),
+        bbb: Vector3(z, z, z),
+        ssp: Vector3(x, x, z),
+        stp: Vector3(x, y, z),
+        sps: Vector3(x, z, x),
+        spt: Vector3(x, z, y),
+        spp: Vector3(x, z, z),
+        tsp: Vector3(y, x, z),
+        ttp: Vector3(y, y, z),
+        tps: Vector3(y, z, x),
+        tpt: Vector3(y, z, y),
+        tpp: Vector3(y, z, z),
+        pss: Vector3(z, x, x),
+        pst: Vector3(z, x, y),
+        psp: Vector3(z, x, z),
+        pts: Vector3(z, y, x),
+        ptt: Vector3(z, y, y),
+        ptp: Vector3(z, y, z),
+        pps: Vector3(z, z, x),
+        ppt: Vector3(z, z, y),
+        ppp: Vector3(z, z, z),
+        xxxz: Vector4(x, x, x, z),
+        xxyz: Vector4(x, x, y, z),
+        xxzx: Vector4(x, x, z, x),
+        xxzy: Vector4(x, x, z, y),
+        xxzz: Vector4(x, x, z, z),
+        xyxz: Vector4(x, y, x, z),
+        xyyz: Vector4(x, y, y, z),
+        xyzx: Vector4(x, y, z, x),
+        xyzy: Vector4(x, y, z, y),
+        xyzz: Vector4(x, y, z, z),
+        xzxx: Vector4(x, z, x, x),
+        xzxy: Vector4(x, z, x, y),
+        xzxz: Vector4(x, z, x, z),
+        xzyx: Vector4(x, z, y, x),
+        xzyy: Vector4(x, z, y, y),
+        xzyz: Vector4(x, z, y, z),
+        xzzx: Vector4(x, z, z, x),
+        xzzy: Vector4(x, z, z, y),
+        xzzz: Vector4(x, z, z, z),
+        yxxz: Vector4(y, x, x, z),
+        yxyz: Vector4(y, x, y, z),
+        yxzx: Vector4(y, x, z, x),
+        yxzy: Vector4(y, x, z, y),
+        yxzz: Vector4(y, x, z, z),
+        yyxz: Vector4(y, y, x, z),
+        yyyz: Vector4(y, y, y, z),
+        yyzx: Vector4(y, y, z, x),
+        yyzy: Vector4(y, y, z, y),
+        yyzz: Vector4(y, y, z, z),
+        yzxx: Vector4(y, z, x, x),
+        yzxy: Vector4(y, z, x, y),
+        yzxz: Vector4(y, z, x, z),
+        yzyx: Vector4(y, z, y, x),
+        yzyy: Vector4(y, z, y, y),
+        yzyz: Vector4(y, z, y, z),
+        yzzx: Vector4(y, z, z, x),
+        yzzy: Vector4(y, z, z, y),
+        yzzz: Vector4(y, z, z, z),
+        zxxx: Vector4(z, x, x, x),
+        zxxy: Vector4(z, x, x, y),
+        zxxz: Vector4(z, x, x, z),
+        zxyx: Vector4(z, x, y, x),
+        zxyy: Vector4(z, x, y, y),
+        zxyz: Vector4(z, x, y, z),
+        zxzx: Vector4(z, x, z, x),
+        zxzy: Vector4(z, x, z, y),
+        zxzz: Vector4(z, x, z, z),
+        zyxx: Vector4(z, y, x, x),
+        zyxy: Vector4(z, y, x, y),
+        zyxz: Vector4(z, y, x, z),
+        zyyx: Vector4(z, y, y, x),
+        zyyy: Vector4(z, y, y, y),
+        zyyz: Vector4(z, y, y, z),
+        zyzx: Vector4(z, y, z, x),
+        zyzy: Vector4(z, y, z, y),
+        zyzz: Vector4(z, y, z, z),
+        zzxx: Vector4(z, z, x, x),
+        zzxy: Vector4(z, z, x, y),
+        zzxz: Vector4(z, z, x, z),
+        zzyx: Vector4(z, z, y, x),
+        zzyy: Vector4(z, z, y, y),
+        zzyz: Vector4(z, z, y, z),
+        zzzx: Vector4(z, z, z, x),
+        zzzy: Vector4(z, z, z, y),
+        zzzz: Vector4(z, z, z, z),
+        rrrb: Vector4(x, x, x, z),
+        rrgb: Vector4(x, x, y, z),
+        rrbr: Vector4(x, x, z, x),
+        rrbg: Vector4(x, x, z, y),
+        rrbb: Vector4(x, x, z, z),
+        rgrb: Vector4(x, y, x, z),
+        rggb: Vector4(x, y, y, z),
+        rgbr: Vector4(x, y, z, x),
+        rgbg: Vector4(x, y, z, y),
+        rgbb: Vector4(x, y, z, z),
+        rbrr: Vector4(x, z, x, x),
+        rbrg: Vector4(x, z, x, y),
+        rbrb: Vector4(x, z, x, z),
+        rbgr: Vector4(x, z, y, x),
+        rbgg: Vector4(x, z, y, y),
+        rbgb: Vector4(x, z, y, z),
+        rbbr: Vector4(x, z, z, x),
+        rbbg: Vector4(x, z, z, y),
+        rbbb: Vector4(x, z, z, z),
+        grrb: Vector4(y, x, x, z),
+        grgb: Vector4(y, x, y, z),
+        grbr: Vector4(y, x, z, x),
+        grbg: Vector4(y, x, z, y),
+        grbb: Vector4(y, x, z, z),
+        ggrb: Vector4(y, y, x, z),
+        gggb: Vector4(y, y, y, z),
+        ggbr: Vector4(y, y, z, x),
+        ggbg: Vector4(y, y, z, y),
+        ggbb: Vector4(y, y, z, z),
+        gbrr: Vector4(y, z, x, x),
+        gbrg: Vector4(y, z, x, y),
+        gbrb: Vector4(y, z, x, z),
+        gbgr: Vector4(y, z, y, x),
+        gbgg: Vector4(y, z, y, y),
+        gbgb: Vector4(y, z, y, z),
+        gbbr: Vector4(y, z, z, x),
+        gbbg: Vector4(y, z, z, y),
+        gbbb: Vector4(y, z, z, z),
+        brrr: Vector4(z, x, x, x),
+        brrg: Vector4(z, x, x, y),
+        brrb: Vector4(z, x, x, z),
+        brgr: Vector4(z, x, y, x),
+        brgg: Vector4(z, x, y, y),
+        brgb: Vector4(z, x, y, z),
+        brbr: Vector4(z, x, z, x),
+        brbg: Vector4(z, x, z, y),
+        brbb: Vector4(z, x, z, z),
+        bgrr: Vector4(z, y, x, x),
+        bgrg: Vector4(z, y, x, y),
+        bgrb: Vector4(z, y, x, z),
+        bggr: Vector4(z, y, y, x),
+        bggg: Vector4(z, y, y, y),
+        bggb: Vector4(z, y, y, z),
+        bgbr: Vector4(z, y, z, x),
+        bgbg: Vector4(z, y, z, y),
+        bgbb: Vector4(z, y, z, z),
+        bbrr: Vector4(z, z, x, x),
+        bbrg: Vector4(z, z, x, y),
+        bbrb: Vector4(z, z, x, z),
+        bbgr: Vector4(z, z, y, x),
+        bbgg: Vector4(z, z, y, y),
+        bbgb: Vector4(z, z, y, z),
+        bbbr: Vector4(z, z, z, x),
+        bbbg: Vector4(z, z, z, y),
+        bbbb: Vector4(z, z, z, z),
+        sssp: Vector4(x, x, x, z),
+        sstp: Vector4(x, x, y, z),
+        ssps: Vector4(x, x, z, x),
+        sspt: Vector4(x, x, z, y),
+        sspp: Vector4(x, x, z, z),
+        stsp: Vector4(x, y, x, z),
+        sttp: Vector4(x, y, y, z),
+        stps: Vector4(x, y, z, x),
+        stpt: Vector4(x, y, z, y),
+        stpp: Vector4(x, y, z, z),
+        spss: Vector4(x, z, x, x),
+        spst: Vector4(x, z, x, y),
+        spsp: Vector4(x, z, x, z),
+        spts: Vector4(x, z, y, x),
+        sptt: Vector4(x, z, y, y),
+        sptp: Vector4(x, z, y, z),
+        spps: Vector4(x, z, z, x),
+        sppt: Vector4(x, z, z, y),
+        sppp: Vector4(x, z, z, z),
+        tssp: Vector4(y, x, x, z),
+        tstp: Vector4(y, x, y, z),
+        tsps: Vector4(y, x, z, x),
+        tspt: Vector4(y, x, z, y),
+        tspp: Vector4(y, x, z, z),
+        ttsp: Vector4(y, y, x, z),
+        tttp: Vector4(y, y, y, z),
+        ttps: Vector4(y, y, z, x),
+        ttpt: Vector4(y, y, z, y),
+        ttpp: Vector4(y, y, z, z),
+        tpss: Vector4(y, z, x, x),
+        tpst: Vector4(y, z, x, y),
+        tpsp: Vector4(y, z, x, z),
+        tpts: Vector4(y, z, y, x),
+        tptt: Vector4(y, z, y, y),
+        tptp: Vector4(y, z, y, z),
+        tpps: Vector4(y, z, z, x),
+        tppt: Vector4(y, z, z, y),
+        tppp: Vector4(y, z, z, z),
+        psss: Vector4(z, x, x, x),
+        psst: Vector4(z, x, x, y),
+        pssp: Vector4(z, x, x, z),
+        psts: Vector4(z, x, y, x),
+        pstt: Vector4(z, x, y, y),
+        pstp: Vector4(z, x, y, z),
+        psps: Vector4(z, x, z, x),
+        pspt: Vector4(z, x, z, y),
+        pspp: Vector4(z, x, z, z),
+        ptss: Vector4(z, y, x, x),
+        ptst: Vector4(z, y, x, y),
+        ptsp: Vector4(z, y, x, z),
+        ptts: Vector4(z, y, y, x),
+        pttt: Vector4(z, y, y, y),
+        pttp: Vector4(z, y, y, z),
+        ptps: Vector4(z, y, z, x),
+        ptpt: Vector4(z, y, z, y),
+        ptpp: Vector4(z, y, z, z),
+        ppss: Vector4(z, z, x, x),
+        ppst: Vector4(z, z, x, y),
+        ppsp: Vector4(z, z, x, z),
+        ppts: Vector4(z, z, y, x),
+        pptt: Vector4(z, z, y, y),
+        pptp: Vector4(z, z, y, z),
+        ppps: Vector4(z, z, z, x),
+        pppt: Vector4(z, z, z, y),
+        pppp: Vector4(z, z, z, z)
+    }
+    swizzle_setters! {
+        set_xz: Vector2(set_x: x, set_z: y),
+        set_yz: Vector2(set_y: x, set_z: y),
+        set_zx: Vector2(set_z: x, set_x: y),
+        set_zy: Vector2(set_z: x, set_y: y),
+        set_rb: Vector2(set_x: x, set_z: y),
+        set_gb: Vector2(set_y: x, set_z: y),
+        set_br: Vector2(set_z: x, set_x: y),
+        set_bg: Vector2(set_z: x, set_y: y),
+        set_sp: Vector2(set_x: x, set_z: y),
+        set_tp: Vector2(set_y: x, set_z: y),
+        set_ps: Vector2(set_z: x, set_x: y),
+        set_pt: Vector2(set_z: x, set_y: y),
+        set_xyz: Vector3(set_x: x, set_y: y, set_z: z),
+        set_xzy: Vector3(set_x: x, set_z: y, set_y: z),
+        set_yxz: Vector3(set_y: x, set_x: y, set_z: z),
+        set_yzx: Vector3(set_y: x, set_z: y, set_x: z),
+        set_zxy: Vector3(set_z: x, set_x: y, set_y: z),
+        set_zyx: Vector3(set_z: x, set_y: y, set_x: z),
+        set_rgb: Vector3(set_x: x, set_y: y, set_z: z),
+        set_rbg: Vector3(set_x: x, set_z: y, set_y: z),
+        set_grb: Vector3(set_y: x, set_x: y, set_z: z),
+        set_gbr: Vector3(set_y: x, set_z: y, set_x: z),
+        set_brg: Vector3(set_z: x, set_x: y, set_y: z),
+        set_bgr: Vector3(set_z: x, set_y: y, set_x: z),
+        set_stp: Vector3(set_x: x, set_y: y, set_z: z),
+        set_spt: Vector3(set_x: x, set_z: y, set_y: z),
+        set_tsp: Vector3(set_y: x, set_x: y, set_z: z),
+        set_tps: Vector3(set_y: x, set_z: y, set_x: z),
+        set_pst: Vector3(set_z: x, set_x: y, set_y: z),
+        set_pts: Vector3(set_z: x, set_y: y, set_x: z)
     }
 }
 
-def_swizzle3! {}
-def_swizzle4! {}
+/// Swizzle operators of vectors that have four components.
+///
+/// The fourth component is also named `a` or `q`.
+///
+/// # Example
+///
+/// ```
+/// use glm::*;
+///
+/// let c = vec4(0.1, 0.2, 0.3, 1.);
+/// assert_eq!(c.bgra(), vec4(0.3, 0.2, 0.1, 1.));
+/// assert_eq!(c.xxww(), vec4(0.1, 0.1, 1., 1.));
+/// assert_eq!(c.rgb(), c.xyz());
+///
+/// let mut v = c;
+/// v.set_wzyx(c);
+/// assert_eq!(v, c.wzyx());
+/// ```
+pub trait Swizzle4<T: Primitive>: Swizzle3<T> {
+    fn w(&self) -> T;
+    fn set_w(&mut self, w: T);
+    swizzle_aliases! { a: w, q: w }
+    swizzle_fns! {
+        xw: Vector2(x, w),
+        yw: Vector2(y, w),
+        zw: Vector2(z, w),
+        wx: Vector2(w, x),
+        wy: Vector2(w, y),
+        wz: Vector2(w, z),
+        ww: Vector2(w, w),
+        ra: Vector2(x, w),
+        ga: Vector2(y, w),
+        ba: Vector2(z, w),
+        ar: Vector2(w, x),
+        ag: Vector2(w, y),
+        ab: Vector2(w, z),
+        aa: Vector2(w, w),
+        sq: Vector2(x, w),
+        tq: Vector2(y, w),
+        pq: Vector2(z, w),
+        qs: Vector2(w, x),
+        qt: Vector2(w, y),
+        qp: Vector2(w, z),
+        qq: Vector2(w, w),
+        xxw: Vector3(x, x, w),
+        xyw: Vector3(x, y, w),
+        xzw: Vector3(x, z, w),
+        xwx: Vector3(x, w, x),
+        xwy: Vector3(x, w, y),
+        xwz: Vector3(x, w, z),
+        xww: Vector3(x, w, w),
+        yxw: Vector3(y, x, w),
+        yyw: Vector3(y, y, w),
+        yzw: Vector3(y, z, w),
+        ywx: Vector3(y, w, x),
+        ywy: Vector3(y, w, y),
+        ywz: Vector3(y, w, z),
+        yww: Vector3(y, w, w),
+        zxw: Vector3(z, x, w),
+        zyw: Vector3(z, y, w),
+        zzw: Vector3(z, z, w),
+        zwx: Vector3(z, w, x),
+        zwy: Vector3(z, w, y),
+        zwz: Vector3(z, w, z),
+        zww: Vector3(z, w, w),
+        wxx: Vector3(w, x, x),
+        wxy: Vector3(w, x, y),
+        wxz: Vector3(w, x, z),
+        wxw: Vector3(w, x, w),
+        wyx: Vector3(w, y, x),
+        wyy: Vector3(w, y, y),
+        wyz: Vector3(w, y, z),
+        wyw: Vector3(w, y, w),
+        wzx: Vector3(w, z, x),
+        wzy: Vector3(w, z, y),
+        wzz: Vector3(w, z, z),
+        wzw: Vector3(w, z, w),
+        wwx: Vector3(w, w, x),
+        wwy: Vector3(w, w, y),
+        wwz: Vector3(w, w, z),
+        www: Vector3(w, w, w),
+        rra: Vector3(x, x, w),
+        rga: Vector3(x, y, w),
+        rba: Vector3(x, z, w),
+        rar: Vector3(x, w, x),
+        rag: Vector3(x, w, y),
+        rab: Vector3(x, w, z),
+        raa: Vector3(x, w, w),
+        gra: Vector3(y, x, w),
+        gga: Vector3(y, y, w),
+        gba: Vector3(y, z, w),
+        gar: Vector3(y, w, x),
+        gag: Vector3(y, w, y),
+        gab: Vector3(y, w, z),
+        gaa: Vector3(y, w, w),
+        bra: Vector3(z, x, w),
+        bga: Vector3(z, y, w),
+        bba: Vector3(z, z, w),
+        bar: Vector3(z, w, x),
+        bag: Vector3(z, w, y),
+        bab: Vector3(z, w, z),
+        baa: Vector3(z, w, w),
+        arr: Vector3(w, x, x),
+        arg: Vector3(w, x, y),
+        arb: Vector3(w, x, z),
+        ara: Vector3(w, x, w),
+        agr: Vector3(w, y, x),
+        agg: Vector3(w, y, y),
+        agb: Vector3(w, y, z),
+        aga: Vector3(w, y, w),
+        abr: Vector3(w, z, x),
+        abg: Vector3(w, z, y),
+        abb: Vector3(w, z, z),
+        aba: Vector3(w, z, w),
+        aar: Vector3(w, w, x),
+        aag: Vector3(w, w, y),
+        aab: Vector3(w, w, z),
+        aaa: Vector3(w, w, w),
+        ssq: Vector3(x, x, w),
+        stq: Vector3(x, y, w),
+        spq: Vector3(x, z, w),
+        sqs: Vector3(x, w, x),
+        sqt: Vector3(x, w, y),
+        sqp: Vector3(x, w, z),
+        sqq: Vector3(x, w, w),
+        tsq: Vector3(y, x, w),
+        ttq: Vector3(y, y, w),
+        tpq: Vector3(y, z, w),
+        tqs: Vector3(y, w, x),
+        tqt: Vector3(y, w, y),
+        tqp: Vector3(y, w, z),
+        tqq: Vector3(y, w, w),
+        psq: Vector3(z, x, w),
+        ptq: Vector3(z, y, w),
+        ppq: Vector3(z, z, w),
+        pqs: Vector3(z, w, x),
+        pqt: Vector3(z, w, y),
+        pqp: Vector3(z, w, z),
+        pqq: Vector3(z, w, w),
+        qss: Vector3(w, x, x),
+        qst: Vector3(w, x, y),
+        qsp: Vector3(w, x, z),
+        qsq: Vector3(w, x, w),
+        qts: Vector3(w, y, x),
+        qtt: Vector3(w, y, y),
+        qtp: Vector3(w, y, z),
+        qtq: Vector3(w, y, w),
+        qps: Vector3(w, z, x),
+        qpt: Vector3(w, z, y),
+        qpp: Vector3(w, z, z),
+        qpq: Vector3(w, z, w),
+        qqs: Vector3(w, w, x),
+        qqt: Vector3(w, w, y),
+        qqp: Vector3(w, w, z),
+        qqq: Vector3(w, w, w),
+        xxxw: Vector4(x, x, x, w),
+        xxyw: Vector4(x, x, y, w),
+        xxzw: Vector4(x, x, z, w),
+        xxwx: Vector4(x, x, w, x),
+        xxwy: Vector4(x, x, w, y),
+        xxwz: Vector4(x, x, w, z),
+        xxww: Vector4(x, x, w, w),
+        xyxw: Vector4(x, y, x, w),
+        xyyw: Vector4(x, y, y, w),
+        xyzw: Vector4(x, y, z, w),
+        xywx: Vector4(x, y, w, x),
+        xywy: Vector4(x, y, w, y),
+        xywz: Vector4(x, y, w, z),
+        xyww: Vector4(x, y, w, w),
+        xzxw: Vector4(x, z, x, w),
+        xzyw: Vector4(x, z, y, w),
+        xzzw: Vector4(x, z, z, w),
+        xzwx: Vector4(x, z, w, x),
+        xzwy: Vector4(x, z, w, y),
+        xzwz: Vector4(x, z, w, z),
+        xzww: Vector4(x, z, w, w),
+        xwxx: Vector4(x, w, x, x),
+        xwxy: Vector4(x, w, x, y),
+        xwxz: Vector4(x, w, x, z),
+        xwxw: Vector4(x, w, x, w),
+        xwyx: Vector4(x, w, y, x),
+        xwyy: Vector4(x, w, y, y),
+        xwyz: Vector4(x, w, y, z),
+        xwyw: Vector4(x, w, y, w),
+        xwzx: Vector4(x, w, z, x),
+        xwzy: Vector4(x, w, z, y),
+        xwzz: Vector4(x, w, z, z),
+        xwzw: Vector4(x, w, z, w),
+        xwwx: Vector4(x, w, w, x),
+        xwwy: Vector4(x, w, w, y),
+        xwwz: Vector4(x, w, w, z),
+        xwww: Vector4(x, w, w, w),
+        yxxw: Vector4(y, x, x, w),
+        yxyw: Vector4(y, x, y, w),
+        yxzw: Vector4(y, x, z, w),
+        yxwx: Vector4(y, x, w, x),
+        yxwy: Vector4(y, x, w, y),
+        yxwz: Vector4(y, x, w, z),
+        yxww: Vector4(y, x, w, w),
+        yyxw: Vector4(y, y, x, w),
+        yyyw: Vector4(y, y, y, w),
+        yyzw: Vector4(y, y, z, w),
+        yywx: Vector4(y, y, w, x),
+        yywy: Vector4(y, y, w, y),
+        yywz: Vector4(y, y, w, z),
+        yyww: Vector4(y, y, w, w),
+        yzxw: Vector4(y, z, x, w),
+        yzyw: Vector4(y, z, y, w),
+        yzzw: Vector4(y, z, z, w),
+        yzwx: Vector4(y, z, w, x),
+        yzwy: Vector4(y, z, w, y),
+        yzwz: Vector4(y, z, w, z),
+        yzww: Vector4(y, z, w, w),
+        ywxx: Vector4(y, w, x, x),
+        ywxy: Vector4(y, w, x, y),
+        ywxz: Vector4(y, w, x, z),
+        ywxw: Vector4(y, w, x, w),
+        ywyx: Vector4(y, w, y, x),
+        ywyy: Vector4(y, w, y, y),
+        ywyz: Vector4(y, w, y, z),
+        ywyw: Vector4(y, w, y, w),
+        ywzx: Vector4(y, w, z, x),
+        ywzy: Vector4(y, w, z, y),
+        ywzz: Vector4(y, w, z, z),
+        ywzw: Vector4(y, w, z, w),
+        ywwx: Vector4(y, w, w, x),
+        ywwy: Vector4(y, w, w, y),
+        ywwz: Vector4(y, w, w, z),
+        ywww: Vector4(y, w, w, w),
+        zxxw: Vector4(z, x, x, w),
+        zxyw: Vector4(z, x, y, w),
+        zxzw: Vector4(z, x, z, w),
+        zxwx: Vector4(z, x, w, x),
+        zxwy: Vector4(z, x, w, y),
+        zxwz: Vector4(z, x, w, z),
+        zxww: Vector4(z, x, w, w),
+        zyxw: Vector4(z, y, x, w),
+        zyyw: Vector4(z, y, y, w),
+        zyzw: Vector4(z, y, z, w),
+        zywx: Vector4(z, y, w, x),
+        zywy: Vector4(z, y, w, y),
+        zywz: Vector4(z, y, w, z),
+        zyww: Vector4(z, y, w, w),
+        zzxw: Vector4(z, z, x, w),
+        zzyw: Vector4(z, z, y, w),
+        zzzw: Vector4(z, z, z, w),
+        zzwx: Vector4(z, z, w, x),
+        zzwy: Vector4(z, z, w, y),
+        zzwz: Vector4(z, z, w, z),
+        zzww: Vector4(z, z, w, w),
+        zwxx: Vector4(z, w, x, x),
+        zwxy: Vector4(z, w, x, y),
+        zwxz: Vector4(z, w, x, z),
+        zwxw: Vector4(z, w, x, w),
+        zwyx: Vector4(z, w, y, x),
+        zwyy: Vector4(z, w, y, y),
+        zwyz: Vector4(z, w, y, z),
+        zwyw: Vector4(z, w, y, w),
+        zwzx: Vector4(z, w, z, x),
+        zwzy: Vector4(z, w, z, y),
+        zwzz: Vector4(z, w, z, z),
+        zwzw: Vector4(z, w, z, w),
+        zwwx: Vector4(z, w, w, x),
+        zwwy: Vector4(z, w, w, y),
+        zwwz: Vector4(z, w, w, z),
+        zwww: Vector4(z, w, w, w),
+        wxxx: Vector4(w, x, x, x),
+        wxxy: Vector4(w, x, x, y),
+        wxxz: Vector4(w, x, x, z),
+        wxxw: Vector4(w, x, x, w),
+        wxyx: Vector4(w, x, y, x),
+        wxyy: Vector4(w, x, y, y),
+        wxyz: Vector4(w, x, y, z),
+        wxyw: Vector4(w, x, y, w),
+        wxzx: Vector4(w, x, z, x),
+        wxzy: Vector4(w, x, z, y),
+        wxzz: Vector4(w, x, z, z),
+        wxzw: Vector4(w, x, z, w),
+        wxwx: Vector4(w, x, w, x),
+        wxwy: Vector4(w, x, w, y),
+        wxwz: Vector4(w, x, w, z),
+        wxww: Vector4(w, x, w, w),
+        wyxx: Vector4(w, y, x, x),
+        wyxy: Vector4(w, y, x, y),
+        wyxz: Vector4(w, y, x, z),
+        wyxw: Vector4(w, y, x, w),
+        wyyx: Vector4(w, y, y, x),
+        wyyy: Vector4(w, y, y, y),
+        wyyz: Vector4(w, y, y, z),
+        wyyw: Vector4(w, y, y, w),
+        wyzx: Vector4(w, y, z, x),
+        wyzy: Vector4(w, y, z, y),
+        wyzz: Vector4(w, y, z, z),
+        wyzw: Vector4(w, y, z, w),
+        wywx: Vector4(w, y, w, x),
+        wywy: Vector4(w, y, w, y),
+        wywz: Vector4(w, y, w, z),
+        wyww: Vector4(w, y, w, w),
+        wzxx: Vector4(w, z, x, x),
+        wzxy: Vector4(w, z, x, y),
+        wzxz: Vector4(w, z, x, z),
+        wzxw: Vector4(w, z, x, w),
+        wzyx: Vector4(w, z, y, x),
+        wzyy: Vector4(w, z, y, y),
+        wzyz: Vector4(w, z, y, z),
+        wzyw: Vector4(w, z, y, w),
+        wzzx: Vector4(w, z, z, x),
+        wzzy: Vector4(w, z, z, y),
+        wzzz: Vector4(w, z, z, z),
+        wzzw: Vector4(w, z, z, w),
+        wzwx: Vector4(w, z, w, x),
+        wzwy: Vector4(w, z, w, y),
+        wzwz: Vector4(w, z, w, z),
+        wzww: Vector4(w, z, w, w),
+        wwxx: Vector4(w, w, x, x),
+        wwxy: Vector4(w, w, x, y),
+        wwxz: Vector4(w, w, x, z),
+        wwxw: Vector4(w, w, x, w),
+        wwyx: Vector4(w, w, y, x),
+        wwyy: Vector4(w, w, y, y),
+        wwyz: Vector4(w, w, y, z),
+        wwyw: Vector4(w, w, y, w),
+        wwzx: Vector4(w, w, z, x),
+        wwzy: Vector4(w, w, z, y),
+        wwzz: Vector4(w, w, z, z),
+        wwzw: Vector4(w, w, z, w),
+        wwwx: Vector4(w, w, w, x),
+        wwwy: Vector4(w, w, w, y),
+        wwwz: Vector4(w, w, w, z),
+        wwww: Vector4(w, w, w, w),
+        rrra: Vector4(x, x, x, w),
+        rrga: Vector4(x, x, y, w),
+        rrba: Vector4(x, x, z, w),
+        rrar: Vector4(x, x, w, x),
+        rrag: Vector4(x, x, w, y),
+        rrab: Vector4(x, x, w, z),
+        rraa: Vector4(x, x, w, w),
+        rgra: Vector4(x, y, x, w),
+        rgga: Vector4(x, y, y, w),
+        rgba: Vector4(x, y, z, w),
+        rgar: Vector4(x, y, w, x),
+        rgag: Vector4(x, y, w, y),
+        rgab: Vector4(x, y, w, z),
+        rgaa: Vector4(x, y, w, w),
+        rbra: Vector4(x, z, x, w),
+        rbga: Vector4(x, z, y, w),
+        rbba: Vector4(x, z, z, w),
+        rbar: Vector4(x, z, w, x),
+        rbag: Vector4(x, z, w, y),
+        rbab: Vector4(x, z, w, z),
+        rbaa: Vector4(x, z, w, w),
+        rarr: Vector4(x, w, x, x),
+        rarg: Vector4(x, w, x, y),
+        rarb: Vector4(x, w, x, z),
+        rara: Vector4(x, w, x, w),
+        ragr: Vector4(x, w, y, x),
+        ragg: Vector4(x, w, y, y),
+        ragb: Vector4(x, w, y, z),
+        raga: Vector4(x, w, y, w),
+        rabr: Vector4(x, w, z, x),
+        rabg: Vector4(x, w, z, y),
+        rabb: Vector4(x, w, z, z),
+        raba: Vector4(x, w, z, w),
+        raar: Vector4(x, w, w, x),
+        raag: Vector4(x, w, w, y),
+        raab: Vector4(x, w, w, z),
+        raaa: Vector4(x, w, w, w),
+        grra: Vector4(y, x, x, w),
+        grga: Vector4(y, x, y, w),
+        grba: Vector4(y, x, z, w),
+        grar: Vector4(y, x, w, x),
+        grag: Vector4(y, x, w, y),
+        grab: Vector4(y, x, w, z),
+        graa: Vector4(y, x, w, w),
+        ggra: Vector4(y, y, x, w),
+        ggga: Vector4(y, y, y, w),
+        ggba: Vector4(y, y, z, w),
+        ggar: Vector4(y, y, w, x),
+        ggag: Vector4(y, y, w, y),
+        ggab: Vector4(y, y, w, z),
+        ggaa: Vector4(y, y, w, w),
+        gbra: Vector4(y, z, x, w),
+        gbga: Vector4(y, z, y, w),
+        gbba: Vector4(y, z, z, w),
+        gbar: Vector4(y, z, w, x),
+        gbag: Vector4(y, z, w, y),
+        gbab: Vector4(y, z, w, z),
+        gbaa: Vector4(y, z, w, w),
+        garr: Vector4(y, w, x, x),
+        garg: Vector4(y, w, x, y),
+        garb: Vector4(y, w, x, z),
+        gara: Vector4(y, w, x, w),
+        gagr: Vector4(y, w, y, x),
+        gagg: Vector4(y, w, y, y),
+        gagb: Vector4(y, w, y, z),
+        gaga: Vector4(y, w, y, w),
+        gabr: Vector4(y, w, z, x),
+        gabg: Vector4(y, w, z, y),
+        gabb: Vector4(y, w, z, z),
+        gaba: Vector4(y, w, z, w),
+        gaar: Vector4(y, w, w, x),
+        gaag: Vector4(y, w, w, y),
+        gaab: Vector4(y, w, w, z),
+        gaaa: Vector4(y, w, w, w),
+        brra: Vector4(z, x, x, w),
+        brga: Vector4(z, x, y, w),
+        brba: Vector4(z, x, z, w),
+        brar: Vector4(z, x, w, x),
+        brag: Vector4(z, x, w, y),
+        brab: Vector4(z, x, w, z),
+        braa: Vector4(z, x, w, w),
+        bgra: Vector4(z, y, x, w),
+        bgga: Vector4(z, y, y, w),
+        bgba: Vector4(z, y, z, w),
+        bgar: Vector4(z, y, w, x),
+        bgag: Vector4(z, y, w, y),
+        bgab: Vector4(z, y, w, z),
+        bgaa: Vector4(z, y, w, w),
+        bbra: Vector4(z, z, x, w),
+        bbga: Vector4(z, z, y, w),
+        bbba: Vector4(z, z, z, w),
+        bbar: Vector4(z, z, w, x),
+        bbag: Vector4(z, z, w, y),
+        bbab: Vector4(z, z, w, z),
+        bbaa: Vector4(z, z, w, w),
+        barr: Vector4(z, w, x, x),
+        barg: Vector4(z, w, x, y),
+        barb: Vector4(z, w, x, z),
+        bara: Vector4(z, w, x, w),
+        bagr: Vector4(z, w, y, x),
+        bagg: Vector4(z, w, y, y),
+        bagb: Vector4(z, w, y, z),
+        baga: Vector4(z, w, y, w),
+        babr: Vector4(z, w, z, x),
+        babg: Vector4(z, w, z, y),
+        babb: Vector4(z, w, z, z),
+        baba: Vector4(z, w, z, w),
+        baar: Vector4(z, w, w, x),
+        baag: Vector4(z, w, w, y),
+        baab: Vector4(z, w, w, z),
+        baaa: Vector4(z, w, w, w),
+        arrr: Vector4(w, x, x, x),
+        arrg: Vector4(w, x, x, y),
+        arrb: Vector4(w, x, x, z),
+        arra: Vector4(w, x, x, w),
+        argr: Vector4(w, x, y, x),
+        argg: Vector4(w, x, y, y),
+        argb: Vector4(w, x, y, z),
+        arga: Vector4(w, x, y, w),
+        arbr: Vector4(w, x, z, x),
+        arbg: Vector4(w, x, z, y),
+        arbb: Vector4(w, x, z, z),
+        arba: Vector4(w, x, z, w),
+        arar: Vector4(w, x, w, x),
+        arag: Vector4(w, x, w, y),
+        arab: Vector4(w, x, w, z),
+        araa: Vector4(w, x, w, w),
+        agrr: Vector4(w, y, x, x),
+        agrg: Vector4(w, y, x, y),
+        agrb: Vector4(w, y, x, z),
+        agra: Vector4(w, y, x, w),
+        aggr: Vector4(w, y, y, x),
+        aggg: Vector4(w, y, y, y),
+        aggb: Vector4(w, y, y, z),
+        agga: Vector4(w, y, y, w),
+        agbr: Vector4(w, y, z, x),
+        agbg: Vector4(w, y, z, y),
+        agbb: Vector4(w, y, z, z),
+        agba: Vector4(w, y, z, w),
+        agar: Vector4(w, y, w, x),
+        agag: Vector4(w, y, w, y),
+        agab: Vector4(w, y, w, z),
+        agaa: Vector4(w, y, w, w),
+        abrr: Vector4(w, z, x, x),
+        abrg: Vector4(w, z, x, y),
+        abrb: Vector4(w, z, x, z),
+        abra: Vector4(w, z, x, w),
+        abgr: Vector4(w, z, y, x),
+        abgg: Vector4(w, z, y, y),
+        abgb: Vector4(w, z, y, z),
+        abga: Vector4(w, z, y, w),
+        abbr: Vector4(w, z, z, x),
+        abbg: Vector4(w, z, z, y),
+        abbb: Vector4(w, z, z, z),
+        abba: Vector4(w, z, z, w),
+        abar: Vector4(w, z, w, x),
+        abag: Vector4(w, z, w, y),
+        abab: Vector4(w, z, w, z),
+        abaa: Vector4(w, z, w, w),
+        aarr: Vector4(w, w, x, x),
+        aarg: Vector4(w, w, x, y),
+        aarb: Vector4(w, w, x, z),
+        aara: Vector4(w, w, x, w),
+        aagr: Vector4(w, w, y, x),
+        aagg: Vector4(w, w, y, y),
+        aagb: Vector4(w, w, y, z),
+        aaga: Vector4(w, w, y, w),
+        aabr: Vector4(w, w, z, x),
+        aabg: Vector4(w, w, z, y),
+        aabb: Vector4(w, w, z, z),
+        aaba: Vector4(w, w, z, w),
+        aaar: Vector4(w, w, w, x),
+        aaag: Vector4(w, w, w, y),
+        aaab: Vector4(w, w, w, z),
+        aaaa: Vector4(w, w, w, w),
+        sssq: Vector4(x, x, x, w),
+        sstq: Vector4(x, x, y, w),
+        sspq: Vector4(x, x, z, w),
+        ssqs: Vector4(x, x, w, x),
+        ssqt: Vector4(x, x, w, y),
+        ssqp: Vector4(x, x, w, z),
+        ssqq: Vector4(x, x, w, w),
+        stsq: Vector4(x, y, x, w),
+        sttq: Vector4(x, y, y, w),
+        stpq: Vector4(x, y, z, w),
+        stqs: Vector4(x, y, w, x),
+        stqt: Vector4(x, y, w, y),
+        stqp: Vector4(x, y, w, z),
+        stqq: Vector4(x, y, w, w),
+        spsq: Vector4(x, z, x, w),
+        sptq: Vector4(x, z, y, w),
+        sppq: Vector4(x, z, z, w),
+        spqs: Vector4(x, z, w, x),
+        spqt: Vector4(x, z, w, y),
+        spqp: Vector4(x, z, w, z),
+        spqq: Vector4(x, z, w, w),
+        sqss: Vector4(x, w, x, x),
+        sqst: Vector4(x, w, x, y),
+        sqsp: Vector4(x, w, x, z),
+        sqsq: Vector4(x, w, x, w),
+        sqts: Vector4(x, w, y, x),
+        sqtt: Vector4(x, w, y, y),
+        sqtp: Vector4(x, w, y, z),
+        sqtq: Vector4(x, w, y, w),
+        sqps: Vector4(x, w, z, x),
+        sqpt: Vector4(x, w, z, y),
+        sqpp: Vector4(x, w, z, z),
+        sqpq: Vector4(x, w, z, w),
+        sqqs: Vector4(x, w, w, x),
+        sqqt: Vector4(x, w, w, y),
+        sqqp: Vector4(x, w, w, z),
+        sqqq: Vector4(x, w, w, w),
+        tssq: Vector4(y, x, x, w),
+        tstq: Vector4(y, x, y, w),
+        tspq: Vector4(y, x, z, w),
+        tsqs: Vector4(y, x, w, x),
+        tsqt: Vector4(y, x, w, y),
+        tsqp: Vector4(y, x, w, z),
+        tsqq: Vector4(y, x, w, w),
+        ttsq: Vector4(y, y, x, w),
+        tttq: Vector4(y, y, y, w),
+        ttpq: Vector4(y, y, z, w),
+        ttqs: Vector4(y, y, w, x),
+        ttqt: Vector4(y, y, w, y),
+        ttqp: Vector4(y, y, w, z),
+        ttqq: Vector4(y, y, w, w),
+        tpsq: Vector4(y, z, x, w),
+        tptq: Vector4(y, z, y, w),
+        tppq: Vector4(y, z, z, w),
+        tpqs: Vector4(y, z, w, x),
+        tpqt: Vector4(y, z, w, y),
+        tpqp: Vector4(y, z, w, z),
+        tpqq: Vector4(y, z, w, w),
+        tqss: Vector4(y, w, x, x),
+        tqst: Vector4(y, w, x, y),
+        tqsp: Vector4(y, w, x, z),
+        tqsq: Vector4(y, w, x, w),
+        tqts: Vector4(y, w, y, x),
+        tqtt: Vector4(y, w, y, y),
+        tqtp: Vector4(y, w, y, z),
+        tqtq: Vector4(y, w, y, w),
+        tqps: Vector4(y, w, z, x),
+        tqpt: Vector4(y, w, z, y),
+        tqpp: Vector4(y, w, z, z),
+        tqpq: Vector4(y, w, z, w),
+        tqqs: Vector4(y, w, w, x),
+        tqqt: Vector4(y, w, w, y),
+        tqqp: Vector4(y, w, w, z),
+        tqqq: Vector4(y, w, w, w),
+        pssq: Vector4(z, x, x, w),
+        pstq: Vector4(z, x, y, w),
+        pspq: Vector4(z, x, z, w),
+        psqs: Vector4(z, x, w, x),
+        psqt: Vector4(z, x, w, y),
+        psqp: Vector4(z, x, w, z),
+        psqq: Vector4(z, x, w, w),
+        ptsq: Vector4(z, y, x, w),
+        pttq: Vector4(z, y, y, w),
+        ptpq: Vector4(z, y, z, w),
+        ptqs: Vector4(z, y, w, x),
+        ptqt: Vector4(z, y, w, y),
+        ptqp: Vector4(z, y, w, z),
+        ptqq: Vector4(z, y, w, w),
+        ppsq: Vector4(z, z, x, w),
+        pptq: Vector4(z, z, y, w),
+        pppq: Vector4(z, z, z, w),
+        ppqs: Vector4(z, z, w, x),
+        ppqt: Vector4(z, z, w, y),
+        ppqp: Vector4(z, z, w, z),
+        ppqq: Vector4(z, z, w, w),
+        pqss: Vector4(z, w, x, x),
+        pqst: Vector4(z, w, x, y),
+        pqsp: Vector4(z, w, x, z),
+        pqsq: Vector4(z, w, x, w),
+        pqts: Vector4(z, w, y, x),
+        pqtt: Vector4(z, w, y, y),
+        pqtp: Vector4(z, w, y, z),
+        pqtq: Vector4(z, w, y, w),
+        pqps: Vector4(z, w, z, x),
+        pqpt: Vector4(z, w, z, y),
+        pqpp: Vector4(z, w, z, z),
+        pqpq: Vector4(z, w, z, w),
+        pqqs: Vector4(z, w, w, x),
+        pqqt: Vector4(z, w, w, y),
+        pqqp: Vector4(z, w, w, z),
+        pqqq: Vector4(z, w, w, w),
+        qsss: Vector4(w, x, x, x),
+        qsst: Vector4(w, x, x, y),
+        qssp: Vector4(w, x, x, z),
+        qssq: Vector4(w, x, x, w),
+        qsts: Vector4(w, x, y, x),
+        qstt: Vector4(w, x, y, y),
+        qstp: Vector4(w, x, y, z),
+        qstq: Vector4(w, x, y, w),
+        qsps: Vector4(w, x, z, x),
+        qspt: Vector4(w, x, z, y),
+        qspp: Vector4(w, x, z, z),
+        qspq: Vector4(w, x, z, w),
+        qsqs: Vector4(w, x, w, x),
+        qsqt: Vector4(w, x, w, y),
+        qsqp: Vector4(w, x, w, z),
+        qsqq: Vector4(w, x, w, w),
+        qtss: Vector4(w, y, x, x),
+        qtst: Vector4(w, y, x, y),
+        qtsp: Vector4(w, y, x, z),
+        qtsq: Vector4(w, y, x, w),
+        qtts: Vector4(w, y, y, x),
+        qttt: Vector4(w, y, y, y),
+        qttp: Vector4(w, y, y, z),
+        qttq: Vector4(w, y, y, w),
+        qtps: Vector4(w, y, z, x),
+        qtpt: Vector4(w, y, z, y),
+        qtpp: Vector4(w, y, z, z),
+        qtpq: Vector4(w, y, z, w),
+        qtqs: Vector4(w, y, w, x),
+        qtqt: Vector4(w, y, w, y),
+        qtqp: Vector4(w, y, w, z),
+        qtqq: Vector4(w, y, w, w),
+        qpss: Vector4(w, z, x, x),
+        qpst: Vector4(w, z, x, y),
+        qpsp: Vector4(w, z, x, z),
+        qpsq: Vector4(w, z, x, w),
+        qpts: Vector4(w, z, y, x),
+        qptt: Vector4(w, z, y, y),
+        qptp: Vector4(w, z, y, z),
+        qptq: Vector4(w, z, y, w),
+        qpps: Vector4(w, z, z, x),
+        qppt: Vector4(w, z, z, y),
+        qppp: Vector4(w, z, z, z),
+        qppq: Vector4(w, z, z, w),
+        qpqs: Vector4(w, z, w, x),
+        qpqt: Vector4(w, z, w, y),
+        qpqp: Vector4(w, z, w, z),
+        qpqq: Vector4(w, z, w, w),
+        qqss: Vector4(w, w, x, x),
+        qqst: Vector4(w, w, x, y),
+        qqsp: Vector4(w, w, x, z),
+        qqsq: Vector4(w, w, x, w),
+        qqts: Vector4(w, w, y, x),
+        qqtt: Vector4(w, w, y, y),
+        qqtp: Vector4(w, w, y, z),
+        qqtq: Vector4(w, w, y, w),
+        qqps: Vector4(w, w, z, x),
+        qqpt: Vector4(w, w, z, y),
+        qqpp: Vector4(w, w, z, z),
+        qqpq: Vector4(w, w, z, w),
+        qqqs: Vector4(w, w, w, x),
+        qqqt: Vector4(w, w, w, y),
+        qqqp: Vector4(w, w, w, z),
+        qqqq: Vector4(w, w, w, w)
+    }
+    swizzle_setters! {
+        set_xw: Vector2(set_x: x, set_w: y),
+        set_yw: Vector2(set_y: x, set_w: y),
+        set_zw: Vector2(set_z: x, set_w: y),
+        set_wx: Vector2(set_w: x, set_x: y),
+        set_wy: Vector2(set_w: x, set_y: y),
+        set_wz: Vector2(set_w: x, set_z: y),
+        set_ra: Vector2(set_x: x, set_w: y),
+        set_ga: Vector2(set_y: x, set_w: y),
+        set_ba: Vector2(set_z: x, set_w: y),
+        set_ar: Vector2(set_w: x, set_x: y),
+        set_ag: Vector2(set_w: x, set_y: y),
+        set_ab: Vector2(set_w: x, set_z: y),
+        set_sq: Vector2(set_x: x, set_w: y),
+        set_tq: Vector2(set_y: x, set_w: y),
+        set_pq: Vector2(set_z: x, set_w: y),
+        set_qs: Vector2(set_w: x, set_x: y),
+        set_qt: Vector2(set_w: x, set_y: y),
+        set_qp: Vector2(set_w: x, set_z: y),
+        set_xyw: Vector3(set_x: x, set_y: y, set_w: z),
+        set_xzw: Vector3(set_x: x, set_z: y, set_w: z),
+        set_xwy: Vector3(set_x: x, set_w: y, set_y: z),
+        set_xwz: Vector3(set_x: x, set_w: y, set_z: z),
+        set_yxw: Vector3(set_y: x, set_x: y, set_w: z),
+        set_yzw: Vector3(set_y: x, set_z: y, set_w: z),
+        set_ywx: Vector3(set_y: x, set_w: y, set_x: z),
+        set_ywz: Vector3(set_y: x, set_w: y, set_z: z),
+        set_zxw: Vector3(set_z: x, set_x: y, set_w: z),
+        set_zyw: Vector3(set_z: x, set_y: y, set_w: z),
+        set_zwx: Vector3(set_z: x, set_w: y, set_x: z),
+        set_zwy: Vector3(set_z: x, set_w: y, set_y: z),
+        set_wxy: Vector3(set_w: x, set_x: y, set_y: z),
+        set_wxz: Vector3(set_w: x, set_x: y, set_z: z),
+        set_wyx: Vector3(set_w: x, set_y: y, set_x: z),
+        set_wyz: Vector3(set_w: x, set_y: y, set_z: z),
+        set_wzx: Vector3(set_w: x, set_z: y, set_x: z),
+        set_wzy: Vector3(set_w: x, set_z: y, set_y: z),
+        set_rga: Vector3(set_x: x, set_y: y, set_w: z),
+        set_rba: Vector3(set_x: x, set_z: y, set_w: z),
+        set_rag: Vector3(set_x: x, set_w: y, set_y: z),
+        set_rab: Vector3(set_x: x, set_w: y, set_z: z),
+        set_gra: Vector3(set_y: x, set_x: y, set_w: z),
+        set_gba: Vector3(set_y: x, set_z: y, set_w: z),
+        set_gar: Vector3(set_y: x, set_w: y, set_x: z),
+        set_gab: Vector3(set_y: x, set_w: y, set_z: z),
+        set_bra: Vector3(set_z: x, set_x: y, set_w: z),
+        set_bga: Vector3(set_z: x, set_y: y, set_w: z),
+        set_bar: Vector3(set_z: x, set_w: y, set_x: z),
+        set_bag: Vector3(set_z: x, set_w: y, set_y: z),
+        set_arg: Vector3(set_w: x, set_x: y, set_y: z),
+        set_arb: Vector3(set_w: x, set_x: y, set_z: z),
+        set_agr: Vector3(set_w: x, set_y: y, set_x: z),
+        set_agb: Vector3(set_w: x, set_y: y, set_z: z),
+        set_abr: Vector3(set_w: x, set_z: y, set_x: z),
+        set_abg: Vector3(set_w: x, set_z: y, set_y: z),
+        set_stq: Vector3(set_x: x, set_y: y, set_w: z),
+        set_spq: Vector3(set_x: x, set_z: y, set_w: z),
+        set_sqt: Vector3(set_x: x, set_w: y, set_y: z),
+        set_sqp: Vector3(set_x: x, set_w: y, set_z: z),
+        set_tsq: Vector3(set_y: x, set_x: y, set_w: z),
+        set_tpq: Vector3(set_y: x, set_z: y, set_w: z),
+        set_tqs: Vector3(set_y: x, set_w: y, set_x: z),
+        set_tqp: Vector3(set_y: x, set_w: y, set_z: z),
+        set_psq: Vector3(set_z: x, set_x: y, set_w: z),
+        set_ptq: Vector3(set_z: x, set_y: y, set_w: z),
+        set_pqs: Vector3(set_z: x, set_w: y, set_x: z),
+        set_pqt: Vector3(set_z: x, set_w: y, set_y: z),
+        set_qst: Vector3(set_w: x, set_x: y, set_y: z),
+        set_qsp: Vector3(set_w: x, set_x: y, set_z: z),
+        set_qts: Vector3(set_w: x, set_y: y, set_x: z),
+        set_qtp: Vector3(set_w: x, set_y: y, set_z: z),
+        set_qps: Vector3(set_w: x, set_z: y, set_x: z),
+        set_qpt: Vector3(set_w: x, set_z: y, set_y: z),
+        set_xyzw: Vector4(set_x: x, set_y: y, set_z: z, set_w: w),
+        set_xywz: Vector4(set_x: x, set_y: y, set_w: z, set_z: w),
+        set_xzyw: Vector4(set_x: x, set_z: y, set_y: z, set_w: w),
+        set_xzwy: Vector4(set_x: x, set_z: y, set_w: z, set_y: w),
+        set_xwyz: Vector4(set_x: x, set_w: y, set_y: z, set_z: w),
+        set_xwzy: Vector4(set_x: x, set_w: y, set_z: z, set_y: w),
+        set_yxzw: Vector4(set_y: x, set_x: y, set_z: z, set_w: w),
+        set_yxwz: Vector4(set_y: x, set_x: y, set_w: z, set_z: w),
+        set_yzxw: Vector4(set_y: x, set_z: y, set_x: z, set_w: w),
+        set_yzwx: Vector4(set_y: x, set_z: y, set_w: z, set_x: w),
+        set_ywxz: Vector4(set_y: x, set_w: y, set_x: z, set_z: w),
+        set_ywzx: Vector4(set_y: x, set_w: y, set_z: z, set_x: w),
+        set_zxyw: Vector4(set_z: x, set_x: y, set_y: z, set_w: w),
+        set_zxwy: Vector4(set_z: x, set_x: y, set_w: z, set_y: w),
+        set_zyxw: Vector4(set_z: x, set_y: y, set_x: z, set_w: w),
+        set_zywx: Vector4(set_z: x, set_y: y, set_w: z, set_x: w),
+        set_zwxy: Vector4(set_z: x, set_w: y, set_x: z, set_y: w),
+        set_zwyx: Vector4(set_z: x, set_w: y, set_y: z, set_x: w),
+        set_wxyz: Vector4(set_w: x, set_x: y, set_y: z, set_z: w),
+        set_wxzy: Vector4(set_w: x, set_x: y, set_z: z, set_y: w),
+        set_wyxz: Vector4(set_w: x, set_y: y, set_x: z, set_z: w),
+        set_wyzx: Vector4(set_w: x, set_y: y, set_z: z, set_x: w),
+        set_wzxy: Vector4(set_w: x, set_z: y, set_x: z, set_y: w),
+        set_wzyx: Vector4(set_w: x, set_z: y, set_y: z, set_x: w),
+        set_rgba: Vector4(set_x: x, set_y: y, set_z: z, set_w: w),
+        set_rgab: Vector4(set_x: x, set_y: y, set_w: z, set_z: w),
+        set_rbga: Vector4(set_x: x, set_z: y, set_y: z, set_w: w),
+        set_rbag: Vector4(set_x: x, set_z: y, set_w: z, set_y: w),
+        set_ragb: Vector4(set_x: x, set_w: y, set_y: z, set_z: w),
+        set_rabg: Vector4(set_x: x, set_w: y, set_z: z, set_y: w),
+        set_grba: Vector4(set_y: x, set_x: y, set_z: z, set_w: w),
+        set_grab: Vector4(set_y: x, set_x: y, set_w: z, set_z: w),
+        set_gbra: Vector4(set_y: x, set_z: y, set_x: z, set_w: w),
+        set_gbar: Vector4(set_y: x, set_z: y, set_w: z, set_x: w),
+        set_garb: Vector4(set_y: x, set_w: y, set_x: z, set_z: w),
+        set_gabr: Vector4(set_y: x, set_w: y, set_z: z, set_x: w),
+        set_brga: Vector4(set_z: x, set_x: y, set_y: z, set_w: w),
+        set_brag: Vector4(set_z: x, set_x: y, set_w: z, set_y: w),
+        set_bgra: Vector4(set_z: x, set_y: y, set_x: z, set_w: w),
+        set_bgar: Vector4(set_z: x, set_y: y, set_w: z, set_x: w),
+        set_barg: Vector4(set_z: x, set_w: y, set_x: z, set_y: w),
+        set_bagr: Vector4(set_z: x, set_w: y, set_y: z, set_x: w),
+        set_argb: Vector4(set_w: x, set_x: y, set_y: z, set_z: w),
+        set_arbg: Vector4(set_w: x, set_x: y, set_z: z, set_y: w),
+        set_agrb: Vector4(set_w: x, set_y: y, set_x: z, set_z: w),
+        set_agbr: Vector4(set_w: x, set_y: y, set_z: z, set_x: w),
+        set_abrg: Vector4(set_w: x, set_z: y, set_x: z, set_y: w),
+        set_abgr: Vector4(set_w: x, set_z: y, set_y: z, set_x: w),
+        set_stpq: Vector4(set_x: x, set_y: y, set_z: z, set_w: w),
+        set_stqp: Vector4(set_x: x, set_y: y, set_w: z, set_z: w),
+        set_sptq: Vector4(set_x: x, set_z: y, set_y: z, set_w: w),
+        set_spqt: Vector4(set_x: x, set_z: y, set_w: z, set_y: w),
+        set_sqtp: Vector4(set_x: x, set_w: y, set_y: z, set_z: w),
+        set_sqpt: Vector4(set_x: x, set_w: y, set_z: z, set_y: w),
+        set_tspq: Vector4(set_y: x, set_x: y, set_z: z, set_w: w),
+        set_tsqp: Vector4(set_y: x, set_x: y, set_w: z, set_z: w),
+        set_tpsq: Vector4(set_y: x, set_z: y, set_x: z, set_w: w),
+        set_tpqs: Vector4(set_y: x, set_z: y, set_w: z, set_x: w),
+        set_tqsp: Vector4(set_y: x, set_w: y, set_x: z, set_z: w),
+        set_tqps: Vector4(set_y: x, set_w: y, set_z: z, set_x: w),
+        set_pstq: Vector4(set_z: x, set_x: y, set_y: z, set_w: w),
+        set_psqt: Vector4(set_z: x, set_x: y, set_w: z, set_y: w),
+        set_ptsq: Vector4(set_z: x, set_y: y, set_x: z, set_w: w),
+        set_ptqs: Vector4(set_z: x, set_y: y, set_w: z, set_x: w),
+        set_pqst: Vector4(set_z: x, set_w: y, set_x: z, set_y: w),
+        set_pqts: Vector4(set_z: x, set_w: y, set_y: z, set_x: w),
+        set_qstp: Vector4(set_w: x, set_x: y, set_y: z, set_z: w),
+        set_qspt: Vector4(set_w: x, set_x: y, set_z: z, set_y: w),
+        set_qtsp: Vector4(set_w: x, set_y: y, set_x: z, set_z: w),
+        set_qtps: Vector4(set_w: x, set_y: y, set_z: z, set_x: w),
+        set_qpst: Vector4(set_w: x, set_z: y, set_x: z, set_y: w),
+        set_qpts: Vector4(set_w: x, set_z: y, set_y: z, set_x: w)
+    }
+}
 
 macro_rules! impl_swizzle2 {
     ($($v: ident),+) => {
@@ -104,6 +1353,10 @@ macro_rules! impl_swizzle2 {
                 fn x(&self) -> T { self.x }
                 #[inline(always)]
                 fn y(&self) -> T { self.y }
+                #[inline(always)]
+                fn set_x(&mut self, x: T) { self.x = x }
+                #[inline(always)]
+                fn set_y(&mut self, y: T) { self.y = y }
             }
         )+
     }
@@ -115,6 +1368,8 @@ macro_rules! impl_swizzle3 {
             impl<T: Primitive> Swizzle3<T> for $v<T> {
                 #[inline(always)]
                 fn z(&self) -> T { self.z }
+                #[inline(always)]
+                fn set_z(&mut self, z: T) { self.z = z }
             }
         )+
     }
@@ -126,6 +1381,8 @@ impl_swizzle3! { Vector3, Vector4 }
 impl<T: Primitive> Swizzle4<T> for Vector4<T> {
     #[inline(always)]
     fn w(&self) -> T { self.w }
+    #[inline(always)]
+    fn set_w(&mut self, w: T) { self.w = w }
 }
 
 #[cfg(test)]
@@ -141,20 +1398,55 @@ mod test {
         assert_eq!(v.ww(), ivec2(4, 4));
         assert_eq!(v.zy(), ivec2(3, 2));
         assert_eq!(v.gb(), ivec2(2, 3));
-        assert_eq!(v.ts(), ivec2(4, 3));
+        assert_eq!(v.ts(), ivec2(2, 1));
         assert_eq!(v.vv(), ivec2(2, 2));
-        assert_eq!(v.bb(), v.ss());
+        assert_eq!(v.bb(), v.pp());
+        assert_eq!(ivec2(5, 6).yx(), ivec2(6, 5));
     }
 
     #[test]
     fn test_swizzle3() {
         let v = vec3(0., 1., 2.);
-
+        assert_eq!(v.zyx(), vec3(2., 1., 0.));
+        assert_eq!(v.xyz(), v);
+        assert_eq!(v.bgr(), v.zyx());
+        assert_eq!(v.pts(), v.zyx());
+        assert_eq!(v.xxy(), vec3(0., 0., 1.));
+        assert_eq!(v.zzzz(), vec4(2., 2., 2., 2.));
+        assert_eq!(vec2(3., 4.).yxy(), vec3(4., 3., 4.));
     }
 
     #[test]
     fn test_swizzle4() {
         let v = uvec4(0, 7, 5, 2);
+        assert_eq!(v.wzyx(), uvec4(2, 5, 7, 0));
+        assert_eq!(v.xxww(), uvec4(0, 0, 2, 2));
+        assert_eq!(v.bgra(), uvec4(5, 7, 0, 2));
+        assert_eq!(v.rgb(), uvec3(0, 7, 5));
+        assert_eq!(v.qp(), uvec2(2, 5));
+        assert_eq!(v.a(), v.w);
+        assert_eq!(v.xyzw(), v);
+    }
+
+    #[test]
+    fn test_swizzle_setters() {
+        let mut v = ivec2(1, 2);
+        let u = v.xx();
+        v.set_yx(u);
+        assert_eq!(v, ivec2(1, 1));
+
+        let mut v = vec3(0., 1., 2.);
+        v.set_xz(vec2(5., 6.));
+        assert_eq!(v, vec3(5., 1., 6.));
+        v.set_bgr(vec3(0., 1., 2.));
+        assert_eq!(v, vec3(2., 1., 0.));
 
+        let mut v = uvec4(0, 7, 5, 2);
+        v.set_wzyx(uvec4(1, 2, 3, 4));
+        assert_eq!(v, uvec4(4, 3, 2, 1));
+        v.set_pq(uvec2(8, 9));
+        assert_eq!(v, uvec4(4, 3, 8, 9));
+        v.set_xw(uvec2(0, 0));
+        assert_eq!(v, uvec4(0, 3, 8, 0));
     }
 }
@@ -22,8 +22,9 @@
 // THE SOFTWARE.
 
 use basenum::{ BaseNum, BaseFloat, Primitive };
-use traits::{ GenBType, GenFloat, GenNum };
+use traits::{ GenBType, GenFloat, GenNum, NumType };
 use std::ops::{ Index, IndexMut };
+use builtin as bif;
 
 /// Generic vector type.
 pub trait GenVec<T: Primitive>
@@ -31,15 +32,53 @@ pub trait GenVec<T: Primitive>
 + Index<usize, Output = T>
 + IndexMut<usize, Output = T>
 {
+    /// The dimension of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenVec;    // bring the constant into scope.
+    /// assert_eq!(glm::IVec4::DIM, 4);
+    /// ```
+    const DIM: usize;
+
     /// Returns the dimension of the vector.
     ///
+    /// This is the same as `DIM`.
+    ///
     /// # Example
     ///
     /// ```
     /// use glm::GenVec;    // bring the method into scope.
     /// assert_eq!(glm::IVec4::dim(), 4);
     /// ```
-    fn dim() -> usize;
+    #[inline(always)]
+    fn dim() -> usize {
+        Self::DIM
+    }
+
+    /// Returns a reference to the `i`-th component, or `None` if `i` is out
+    /// of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenVec;    // bring the method into scope.
+    /// let v = glm::vec2(1., 2.);
+    /// assert_eq!(v.get(1), Some(&2.));
+    /// assert_eq!(v.get(2), None);
+    /// ```
+    #[inline]
+    fn get(&self, i: usize) -> Option<&T> {
+        if i < Self::DIM { Some(&self[i]) } else { None }
+    }
+
+    /// Returns a mutable reference to the `i`-th component, or `None` if `i`
+    /// is out of range.
+    #[inline]
+    fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i < Self::DIM { Some(&mut self[i]) } else { None }
+    }
 
     // XXX: swap(i, j)
 }
@@ -91,10 +130,195 @@ pub trait GenNumVec<T: BaseNum>: GenNum<T> + GenVec<T> {
     /// assert_eq!(v.max(), 3.0);
     /// ```
     fn max(&self) -> T;
+
+    /// Returns the index of the minimal component. The first one is returned
+    /// if there are several.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenNumVec;     // bring the method into scope.
+    ///
+    /// let v = glm::vec3(2.0, -3.0, -3.0);
+    /// assert_eq!(v.min_element_index(), 1);
+    /// ```
+    #[inline]
+    fn min_element_index(&self) -> usize {
+        (1..Self::DIM).fold(0, |m, i| if self[i] < self[m] { i } else { m })
+    }
+
+    /// Returns the index of the maximal component. The first one is returned
+    /// if there are several.
+    ///
+    /// This is handy for finding the dominant axis of a vector, e.g., with
+    /// `abs(v).max_element_index()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenNumVec;     // bring the method into scope.
+    ///
+    /// let v = glm::ivec4(1, 5, -7, 5);
+    /// assert_eq!(v.max_element_index(), 1);
+    /// assert_eq!(glm::abs(v).max_element_index(), 2);
+    /// ```
+    #[inline]
+    fn max_element_index(&self) -> usize {
+        (1..Self::DIM).fold(0, |m, i| if self[i] > self[m] { i } else { m })
+    }
+
+    /// Returns a vector with the components sorted in ascending order.
+    ///
+    /// The order of `NaN` components is unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenNumVec;     // bring the method into scope.
+    ///
+    /// let v = glm::ivec4(3, -1, 2, 0);
+    /// assert_eq!(v.sorted(), glm::ivec4(-1, 0, 2, 3));
+    /// ```
+    #[inline]
+    fn sorted(&self) -> Self {
+        let mut v = *self;
+        for i in 1..Self::DIM {
+            let mut j = i;
+            while j > 0 && v[j] < v[j - 1] {
+                let t = v[j];
+                v[j] = v[j - 1];
+                v[j - 1] = t;
+                j -= 1;
+            }
+        }
+        v
+    }
+
+    /// Returns the median of all components.
+    ///
+    /// For vectors of even dimension, it is the mean of the two middle
+    /// components, which is truncated for integer vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenNumVec;     // bring the method into scope.
+    ///
+    /// assert_eq!(glm::vec3(5., -1., 2.).median(), 2.);
+    /// assert_eq!(glm::vec4(5., -1., 2., 0.).median(), 1.);
+    /// assert_eq!(glm::uvec2(2, 5).median(), 3);
+    /// ```
+    #[inline]
+    fn median(&self) -> T {
+        let v = self.sorted();
+        let h = Self::DIM / 2;
+        if Self::DIM % 2 == 1 {
+            v[h]
+        } else {
+            (v[h - 1] + v[h]) / (T::one() + T::one())
+        }
+    }
 }
 
 /// Generic type of vectors of float number.
-pub trait GenFloatVec<T: BaseFloat>: GenNumVec<T> + GenFloat<T> {}
+///
+/// Besides the marker role, it provides method-style versions of the
+/// built-in geometric functions, which read better in long expressions.
+pub trait GenFloatVec<T: BaseFloat>: GenNumVec<T> + GenFloat<T> {
+
+    /// Returns the dot product of _self_ and `y`, i.e., `dot(self, y)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenFloatVec;   // bring the method into scope.
+    ///
+    /// let v = glm::vec3(1., 2., 3.);
+    /// assert_eq!(v.dot(glm::vec3(4., 5., 6.)), 32.);
+    /// ```
+    #[inline(always)]
+    fn dot(&self, y: Self) -> T {
+        (*self * y).sum()
+    }
+
+    /// Returns the length of _self_, i.e., `length(self)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenFloatVec;   // bring the method into scope.
+    ///
+    /// assert_eq!(glm::vec2(3., 4.).length(), 5.);
+    /// ```
+    #[inline(always)]
+    fn length(&self) -> T {
+        bif::length(*self)
+    }
+
+    /// Returns the distance between _self_ and `p`, i.e.,
+    /// `distance(self, p)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenFloatVec;   // bring the method into scope.
+    ///
+    /// let p = glm::dvec3(1., 2., 3.);
+    /// assert_eq!(p.distance(glm::dvec3(1., 5., 7.)), 5.);
+    /// ```
+    #[inline(always)]
+    fn distance(&self, p: Self) -> T {
+        bif::distance(*self, p)
+    }
+
+    /// Returns a vector in the same direction as _self_ but with a length
+    /// of `1`, i.e., `normalize(self)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glm::GenFloatVec;   // bring the method into scope.
+    ///
+    /// assert_eq!(glm::vec2(3., 4.).normalize(), glm::vec2(0.6, 0.8));
+    /// ```
+    #[inline(always)]
+    fn normalize(&self) -> Self where T: GenFloat<T> {
+        bif::normalize(*self)
+    }
+}
+
+/// Numeric vector type whose scalar type is an associated type.
+///
+/// It is implemented for every `NumType` that is a `GenNumVec` of its scalar
+/// type. See `NumType`.
+pub trait NumVecType: GenNumVec<<Self as NumVecType>::Scalar> {
+    /// Type of the components.
+    type Scalar: BaseNum;
+}
+
+impl<T> NumVecType for T
+where
+    T: NumType + GenNumVec<<T as NumType>::Scalar>,
+{
+    type Scalar = <T as NumType>::Scalar;
+}
+
+/// Float vector type whose scalar type is an associated type.
+///
+/// It is implemented for every `NumType` that is a `GenFloatVec` of its
+/// scalar type. See `NumType`.
+pub trait FloatVecType: GenFloatVec<<Self as FloatVecType>::Scalar> {
+    /// Type of the components.
+    type Scalar: BaseFloat;
+}
+
+impl<T> FloatVecType for T
+where
+    T: NumType + GenFloatVec<<T as NumType>::Scalar>,
+    <T as NumType>::Scalar: BaseFloat,
+{
+    type Scalar = <T as NumType>::Scalar;
+}
 
 /// Generic boolean vector type.
 pub trait GenBVec: GenVec<bool> + GenBType {
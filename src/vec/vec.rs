@@ -24,14 +24,18 @@
 use basenum::*;
 use traits::*;
 use super::traits::{ GenVec, GenNumVec, GenFloatVec, GenBVec };
+use error::GlmError;
 use std::cmp::Eq;
+use std::convert::TryFrom;
+use std::iter::FromIterator;
 use std::mem;
 use std::ops::{
     Add, Mul, Sub, Neg, Div, Rem, Not, BitAnd, BitOr, BitXor, Shl, Shr,
+    AddAssign, SubAssign, MulAssign, DivAssign, RemAssign,
     Index, IndexMut,
 };
 use rand::{ Rand, Rng };
-use num::{ Float, One, Zero };
+use num::{ One, Zero };
 use quickcheck::{ Arbitrary, Gen };
 
 // copied from `cgmath-rs/src/vector.rs`.
@@ -47,6 +51,11 @@ macro_rules! fold(
     };
 );
 
+// Expands to `$t` once for `$x`, e.g., to repeat a type for each field.
+macro_rules! subst(
+    ($x: ident, $t: ty) => { $t };
+);
+
 macro_rules! def_genvec(
     (
         $t: ident,          // name of the type to be defined,
@@ -55,6 +64,15 @@ macro_rules! def_genvec(
     ) => {
         #[repr(C)]
         #[derive(Copy, Clone, PartialEq, Debug)]
+        #[cfg_attr(feature = "zerocopy", derive(
+            ::zerocopy::FromBytes, ::zerocopy::IntoBytes,
+            ::zerocopy::Immutable, ::zerocopy::KnownLayout
+        ))]
+        #[cfg_attr(feature = "rkyv", derive(
+            ::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize
+        ))]
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "bevy_reflect", derive(::bevy_reflect::Reflect))]
         pub struct $t<T: Primitive> {
             $(pub $field: T),+
         }
@@ -83,10 +101,74 @@ macro_rules! def_genvec(
                 let ary: &mut [T; $n] = unsafe { mem::transmute(self) };
                 ary
             }
+            /// Constructs a vector from the elements of `iter`, or returns
+            /// `Err(GlmError::LengthMismatch)` if `iter` does not yield
+            /// exactly as many elements as the dimension.
+            pub fn try_from_iter<I: IntoIterator<Item = T>>(
+                iter: I
+            ) -> Result<$t<T>, GlmError> {
+                let mut it = iter.into_iter();
+                $(
+                    let $field = match it.next() {
+                        Some(c) => c,
+                        None => return Err(GlmError::LengthMismatch),
+                    };
+                )+
+                if it.next().is_some() {
+                    return Err(GlmError::LengthMismatch);
+                }
+                Ok($t::new($($field),+))
+            }
         }
         impl<T: Primitive> GenVec<T> for $t<T> {
+            const DIM: usize = $n;
+        }
+        impl<T: Primitive> FromIterator<T> for $t<T> {
+            /// # Panic
+            ///
+            /// It is a panic if `iter` does not yield exactly as many
+            /// elements as the dimension. Use `try_from_iter` to handle
+            /// this case.
+            #[inline]
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> $t<T> {
+                match $t::try_from_iter(iter) {
+                    Ok(v) => v,
+                    Err(_) => panic!("expected exactly {} elements.", $n),
+                }
+            }
+        }
+        impl<'a, T: Primitive> TryFrom<&'a [T]> for $t<T> {
+            type Error = GlmError;
+            #[inline]
+            fn try_from(s: &'a [T]) -> Result<$t<T>, GlmError> {
+                $t::try_from_iter(s.iter().cloned())
+            }
+        }
+        impl<T: Primitive> From<[T; $n]> for $t<T> {
             #[inline(always)]
-            fn dim() -> usize { $n }
+            fn from(ary: [T; $n]) -> $t<T> {
+                let [$($field),+] = ary;
+                $t::new($($field),+)
+            }
+        }
+        impl<T: Primitive> From<$t<T>> for [T; $n] {
+            #[inline(always)]
+            fn from(v: $t<T>) -> [T; $n] {
+                [$(v.$field),+]
+            }
+        }
+        impl<T: Primitive> From<($(subst!($field, T)),+)> for $t<T> {
+            #[inline(always)]
+            fn from(t: ($(subst!($field, T)),+)) -> $t<T> {
+                let ($($field),+) = t;
+                $t::new($($field),+)
+            }
+        }
+        impl<T: Primitive> From<$t<T>> for ($(subst!($field, T)),+) {
+            #[inline(always)]
+            fn from(v: $t<T>) -> ($(subst!($field, T)),+) {
+                ($(v.$field),+)
+            }
         }
         impl<T: Primitive> Index<usize> for $t<T> {
             type Output = T;
@@ -129,6 +211,54 @@ macro_rules! def_genvec(
                 $t::new($(!self.$field),+)
             }
         }
+        impl $t<bool> {
+            /// Packs the components into the lowest bits of a `u32`. The
+            /// first component goes to bit `0`.
+            #[inline]
+            pub fn to_bits(&self) -> u32 {
+                self.as_array().iter().enumerate().fold(0, |bits, (i, &c)| {
+                    bits | ((c as u32) << i)
+                })
+            }
+            /// Unpacks the lowest bits of `bits` into a boolean vector. Bit
+            /// `0` goes to the first component. Higher bits are ignored.
+            #[inline]
+            pub fn from_bits(bits: u32) -> $t<bool> {
+                let mut v = $t { $($field: false),+ };
+                for i in 0..$n {
+                    v[i] = bits & (1 << i) != 0;
+                }
+                v
+            }
+        }
+        impl Not for $t<bool> {
+            type Output = $t<bool>;
+            #[inline(always)]
+            fn not(self) -> $t<bool> {
+                $t::new($(!self.$field),+)
+            }
+        }
+        impl BitAnd<$t<bool>> for $t<bool> {
+            type Output = $t<bool>;
+            #[inline(always)]
+            fn bitand(self, rhs: $t<bool>) -> $t<bool> {
+                $t::new($(self.$field & rhs.$field),+)
+            }
+        }
+        impl BitOr<$t<bool>> for $t<bool> {
+            type Output = $t<bool>;
+            #[inline(always)]
+            fn bitor(self, rhs: $t<bool>) -> $t<bool> {
+                $t::new($(self.$field | rhs.$field),+)
+            }
+        }
+        impl BitXor<$t<bool>> for $t<bool> {
+            type Output = $t<bool>;
+            #[inline(always)]
+            fn bitxor(self, rhs: $t<bool>) -> $t<bool> {
+                $t::new($(self.$field ^ rhs.$field),+)
+            }
+        }
         impl<T: BaseNum> Add<$t<T>> for $t<T> {
             type Output = $t<T>;
             #[inline(always)]
@@ -185,6 +315,82 @@ macro_rules! def_genvec(
                 $t::new($(self.$field % rhs),+)
             }
         }
+        impl<'a, 'b, T: BaseNum> Add<&'b $t<T>> for &'a $t<T> {
+            type Output = $t<T>;
+            #[inline(always)]
+            fn add(self, rhs: &'b $t<T>) -> $t<T> {
+                $t::new($(self.$field + rhs.$field),+)
+            }
+        }
+        impl<'a, 'b, T: BaseNum> Mul<&'b $t<T>> for &'a $t<T> {
+            type Output = $t<T>;
+            #[inline(always)]
+            fn mul(self, rhs: &'b $t<T>) -> $t<T> {
+                $t::new($(self.$field * rhs.$field),+)
+            }
+        }
+        impl<'a, 'b, T: BaseNum> Div<&'b $t<T>> for &'a $t<T> {
+            type Output = $t<T>;
+            #[inline(always)]
+            fn div(self, rhs: &'b $t<T>) -> $t<T> {
+                $t::new($(self.$field / rhs.$field),+)
+            }
+        }
+        impl<'a, 'b, T: BaseNum> Rem<&'b $t<T>> for &'a $t<T> {
+            type Output = $t<T>;
+            #[inline(always)]
+            fn rem(self, rhs: &'b $t<T>) -> $t<T> {
+                $t::new($(self.$field % rhs.$field),+)
+            }
+        }
+        impl<T: BaseNum> AddAssign<$t<T>> for $t<T> {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: $t<T>) {
+                $(self.$field = self.$field + rhs.$field;)+
+            }
+        }
+        impl<T: BaseNum> AddAssign<T> for $t<T> {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: T) {
+                $(self.$field = self.$field + rhs;)+
+            }
+        }
+        impl<T: BaseNum> MulAssign<$t<T>> for $t<T> {
+            #[inline(always)]
+            fn mul_assign(&mut self, rhs: $t<T>) {
+                $(self.$field = self.$field * rhs.$field;)+
+            }
+        }
+        impl<T: BaseNum> MulAssign<T> for $t<T> {
+            #[inline(always)]
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$field = self.$field * rhs;)+
+            }
+        }
+        impl<T: BaseNum> DivAssign<$t<T>> for $t<T> {
+            #[inline(always)]
+            fn div_assign(&mut self, rhs: $t<T>) {
+                $(self.$field = self.$field / rhs.$field;)+
+            }
+        }
+        impl<T: BaseNum> DivAssign<T> for $t<T> {
+            #[inline(always)]
+            fn div_assign(&mut self, rhs: T) {
+                $(self.$field = self.$field / rhs;)+
+            }
+        }
+        impl<T: BaseNum> RemAssign<$t<T>> for $t<T> {
+            #[inline(always)]
+            fn rem_assign(&mut self, rhs: $t<T>) {
+                $(self.$field = self.$field % rhs.$field;)+
+            }
+        }
+        impl<T: BaseNum> RemAssign<T> for $t<T> {
+            #[inline(always)]
+            fn rem_assign(&mut self, rhs: T) {
+                $(self.$field = self.$field % rhs;)+
+            }
+        }
         impl<T: BaseNum> One for $t<T> {
             #[inline(always)]
             fn one() -> $t<T> {
@@ -204,6 +410,9 @@ macro_rules! def_genvec(
                 $(self.$field == l) && +
             }
         }
+        impl<T: BaseNum> NumType for $t<T> {
+            type Scalar = T;
+        }
         impl<T: BaseNum> GenNum<T> for $t<T> {
             #[inline(always)]
             fn from_s(x: T) -> Self {
@@ -222,7 +431,7 @@ macro_rules! def_genvec(
                 let ling = $t::<T>::zero();
                 let mut a = ling;
                 let mut b = ling;
-                let dim = $t::<T>::dim();
+                let dim = $t::<T>::DIM;
                 for i in 0..dim {
                     let (c, d) = f(self[i]);
                     a[i] = c;
@@ -235,7 +444,7 @@ macro_rules! def_genvec(
                 let ling = Self::zero();
                 let mut a = ling;
                 let mut b = ling;
-                let dim = Self::dim();
+                let dim = Self::DIM;
                 for i in 0..dim {
                     let (c, d) = f(self[i], y[i]);
                     a[i] = c;
@@ -283,6 +492,25 @@ macro_rules! def_genvec(
                 $t::new($(self.$field - rhs),+)
             }
         }
+        impl<'a, 'b, T: SignedNum + BaseNum> Sub<&'b $t<T>> for &'a $t<T> {
+            type Output = $t<T>;
+            #[inline(always)]
+            fn sub(self, rhs: &'b $t<T>) -> $t<T> {
+                $t::new($(self.$field - rhs.$field),+)
+            }
+        }
+        impl<T: SignedNum + BaseNum> SubAssign<$t<T>> for $t<T> {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: $t<T>) {
+                $(self.$field = self.$field - rhs.$field;)+
+            }
+        }
+        impl<T: SignedNum + BaseNum> SubAssign<T> for $t<T> {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: T) {
+                $(self.$field = self.$field - rhs;)+
+            }
+        }
         impl<T: SignedNum + BaseNum> SignedNum for $t<T> {
             #[inline]
             fn abs(&self) -> $t<T> {
@@ -357,6 +585,41 @@ macro_rules! def_genvec(
                 $t::new($(self.$field >> rhs),+)
             }
         }
+        // per-component shift amounts, as `x << y` of GLSL with vector `y`.
+        // GLSL leaves amounts out of `[0, bits)` undefined, here they wrap
+        // around like `wrapping_shl`, so negative amounts do not panic.
+        impl<T: BaseInt> Shl<$t<u32>> for $t<T> {
+            type Output = Self;
+            #[inline]
+            fn shl(self, rhs: $t<u32>) -> Self {
+                let m = mem::size_of::<T>() * 8 - 1;
+                $t::new($(self.$field << (rhs.$field as usize & m)),+)
+            }
+        }
+        impl<T: BaseInt> Shl<$t<i32>> for $t<T> {
+            type Output = Self;
+            #[inline]
+            fn shl(self, rhs: $t<i32>) -> Self {
+                let m = mem::size_of::<T>() * 8 - 1;
+                $t::new($(self.$field << (rhs.$field as usize & m)),+)
+            }
+        }
+        impl<T: BaseInt> Shr<$t<u32>> for $t<T> {
+            type Output = Self;
+            #[inline]
+            fn shr(self, rhs: $t<u32>) -> Self {
+                let m = mem::size_of::<T>() * 8 - 1;
+                $t::new($(self.$field >> (rhs.$field as usize & m)),+)
+            }
+        }
+        impl<T: BaseInt> Shr<$t<i32>> for $t<T> {
+            type Output = Self;
+            #[inline]
+            fn shr(self, rhs: $t<i32>) -> Self {
+                let m = mem::size_of::<T>() * 8 - 1;
+                $t::new($(self.$field >> (rhs.$field as usize & m)),+)
+            }
+        }
         impl<T: BaseInt> GenInt<T> for $t<T> {}
         impl GenIType for $t<i32> {}
         impl GenUType for $t<u32> {}
@@ -369,10 +632,9 @@ macro_rules! def_genvec(
         }
         impl<T: BaseFloat> GenFloat<T> for $t<T> {
             fn fma(&self, b: &$t<T>, c: &$t<T>) -> $t<T> {
-                $t::new($(Float::mul_add(self.$field, b.$field, c.$field)),+)
+                $t::new($(self.$field.fused_mul_add(b.$field, c.$field)),+)
             }
         }
-        impl<T: BaseFloat> GenFloatVec<T> for $t<T> {}
         impl GenType for $t<f32> {}
         impl GenDType for $t<f64> {}
     }
@@ -382,6 +644,71 @@ def_genvec! { Vector2, 2, x, y }
 def_genvec! { Vector3, 3, x, y, z }
 def_genvec! { Vector4, 4, x, y, z, w }
 
+impl<T: BaseFloat> GenFloatVec<T> for Vector2<T> {}
+impl<T: BaseFloat> GenFloatVec<T> for Vector3<T> {}
+impl<T: BaseFloat> GenFloatVec<T> for Vector4<T> {
+    #[inline(always)]
+    fn dot(&self, y: Vector4<T>) -> T {
+        T::dot4(self.as_array(), y.as_array())
+    }
+}
+
+macro_rules! def_with(
+    ($t: ident, $({ $nm: ident, $field: ident }),+) => {
+        impl<T: Primitive> $t<T> {
+            $(
+                #[doc = concat!(
+                    "Returns a copy of _self_ with component `",
+                    stringify!($field), "` replaced by `v`."
+                )]
+                #[inline(always)]
+                pub fn $nm(&self, v: T) -> $t<T> {
+                    $t { $field: v, ..*self }
+                }
+            )+
+        }
+    }
+);
+
+def_with! { Vector2, { with_x, x }, { with_y, y } }
+def_with! { Vector3, { with_x, x }, { with_y, y }, { with_z, z } }
+def_with! { Vector4, { with_x, x }, { with_y, y }, { with_z, z }, { with_w, w } }
+
+// Constants can't be generic, because `Zero::zero()` and `One::one()` are
+// not `const`. So they are defined for each scalar type.
+macro_rules! def_consts(
+    ($t: ident, $fields: tt, $({ $s: ty, $ling: expr, $yi: expr }),+) => {
+        $(
+            def_consts! { @impl $t, $s, $ling, $yi, $fields }
+        )+
+    };
+    (@impl $t: ident, $s: ty, $ling: expr, $yi: expr, { $({ $c: ident, $field: ident }),+ }) => {
+        impl $t<$s> {
+            /// The vector whose components are all `0`.
+            pub const ZERO: $t<$s> = $t { $($field: $ling),+ };
+            /// The vector whose components are all `1`.
+            pub const ONE: $t<$s> = $t { $($field: $yi),+ };
+            $(
+                #[doc = concat!("The unit vector along the `", stringify!($field), "` axis.")]
+                pub const $c: $t<$s> = $t { $field: $yi, ..Self::ZERO };
+            )+
+        }
+    }
+);
+
+def_consts! {
+    Vector2, { { X, x }, { Y, y } },
+    { f32, 0., 1. }, { f64, 0., 1. }, { i32, 0, 1 }, { u32, 0, 1 }
+}
+def_consts! {
+    Vector3, { { X, x }, { Y, y }, { Z, z } },
+    { f32, 0., 1. }, { f64, 0., 1. }, { i32, 0, 1 }, { u32, 0, 1 }
+}
+def_consts! {
+    Vector4, { { X, x }, { Y, y }, { Z, z }, { W, w } },
+    { f32, 0., 1. }, { f64, 0., 1. }, { i32, 0, 1 }, { u32, 0, 1 }
+}
+
 impl<T: Primitive> Vector2<T> {
     /// Extends _self_ to a `Vector3` by appending `z`.
     ///
@@ -398,6 +725,20 @@ impl<T: Primitive> Vector2<T> {
     pub fn extend(&self, z: T) -> Vector3<T> {
         Vector3 { x: self.x, y: self.y, z: z }
     }
+
+    /// Extends _self_ to a `Vector4` by appending `z` and `w`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glm::*;
+    ///
+    /// assert_eq!(vec2(1., 2.).extend2(3., 4.), vec4(1., 2., 3., 4.));
+    /// ```
+    #[inline]
+    pub fn extend2(&self, z: T, w: T) -> Vector4<T> {
+        Vector4 { x: self.x, y: self.y, z: z, w: w }
+    }
 }
 
 impl<T: Primitive> Vector3<T> {
@@ -417,7 +758,22 @@ impl<T: Primitive> Vector3<T> {
         Vector4 { x: self.x, y: self.y, z: self.z, w: w }
     }
 
-    /// Truncates _self_ to a `Vector2` by remove the `i`<sub>th</sub> element.
+    /// Returns the `x` and `y` components, i.e., drops the last one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glm::*;
+    ///
+    /// assert_eq!(vec3(1., 2., 3.).xy(), vec2(1., 2.));
+    /// ```
+    #[inline(always)]
+    pub fn xy(&self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
+    }
+
+    /// Returns a `Vector2` with the `i`<sub>th</sub> component of _self_
+    /// removed.
     ///
     /// Parameter `i` is `0` based index.
     ///
@@ -432,10 +788,10 @@ impl<T: Primitive> Vector3<T> {
     ///
     /// let v3 = vec3(1., 2., 3.);
     /// let v2 = vec2(1., 3.);
-    /// assert_eq!(v3.truncate(1), v2);
+    /// assert_eq!(v3.without(1), v2);
     /// ```
     #[inline]
-    pub fn truncate(&self, i: usize) -> Vector2<T> {
+    pub fn without(&self, i: usize) -> Vector2<T> {
         match i {
             0 => Vector2::new(self.y, self.z),
             1 => Vector2::new(self.x, self.z),
@@ -443,18 +799,64 @@ impl<T: Primitive> Vector3<T> {
             _ => panic!("parameter i is out of range [{:?} > 2].", i)
         }
     }
+
+    /// Truncates _self_ to a `Vector2` by remove the `i`<sub>th</sub> element.
+    ///
+    /// This is the same as `without`.
+    #[deprecated(note = "use `without(i)`, or `xy()` to drop the last component")]
+    #[inline(always)]
+    pub fn truncate(&self, i: usize) -> Vector2<T> {
+        self.without(i)
+    }
 }
 
 impl<T: Primitive> Vector4<T> {
-    /// Truncates _self_ to a `Vector3` by remove the `i`<sub>th</sub> element.
+    /// Returns the `x` and `y` components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glm::*;
+    ///
+    /// assert_eq!(vec4(1., 2., 3., 4.).xy(), vec2(1., 2.));
+    /// ```
+    #[inline(always)]
+    pub fn xy(&self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
+    }
+
+    /// Returns the `x`, `y` and `z` components, i.e., drops the last one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glm::*;
+    ///
+    /// assert_eq!(vec4(1., 2., 3., 4.).xyz(), vec3(1., 2., 3.));
+    /// ```
+    #[inline(always)]
+    pub fn xyz(&self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// Returns a `Vector3` with the `i`<sub>th</sub> component of _self_
+    /// removed.
     ///
     /// Parameter `i` is `0` based index.
     ///
     /// # Panic
     ///
     /// It is a panic if i is larger than `3`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glm::*;
+    ///
+    /// assert_eq!(vec4(1., 2., 3., 4.).without(0), vec3(2., 3., 4.));
+    /// ```
     #[inline]
-    pub fn truncate(&self, i: usize) -> Vector3<T> {
+    pub fn without(&self, i: usize) -> Vector3<T> {
         match i {
             0 => Vector3::new(self.y, self.z, self.w),
             1 => Vector3::new(self.x, self.z, self.w),
@@ -463,6 +865,15 @@ impl<T: Primitive> Vector4<T> {
             _ => panic!("parameter i is out of range [{:?} > 3].", i)
         }
     }
+
+    /// Truncates _self_ to a `Vector3` by remove the `i`<sub>th</sub> element.
+    ///
+    /// This is the same as `without`.
+    #[deprecated(note = "use `without(i)`, or `xyz()` to drop the last component")]
+    #[inline(always)]
+    pub fn truncate(&self, i: usize) -> Vector3<T> {
+        self.without(i)
+    }
 }
 
 macro_rules! def_alias(
@@ -511,9 +922,97 @@ def_alias! {
 mod test {
 
     use super::*;
-    use num::One;
+    use num::{ One, Zero };
     use quickcheck::*;
 
+    #[test]
+    fn test_from_iter() {
+        let v: Vec3 = (1..4).map(|i| i as f32).collect();
+        assert_eq!(v, vec3(1., 2., 3.));
+        assert_eq!(IVec2::try_from_iter(vec![1, 2, 3]), Err(GlmError::LengthMismatch));
+        assert_eq!(UVec4::try_from(&[1, 2, 3, 4][..]), Ok(uvec4(1, 2, 3, 4)));
+        assert_eq!(Vec2::try_from(&[1.][..]), Err(GlmError::LengthMismatch));
+    }
+
+    #[test]
+    fn test_consts() {
+        const UP: Vec3 = Vec3::Y;
+        assert_eq!(UP, vec3(0., 1., 0.));
+        assert_eq!(Vec2::ZERO, Vec2::zero());
+        assert_eq!(DVec4::ONE, DVec4::one());
+        assert_eq!(IVec4::W, ivec4(0, 0, 0, 1));
+        assert_eq!(UVec3::X + UVec3::Y + UVec3::Z, UVec3::ONE);
+    }
+
+    #[test]
+    fn test_from_array_tuple() {
+        let v: Vec3 = [1., 2., 3.].into();
+        assert_eq!(v, vec3(1., 2., 3.));
+        let a: [f32; 3] = v.into();
+        assert_eq!(a, [1., 2., 3.]);
+        assert_eq!(IVec2::from((1, 2)), ivec2(1, 2));
+        let (x, y, z, w) = uvec4(1, 2, 3, 4).into();
+        assert_eq!((x, y, z, w), (1, 2, 3, 4));
+        assert_eq!(BVec2::from([true, false]), bvec2(true, false));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_iter_panic() {
+        let _: IVec3 = vec![1, 2].into_iter().collect();
+    }
+
+    #[test]
+    fn test_ref_ops() {
+        let (a, b) = (vec3(1., 2., 3.), vec3(4., 5., 6.));
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - &b, a - b);
+        assert_eq!(&a * &b, a * b);
+        assert_eq!(&a / &b, a / b);
+        assert_eq!(&uvec2(7, 8) % &uvec2(4, 3), uvec2(3, 2));
+    }
+
+    #[test]
+    fn test_with() {
+        let v = vec3(1., 2., 3.);
+        assert_eq!(v.with_x(4.), vec3(4., 2., 3.));
+        assert_eq!(v.with_z(0.).with_y(5.), vec3(1., 5., 0.));
+        assert_eq!(ivec4(1, 2, 3, 4).with_w(0), ivec4(1, 2, 3, 0));
+        assert_eq!(uvec2(1, 2).with_y(3), uvec2(1, 3));
+    }
+
+    #[test]
+    fn test_bvec_bits() {
+        let m = bvec4(true, false, true, true);
+        assert_eq!(m.to_bits(), 0b1101);
+        assert_eq!(BVec4::from_bits(0b1101), m);
+        assert_eq!(BVec2::from_bits(0xFFFF_FFFE), bvec2(false, true));
+        fn prop(b: BVec3) -> bool {
+            BVec3::from_bits(b.to_bits()) == b
+        }
+        quickcheck(prop as fn(BVec3) -> bool);
+        let n = bvec4(false, false, true, false);
+        assert_eq!(m & n, n);
+        assert_eq!(m | n, m);
+        assert_eq!(m ^ n, bvec4(true, false, false, true));
+        assert_eq!(!m, bvec4(false, true, false, false));
+    }
+
+    #[test]
+    fn test_shift_per_component() {
+        assert_eq!(uvec3(1, 1, 0xF0) << uvec3(0, 4, 4), uvec3(1, 16, 0xF00));
+        assert_eq!(ivec2(-16, 16) >> ivec2(2, 4), ivec2(-4, 1));
+        assert_eq!(ivec2(3, 3) << uvec2(1, 2), ivec2(6, 12));
+        assert_eq!(uvec4(256, 256, 256, 256) >> ivec4(0, 1, 2, 8), uvec4(256, 128, 64, 1));
+    }
+
+    #[test]
+    fn test_shift_per_component_wrapping() {
+        assert_eq!(uvec3(1, 1, 1) << uvec3(31, 32, 33), uvec3(0x8000_0000, 1, 2));
+        assert_eq!(uvec2(1, 1) << ivec2(-1, -32), uvec2(0x8000_0000, 1));
+        assert_eq!(ivec3(-16, -16, 16) >> ivec3(31, -1, 36), ivec3(-1, -1, 1));
+    }
+
     #[test]
     fn test_as_array() {
         fn prop(v3: Vec3) -> bool {
@@ -560,4 +1059,69 @@ mod test {
         }
         quickcheck(prop as fn(IVec3) -> bool);
     }
+
+    #[test]
+    fn test_assign_ops() {
+        fn prop(v: Vec3, w: Vec3) -> bool {
+            let mut u = v;
+            u += w;
+            u -= 1.;
+            u *= 2.;
+            u == (v + w - 1.) * 2.
+        }
+        quickcheck(prop as fn(Vec3, Vec3) -> bool);
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn test_zerocopy() {
+        use zerocopy::{ FromBytes, IntoBytes };
+
+        let v = vec3(1., 2., 3.);
+        let bytes = v.as_bytes();
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(Vec3::read_from_bytes(bytes).unwrap(), v);
+        let m = ::mat::ctor::mat2(1., 2., 3., 4.);
+        assert_eq!(<[Vec2; 2]>::ref_from_bytes(m.as_array().as_bytes()).unwrap()[1], vec2(3., 4.));
+        let q = ::quat::Quaternion::new(1_f32, 2., 3., 4.);
+        assert_eq!(q.as_bytes().len(), 16);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_bytemuck() {
+        let vs = [vec3(1., 2., 3.), vec3(4., 5., 6.)];
+        let fs: &[f32] = ::bytemuck::cast_slice(&vs);
+        assert_eq!(fs, &[1., 2., 3., 4., 5., 6.]);
+        assert_eq!(::bytemuck::cast::<[u32; 4], UVec4>([1, 2, 3, 4]), uvec4(1, 2, 3, 4));
+        let m: ::mat::mat::Mat2 = ::bytemuck::Zeroable::zeroed();
+        assert_eq!(::bytemuck::bytes_of(&m), &[0; 16][..]);
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv() {
+        use rkyv::rancor::Error;
+
+        let v = vec4(1., 2., 3., 4.);
+        let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+        let archived = rkyv::access::<<Vec4 as rkyv::Archive>::Archived, Error>(&bytes).unwrap();
+        assert_eq!(archived.z, 3.);
+        assert_eq!(rkyv::deserialize::<Vec4, Error>(archived).unwrap(), v);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let v = ivec2(1, -2);
+        let json = ::serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"x":1,"y":-2}"#);
+        assert_eq!(::serde_json::from_str::<IVec2>(&json).unwrap(), v);
+        let m = ::mat::ctor::dmat2(1., 2., 3., 4.);
+        let json = ::serde_json::to_string(&m).unwrap();
+        assert_eq!(::serde_json::from_str::<::mat::mat::DMat2>(&json).unwrap(), m);
+        let q = ::quat::dquat(1., 0., 0., 0.);
+        let json = ::serde_json::to_string(&q).unwrap();
+        assert_eq!(::serde_json::from_str::<::quat::DQuat>(&json).unwrap(), q);
+    }
 }